@@ -0,0 +1,4 @@
+import * as wasm from "./wasm/sulafat_example_demo.js";
+import { init } from "../../runtime-web/ts/index.js";
+
+init(wasm);
@@ -0,0 +1,90 @@
+use sulafat_macros::{html, StyleSet};
+use sulafat_vdom::{cmd::Cmd, random::range, timer::timeout, Node, Program};
+use wasm_bindgen::prelude::*;
+
+struct MyProgram;
+
+enum Msg {
+    Update,
+    Timeout,
+    Random(u32),
+}
+
+#[derive(PartialEq)]
+struct Model {
+    len: usize,
+    rand: u32,
+    count: u32,
+}
+
+impl Program for MyProgram {
+    type Model = Model;
+    type Msg = Msg;
+    fn init_cmd() -> (Self::Model, Cmd<Self::Msg>) {
+        (
+            Model {
+                len: 0,
+                rand: 0,
+                count: 0,
+            },
+            Cmd::batch(vec![
+                timeout(|| Msg::Timeout, 1000),
+                range(Msg::Random, 0..10),
+            ]),
+        )
+    }
+    fn update_cmd(model: &Self::Model, msg: &Self::Msg) -> (Self::Model, Cmd<Self::Msg>) {
+        match *msg {
+            Msg::Update => (
+                Model {
+                    len: model.len + 1,
+                    ..*model
+                },
+                range(Msg::Random, 0..10),
+            ),
+            Msg::Timeout => (
+                Model {
+                    count: model.count + 1,
+                    ..*model
+                },
+                timeout(|| Msg::Timeout, 1000),
+            ),
+            Msg::Random(rand) => (Model { rand, ..*model }, Cmd::none()),
+        }
+    }
+    fn view(model: &Self::Model) -> Node<Self::Msg> {
+        #[derive(StyleSet)]
+        #[style_set{
+            .style1 {
+                left: 10px;
+            }
+        }]
+        struct Style1;
+
+        #[derive(StyleSet)]
+        #[style_set{
+            .style2 {
+                left: 20px;
+            }
+        }]
+        struct Style2;
+
+        html! {
+            <div on_click={|_| Msg::Update}>"Update"</div>
+            <div style={Style1}>{format!("{}, {}, {}", model.len, model.rand, model.count)}</div>
+            for index in 0..model.len {
+                if index % 2 == 1 {
+                    <div style={Style1}>{index.to_string()}</div>
+                } else {
+                    <div style={Style2}>{index.to_string()}</div>
+                }
+            }
+        }
+    }
+}
+
+#[wasm_bindgen(start)]
+pub fn main() {
+    sulafat_runtime_web::start::<MyProgram>("#mount-point");
+    sulafat_runtime_web::start::<MyProgram>("#another-mount-point");
+}
@@ -53,7 +53,7 @@ impl<'a> Struct<'a> {
             }
             Fields::Unnamed(fields) => {
                 let fields = (0..fields.unnamed.len())
-                    .map(|field| Index::from(field))
+                    .map(Index::from)
                     .collect::<Vec<_>>();
                 let clone = _Clone;
                 (
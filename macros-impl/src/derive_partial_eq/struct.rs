@@ -42,7 +42,7 @@ impl<'a> Struct<'a> {
                 }
             }
             Fields::Unnamed(fields) => {
-                let fields = (0..fields.unnamed.len()).map(|field| Index::from(field));
+                let fields = (0..fields.unnamed.len()).map(Index::from);
                 let partial_eq = _PartialEq;
                 quote! {
                     #(#partial_eq::eq(&self.#fields, &other.#fields) &&)* true
@@ -128,14 +128,14 @@ impl<'a> Struct<'a> {
                 if path.leading_colon.is_none()
                     && path.segments.len() == 1
                     && path.segments[0].arguments.is_empty()
-                    && path.segments[0].ident.to_string() == "serde"
+                    && path.segments[0].ident == "serde"
                 {
                     nested.into_iter().any(|nested_meta| {
                         if let NestedMeta::Meta(Meta::Path(path)) = nested_meta {
                             path.leading_colon.is_none()
                                 && path.segments.len() == 1
                                 && path.segments[0].arguments.is_empty()
-                                && path.segments[0].ident.to_string() == "skip"
+                                && path.segments[0].ident == "skip"
                         } else {
                             false
                         }
@@ -0,0 +1,164 @@
+use super::{value::option_tokens, Wrapper};
+use crate::util::crate_name;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use sulafat_style::{
+    Animation, IterationCount, Keyframe, Keyframes, SingleAnimation, SingleTransition, Time,
+    TimingFunction, Transition, TransitionProperty,
+};
+
+impl ToTokens for Wrapper<&Time> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            Time::Seconds(seconds) => quote! { ::#sulafat_style::Time::Seconds(#seconds) },
+            Time::Milliseconds(milliseconds) => {
+                quote! { ::#sulafat_style::Time::Milliseconds(#milliseconds) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TimingFunction> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TimingFunction::Ease => quote! { ::#sulafat_style::TimingFunction::Ease },
+            TimingFunction::Linear => quote! { ::#sulafat_style::TimingFunction::Linear },
+            TimingFunction::EaseIn => quote! { ::#sulafat_style::TimingFunction::EaseIn },
+            TimingFunction::EaseOut => quote! { ::#sulafat_style::TimingFunction::EaseOut },
+            TimingFunction::EaseInOut => quote! { ::#sulafat_style::TimingFunction::EaseInOut },
+            TimingFunction::StepStart => quote! { ::#sulafat_style::TimingFunction::StepStart },
+            TimingFunction::StepEnd => quote! { ::#sulafat_style::TimingFunction::StepEnd },
+            TimingFunction::CubicBezier(x1, y1, x2, y2) => quote! {
+                ::#sulafat_style::TimingFunction::CubicBezier(#x1, #y1, #x2, #y2)
+            },
+            TimingFunction::Steps(steps, position) => {
+                let position = option_tokens(position.as_ref().map(Wrapper));
+                quote! { ::#sulafat_style::TimingFunction::Steps(#steps, #position) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TransitionProperty> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TransitionProperty::None => quote! { ::#sulafat_style::TransitionProperty::None },
+            TransitionProperty::All => quote! { ::#sulafat_style::TransitionProperty::All },
+            TransitionProperty::Property(name) => quote! {
+                ::#sulafat_style::TransitionProperty::Property(::std::string::String::from(#name))
+            },
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&SingleTransition> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let property = option_tokens(self.0.property.as_ref().map(Wrapper));
+        let duration = option_tokens(self.0.duration.as_ref().map(Wrapper));
+        let timing_function = option_tokens(self.0.timing_function.as_ref().map(Wrapper));
+        let delay = option_tokens(self.0.delay.as_ref().map(Wrapper));
+        tokens.extend(quote! {
+            ::#sulafat_style::SingleTransition {
+                property: #property,
+                duration: #duration,
+                timing_function: #timing_function,
+                delay: #delay,
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&Transition> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let transitions = self.0 .0.iter().map(Wrapper);
+        tokens.extend(quote! {
+            ::#sulafat_style::Transition(::std::vec![#(#transitions),*])
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&IterationCount> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            IterationCount::Infinite => quote! { ::#sulafat_style::IterationCount::Infinite },
+            IterationCount::Number(count) => {
+                quote! { ::#sulafat_style::IterationCount::Number(#count) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&SingleAnimation> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let duration = option_tokens(self.0.duration.as_ref().map(Wrapper));
+        let timing_function = option_tokens(self.0.timing_function.as_ref().map(Wrapper));
+        let delay = option_tokens(self.0.delay.as_ref().map(Wrapper));
+        let iteration_count = option_tokens(self.0.iteration_count.as_ref().map(Wrapper));
+        let direction = option_tokens(self.0.direction.as_ref().map(Wrapper));
+        let fill_mode = option_tokens(self.0.fill_mode.as_ref().map(Wrapper));
+        let play_state = option_tokens(self.0.play_state.as_ref().map(Wrapper));
+        let name = option_tokens(
+            self.0
+                .name
+                .as_ref()
+                .map(|name| quote! { ::std::string::String::from(#name) }),
+        );
+        tokens.extend(quote! {
+            ::#sulafat_style::SingleAnimation {
+                duration: #duration,
+                timing_function: #timing_function,
+                delay: #delay,
+                iteration_count: #iteration_count,
+                direction: #direction,
+                fill_mode: #fill_mode,
+                play_state: #play_state,
+                name: #name,
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&Animation> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let animations = self.0 .0.iter().map(Wrapper);
+        tokens.extend(quote! {
+            ::#sulafat_style::Animation(::std::vec![#(#animations),*])
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&Keyframe> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let selectors = self.0.selectors.iter().map(Wrapper);
+        let rules = self.0.rules.iter().map(Wrapper);
+        tokens.extend(quote! {
+            ::#sulafat_style::Keyframe {
+                selectors: ::std::vec![#(#selectors),*],
+                rules: ::std::vec![#(#rules),*],
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&Keyframes> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let name = &self.0.name;
+        let frames = self.0.frames.iter().map(Wrapper);
+        tokens.extend(quote! {
+            ::#sulafat_style::Keyframes {
+                name: ::std::string::String::from(#name),
+                frames: ::std::vec![#(#frames),*],
+            }
+        })
+    }
+}
@@ -0,0 +1,406 @@
+use super::{
+    declaration::parse_declaration,
+    interpolation::Interpolations,
+    value::{parse_name, parse_value, peek_keyword, Number},
+    Wrapper,
+};
+use crate::util::crate_name;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use sulafat_style::{
+    ColorScheme, Condition, Keyframe, Keyframes, Length, MediaFeature, MediaQuery, MediaType,
+    Orientation, Parcentage, Pseudo, ReducedMotion, StyleBlock, StyleRule, SupportsCondition,
+};
+use syn::{
+    braced, parenthesized,
+    parse::{Parse, ParseStream},
+    token::Paren,
+    Token,
+};
+
+/// Parses rules and nested blocks until the end of `input`. Nested blocks are
+/// flattened into `blocks`, each carrying the conditions and selector it is nested in.
+/// `@keyframes` are only allowed at the top level.
+pub(super) fn parse_body(
+    input: ParseStream,
+    conditions: &[Condition],
+    selector: &[Pseudo],
+    rules: &mut Vec<StyleRule>,
+    blocks: &mut Vec<StyleBlock>,
+    keyframes: &mut Vec<Keyframes>,
+    interpolations: &mut Interpolations,
+) -> syn::Result<()> {
+    while !input.is_empty() {
+        if input.peek(Token![&]) {
+            let span = input.parse::<Token![&]>()?.span;
+            let mut selector = selector.to_vec();
+            let len = selector.len();
+            loop {
+                if input.parse::<Option<Token![::]>>()?.is_some() {
+                    selector.push(Pseudo::Element(parse_name(input)?.0));
+                } else if input.parse::<Option<Token![:]>>()?.is_some() {
+                    selector.push(Pseudo::Class(parse_name(input)?.0));
+                } else {
+                    break;
+                }
+            }
+            if selector.len() == len {
+                return Err(syn::Error::new(
+                    span,
+                    "Pseudo-class or pseudo-element is expected.",
+                ));
+            }
+            let content;
+            braced!(content in input);
+            parse_nested(
+                &content,
+                conditions,
+                &selector,
+                blocks,
+                keyframes,
+                interpolations,
+            )?;
+        } else if input.peek(Token![@]) {
+            input.parse::<Token![@]>()?;
+            let (name, span) = parse_name(input)?;
+            if name == "keyframes" {
+                if !conditions.is_empty() || !selector.is_empty() {
+                    return Err(syn::Error::new(
+                        span,
+                        "@keyframes is only allowed at the top level.",
+                    ));
+                }
+                keyframes.push(parse_value::<Keyframes>(input)?);
+                continue;
+            }
+            let condition = match name.as_str() {
+                "media" => Condition::Media(parse_media_queries(input)?),
+                "supports" => Condition::Supports(parse_value::<SupportsCondition>(input)?),
+                _ => {
+                    return Err(syn::Error::new(
+                        span,
+                        format!("Unexpected at-rule @{}", name),
+                    ))
+                }
+            };
+            let mut conditions = conditions.to_vec();
+            conditions.push(condition);
+            let content;
+            braced!(content in input);
+            parse_nested(
+                &content,
+                &conditions,
+                selector,
+                blocks,
+                keyframes,
+                interpolations,
+            )?;
+        } else if Interpolations::peek(input) {
+            rules.push(interpolations.parse(input)?);
+        } else {
+            rules.push(input.parse::<Wrapper<StyleRule>>()?.0);
+        }
+    }
+    Ok(())
+}
+
+fn parse_nested(
+    input: ParseStream,
+    conditions: &[Condition],
+    selector: &[Pseudo],
+    blocks: &mut Vec<StyleBlock>,
+    keyframes: &mut Vec<Keyframes>,
+    interpolations: &mut Interpolations,
+) -> syn::Result<()> {
+    let mut rules = vec![];
+    let mut nested = vec![];
+    parse_body(
+        input,
+        conditions,
+        selector,
+        &mut rules,
+        &mut nested,
+        keyframes,
+        interpolations,
+    )?;
+    if !rules.is_empty() {
+        blocks.push(StyleBlock {
+            conditions: conditions.to_vec(),
+            selector: selector.to_vec(),
+            rules,
+        });
+    }
+    blocks.extend(nested);
+    Ok(())
+}
+
+impl Parse for Wrapper<Keyframes> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (name, _) = parse_name(input)?;
+        let content;
+        braced!(content in input);
+        let mut frames = vec![];
+        while !content.is_empty() {
+            let mut selectors = vec![parse_keyframe_selector(&content)?];
+            while content.parse::<Option<Token![,]>>()?.is_some() {
+                selectors.push(parse_keyframe_selector(&content)?);
+            }
+            let body;
+            braced!(body in content);
+            let mut rules = vec![];
+            while !body.is_empty() {
+                rules.push(body.parse::<Wrapper<StyleRule>>()?.0);
+            }
+            frames.push(Keyframe { selectors, rules });
+        }
+        Ok(Wrapper(Keyframes { name, frames }))
+    }
+}
+
+/// `from`, `to` or a percentage.
+fn parse_keyframe_selector(input: ParseStream) -> syn::Result<Parcentage> {
+    if peek_keyword(input, "from") || peek_keyword(input, "to") {
+        let (name, _) = parse_name(input)?;
+        return Ok(Parcentage(if name == "from" { 0. } else { 100. }));
+    }
+    let number = input.parse::<Number>()?;
+    if number.unit != "%" {
+        return Err(syn::Error::new(
+            number.span,
+            "`from`, `to` or a percentage is expected.",
+        ));
+    }
+    if !(0. ..=100.).contains(&number.value) {
+        return Err(syn::Error::new(number.span, "0% to 100% is expected."));
+    }
+    Ok(Parcentage(number.value))
+}
+
+fn parse_media_queries(input: ParseStream) -> syn::Result<Vec<MediaQuery>> {
+    let mut queries = vec![];
+    loop {
+        let mut query = MediaQuery {
+            media_type: None,
+            features: vec![],
+        };
+        if input.peek(Paren) {
+            query.features.push(parse_value::<MediaFeature>(input)?);
+        } else {
+            query.media_type = Some(parse_value::<MediaType>(input)?);
+        }
+        while peek_keyword(input, "and") {
+            parse_name(input)?;
+            query.features.push(parse_value::<MediaFeature>(input)?);
+        }
+        queries.push(query);
+        if input.parse::<Option<Token![,]>>()?.is_none() {
+            return Ok(queries);
+        }
+    }
+}
+
+impl Parse for Wrapper<MediaFeature> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let (name, span) = parse_name(&content)?;
+        content.parse::<Token![:]>()?;
+        let feature = match name.as_str() {
+            "min-width" => MediaFeature::MinWidth(parse_value::<Length>(&content)?),
+            "max-width" => MediaFeature::MaxWidth(parse_value::<Length>(&content)?),
+            "min-height" => MediaFeature::MinHeight(parse_value::<Length>(&content)?),
+            "max-height" => MediaFeature::MaxHeight(parse_value::<Length>(&content)?),
+            "orientation" => MediaFeature::Orientation(parse_value::<Orientation>(&content)?),
+            "prefers-color-scheme" => {
+                MediaFeature::PrefersColorScheme(parse_value::<ColorScheme>(&content)?)
+            }
+            "prefers-reduced-motion" => {
+                MediaFeature::PrefersReducedMotion(parse_value::<ReducedMotion>(&content)?)
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Unexpected media feature {}", name),
+                ))
+            }
+        };
+        if !content.is_empty() {
+            return Err(content.error("Unexpected token"));
+        }
+        Ok(Wrapper(feature))
+    }
+}
+
+impl ToTokens for Wrapper<&MediaFeature> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            MediaFeature::MinWidth(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::MinWidth(#value) }
+            }
+            MediaFeature::MaxWidth(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::MaxWidth(#value) }
+            }
+            MediaFeature::MinHeight(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::MinHeight(#value) }
+            }
+            MediaFeature::MaxHeight(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::MaxHeight(#value) }
+            }
+            MediaFeature::Orientation(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::Orientation(#value) }
+            }
+            MediaFeature::PrefersColorScheme(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::PrefersColorScheme(#value) }
+            }
+            MediaFeature::PrefersReducedMotion(value) => {
+                let value = Wrapper(value);
+                quote! { ::#sulafat_style::MediaFeature::PrefersReducedMotion(#value) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&MediaQuery> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let media_type = match &self.0.media_type {
+            Some(media_type) => {
+                let media_type = Wrapper(media_type);
+                quote! { ::std::option::Option::Some(#media_type) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        let features = self.0.features.iter().map(Wrapper);
+        tokens.extend(quote! {
+            ::#sulafat_style::MediaQuery {
+                media_type: #media_type,
+                features: ::std::vec![#(#features),*],
+            }
+        })
+    }
+}
+
+impl Parse for Wrapper<SupportsCondition> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if peek_keyword(input, "not") {
+            parse_name(input)?;
+            return Ok(Wrapper(SupportsCondition::Not(Box::new(
+                parse_supports_in_parens(input)?,
+            ))));
+        }
+        let first = parse_supports_in_parens(input)?;
+        let operator = if peek_keyword(input, "and") {
+            "and"
+        } else if peek_keyword(input, "or") {
+            "or"
+        } else {
+            return Ok(Wrapper(first));
+        };
+        let mut conditions = vec![first];
+        while peek_keyword(input, operator) {
+            parse_name(input)?;
+            conditions.push(parse_supports_in_parens(input)?);
+        }
+        if peek_keyword(input, "and") || peek_keyword(input, "or") {
+            return Err(input.error("`and` and `or` cannot be mixed without parentheses."));
+        }
+        Ok(Wrapper(if operator == "and" {
+            SupportsCondition::And(conditions)
+        } else {
+            SupportsCondition::Or(conditions)
+        }))
+    }
+}
+
+fn parse_supports_in_parens(input: ParseStream) -> syn::Result<SupportsCondition> {
+    let content;
+    parenthesized!(content in input);
+    let condition = if content.peek(Paren) || peek_keyword(&content, "not") {
+        parse_value::<SupportsCondition>(&content)?
+    } else {
+        SupportsCondition::Declaration(parse_declaration(&content)?)
+    };
+    if !content.is_empty() {
+        return Err(content.error("Unexpected token"));
+    }
+    Ok(condition)
+}
+
+impl ToTokens for Wrapper<&SupportsCondition> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            SupportsCondition::Declaration(rule) => {
+                let rule = Wrapper(rule);
+                quote! { ::#sulafat_style::SupportsCondition::Declaration(#rule) }
+            }
+            SupportsCondition::Not(condition) => {
+                let condition = Wrapper(&**condition);
+                quote! {
+                    ::#sulafat_style::SupportsCondition::Not(::std::boxed::Box::new(#condition))
+                }
+            }
+            SupportsCondition::And(conditions) => {
+                let conditions = conditions.iter().map(Wrapper);
+                quote! { ::#sulafat_style::SupportsCondition::And(::std::vec![#(#conditions),*]) }
+            }
+            SupportsCondition::Or(conditions) => {
+                let conditions = conditions.iter().map(Wrapper);
+                quote! { ::#sulafat_style::SupportsCondition::Or(::std::vec![#(#conditions),*]) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&Condition> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            Condition::Media(queries) => {
+                let queries = queries.iter().map(Wrapper);
+                quote! { ::#sulafat_style::Condition::Media(::std::vec![#(#queries),*]) }
+            }
+            Condition::Supports(condition) => {
+                let condition = Wrapper(condition);
+                quote! { ::#sulafat_style::Condition::Supports(#condition) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&Pseudo> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            Pseudo::Class(name) => {
+                quote! { ::#sulafat_style::Pseudo::Class(::std::string::String::from(#name)) }
+            }
+            Pseudo::Element(name) => {
+                quote! { ::#sulafat_style::Pseudo::Element(::std::string::String::from(#name)) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&StyleBlock> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let conditions = self.0.conditions.iter().map(Wrapper);
+        let selector = self.0.selector.iter().map(Wrapper);
+        let rules = self.0.rules.iter().map(Wrapper);
+        tokens.extend(quote! {
+            ::#sulafat_style::StyleBlock {
+                conditions: ::std::vec![#(#conditions),*],
+                selector: ::std::vec![#(#selector),*],
+                rules: ::std::vec![#(#rules),*],
+            }
+        })
+    }
+}
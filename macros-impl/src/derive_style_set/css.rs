@@ -0,0 +1,109 @@
+use sulafat_style::StyleBody;
+use syn::LitStr;
+
+/// A body written as CSS text, e.g. `#[style_set(r#"writing-mode: vertical-rl;"#)]`.
+///
+/// Fields cannot be interpolated into CSS text, which has no tokens to tell `{field}`
+/// from a block; declarations using them are to be written as tokens.
+pub(super) fn parse_block(lit: &LitStr) -> syn::Result<StyleBody> {
+    let css = lit.value();
+    sulafat_style::parse_block(&css).map_err(|error| {
+        let offset = error.offset();
+        if css[offset..].starts_with('{') {
+            error_in(
+                lit,
+                &css,
+                offset,
+                "Interpolation is not supported in CSS text. Write the declaration as tokens to use `{field}`.",
+            )
+        } else {
+            error_in(lit, &css, offset, error.message())
+        }
+    })
+}
+
+/// Points at the offending text inside the literal where the compiler allows it, and
+/// names its line and column in the literal as written otherwise.
+fn error_in(lit: &LitStr, css: &str, offset: usize, message: &str) -> syn::Error {
+    let token = lit.token();
+    let repr = token.to_string();
+    let start = source_offset(&repr, offset);
+    let before = &repr[..start];
+    let line = before.matches('\n').count() + 1;
+    let column = match before.rfind('\n') {
+        Some(newline) => before[newline + 1..].chars().count() + 1,
+        None => before.chars().count() - repr.find('"').unwrap_or(0),
+    };
+    let len = css[offset..]
+        .chars()
+        .next()
+        .map_or(0, char::len_utf8)
+        .max(1);
+    let span = token.subspan(start..start + len);
+    syn::Error::new(
+        span.unwrap_or_else(|| lit.span()),
+        format!("{} (line {}, column {})", message, line, column),
+    )
+}
+
+/// The byte offset in the source of a string literal `repr` of the byte `offset` in its
+/// value, going through the escapes of a non-raw literal.
+fn source_offset(repr: &str, offset: usize) -> usize {
+    let quote = repr.find('"').unwrap_or(0) + 1;
+    if repr.starts_with('r') {
+        return quote + offset;
+    }
+    let mut value = 0;
+    let mut chars = repr[quote..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let len = if c != '\\' {
+            c.len_utf8()
+        } else {
+            match chars.next() {
+                Some((_, 'x')) => {
+                    chars.nth(1);
+                    1
+                }
+                Some((_, 'u')) => {
+                    let mut code = String::new();
+                    for (_, c) in &mut chars {
+                        match c {
+                            '{' => {}
+                            '}' => break,
+                            c => code.push(c),
+                        }
+                    }
+                    u32::from_str_radix(&code, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .map_or(1, char::len_utf8)
+                }
+                Some((_, '\n')) | Some((_, '\r')) => {
+                    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                    0
+                }
+                _ => 1,
+            }
+        };
+        // A line continuation stands for nothing, so the offset is at the text after it.
+        if len > 0 && value >= offset {
+            return quote + i;
+        }
+        value += len;
+    }
+    repr.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::source_offset;
+
+    #[test]
+    fn offsets() {
+        assert_eq!(source_offset(r##"r#"a: b"#"##, 3), 6);
+        assert_eq!(source_offset(r#""a: b""#, 3), 4);
+        assert_eq!(source_offset(r#""\"a\": b""#, 4), 7);
+        assert_eq!(source_offset(r#""\x41\u{3042}: b""#, 6), 15);
+        assert_eq!(source_offset("\"a: \\\n    b\"", 3), 10);
+    }
+}
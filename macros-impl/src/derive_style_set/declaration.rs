@@ -0,0 +1,151 @@
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use sulafat_style::StyleRule;
+use syn::{ext::IdentExt, parse::ParseStream, Lit, Token};
+
+/// CSS text reassembled from tokens, remembering which token each byte came from.
+struct Css {
+    text: String,
+    spans: Vec<(usize, Span)>,
+    prev: Prev,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Prev {
+    None,
+    Ident,
+    Word,
+    Glue,
+    Operator,
+}
+
+impl Css {
+    fn push(&mut self, text: &str, span: Span, space: bool) {
+        if space {
+            self.text.push(' ');
+        }
+        self.spans.push((self.text.len(), span));
+        self.text.push_str(text);
+    }
+
+    /// `math` is set inside `calc()`, `min()`, `max()` and `clamp()`, where `+` and `-`
+    /// between values are operators that CSS requires to be surrounded by spaces.
+    fn write(&mut self, tokens: TokenStream, math: bool) -> syn::Result<()> {
+        let mut tokens = tokens.into_iter().peekable();
+        let mut function = None;
+        while let Some(token) = tokens.next() {
+            let previous = function.take();
+            match token {
+                TokenTree::Group(group) => match group.delimiter() {
+                    Delimiter::Parenthesis => {
+                        self.push("(", group.span_open(), self.prev == Prev::Operator);
+                        self.prev = Prev::Glue;
+                        let math =
+                            math || matches!(previous, Some("calc" | "min" | "max" | "clamp"));
+                        self.write(group.stream(), math)?;
+                        self.push(")", group.span_close(), false);
+                        self.prev = Prev::Word;
+                    }
+                    Delimiter::None => self.write(group.stream(), math)?,
+                    _ => return Err(syn::Error::new(group.span(), "Unexpected token")),
+                },
+                TokenTree::Ident(ident) => {
+                    let space = self.prev != Prev::None && self.prev != Prev::Glue;
+                    let name = ident.unraw().to_string();
+                    self.push(&name, ident.span(), space);
+                    self.prev = Prev::Ident;
+                    function = ["calc", "min", "max", "clamp"]
+                        .iter()
+                        .copied()
+                        .find(|function| name.eq_ignore_ascii_case(function));
+                }
+                TokenTree::Literal(literal) => {
+                    let span = literal.span();
+                    let text = match Lit::new(literal) {
+                        Lit::Str(string) => quote_string(&string.value()),
+                        literal @ Lit::Int(_) | literal @ Lit::Float(_) => {
+                            literal.into_token_stream().to_string()
+                        }
+                        _ => return Err(syn::Error::new(span, "Unexpected token")),
+                    };
+                    let space = self.prev != Prev::None && self.prev != Prev::Glue;
+                    self.push(&text, span, space);
+                    self.prev = Prev::Word;
+                }
+                TokenTree::Punct(punct) => {
+                    let c = punct.as_char();
+                    // `vertical - rl` is a single name, while `span -2` is a name and a number.
+                    let joins = c == '-'
+                        && self.prev == Prev::Ident
+                        && matches!(tokens.peek(), Some(TokenTree::Ident(_)));
+                    if math
+                        && !joins
+                        && (c == '+' || c == '-')
+                        && (self.prev == Prev::Ident || self.prev == Prev::Word)
+                    {
+                        self.push(&c.to_string(), punct.span(), true);
+                        self.prev = Prev::Operator;
+                        continue;
+                    }
+                    let space = match c {
+                        '%' | ',' => false,
+                        '-' => !joins && self.prev != Prev::Glue && self.prev != Prev::None,
+                        _ => self.prev == Prev::Ident || self.prev == Prev::Word,
+                    };
+                    self.push(&c.to_string(), punct.span(), space);
+                    self.prev = match c {
+                        '%' | '/' => Prev::Word,
+                        _ => Prev::Glue,
+                    };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn span(&self, offset: usize) -> Span {
+        self.spans
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= offset)
+            .map(|(_, span)| *span)
+            .unwrap_or_else(Span::call_site)
+    }
+}
+
+fn quote_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\a "),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// `name: value` without the trailing `;`, parsed by [`sulafat_style::parse_declaration`]
+/// so that the derive accepts exactly what the runtime parser does.
+pub(super) fn parse_declaration(input: ParseStream) -> syn::Result<StyleRule> {
+    let span = input.span();
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() && !input.peek(Token![;]) {
+        tokens.extend(Some(input.parse::<TokenTree>()?));
+    }
+    if tokens.is_empty() {
+        return Err(syn::Error::new(span, "Declaration is expected."));
+    }
+    let mut css = Css {
+        text: String::new(),
+        spans: vec![],
+        prev: Prev::None,
+    };
+    css.write(tokens, false)?;
+    sulafat_style::parse_declaration(&css.text)
+        .map_err(|error| syn::Error::new(css.span(error.offset()), error.message()))
+}
@@ -0,0 +1,191 @@
+use super::value::parse_name;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use sulafat_style::{is_property, StyleRule};
+use syn::{parse::ParseStream, parse2, Member, Token};
+
+/// Declarations whose value refers to fields of the deriving struct, e.g.
+/// `left: {x}px;`. Each one becomes `left:var(--<prefix>-<n>);` in the static CSS and
+/// a formatted value in `StyleSet::variables`.
+pub(super) struct Interpolations {
+    prefix: String,
+    list: Vec<Interpolation>,
+}
+
+struct Interpolation {
+    name: String,
+    format: String,
+    args: Vec<Member>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Prev {
+    None,
+    Word,
+    Placeholder,
+    Glue,
+}
+
+impl Interpolations {
+    pub(super) fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            list: vec![],
+        }
+    }
+
+    /// Whether `name` is the custom property of one of the declarations.
+    pub(super) fn contains(&self, name: &str) -> bool {
+        self.list
+            .iter()
+            .any(|interpolation| interpolation.name == name)
+    }
+
+    /// Whether the declaration at the head of `input` contains `{field}`.
+    pub(super) fn peek(input: ParseStream) -> bool {
+        fn contains(tokens: TokenStream) -> bool {
+            tokens.into_iter().any(|token| match token {
+                TokenTree::Group(group) => {
+                    group.delimiter() == Delimiter::Brace || contains(group.stream())
+                }
+                _ => false,
+            })
+        }
+        let fork = input.fork();
+        let mut tokens = TokenStream::new();
+        while !fork.is_empty() && !fork.peek(Token![;]) {
+            match fork.parse::<TokenTree>() {
+                Ok(token) => tokens.extend(Some(token)),
+                Err(_) => return false,
+            }
+        }
+        contains(tokens)
+    }
+
+    /// `name: value;` where `value` contains `{field}`.
+    pub(super) fn parse(&mut self, input: ParseStream) -> syn::Result<StyleRule> {
+        let (property, span) = parse_name(input)?;
+        if !is_property(&property) {
+            return Err(syn::Error::new(
+                span,
+                format!("Unexpected rule name {}", property),
+            ));
+        }
+        input.parse::<Token![:]>()?;
+        let mut tokens = TokenStream::new();
+        while !input.is_empty() && !input.peek(Token![;]) {
+            tokens.extend(Some(input.parse::<TokenTree>()?));
+        }
+        input.parse::<Token![;]>()?;
+        let mut format = String::new();
+        let mut args = vec![];
+        write_tokens(tokens, &mut format, &mut args, Prev::None, false)?;
+        let name = format!("{}-{}", self.prefix, self.list.len());
+        self.list.push(Interpolation {
+            name: name.clone(),
+            format,
+            args,
+        });
+        Ok(StyleRule::Var(property, name))
+    }
+}
+
+/// Reassembles CSS text from tokens. A unit directly follows a placeholder
+/// (`{x}px`), hyphens join their neighbours as in `vertical - rl`, and a function name
+/// is joined to its arguments. Inside `calc()`, `min()`, `max()` and `clamp()`, `+`, `-`
+/// and `*` between values are spaced as operators.
+fn write_tokens(
+    tokens: TokenStream,
+    format: &mut String,
+    args: &mut Vec<Member>,
+    mut prev: Prev,
+    math: bool,
+) -> syn::Result<()> {
+    let mut function = None;
+    for token in tokens {
+        let previous = function.take();
+        match token {
+            TokenTree::Group(group) => match group.delimiter() {
+                Delimiter::Brace => {
+                    if prev == Prev::Word || prev == Prev::Placeholder {
+                        format.push(' ');
+                    }
+                    args.push(parse2::<Member>(group.stream())?);
+                    format.push_str("{}");
+                    prev = Prev::Placeholder;
+                }
+                Delimiter::Parenthesis => {
+                    if prev == Prev::Placeholder {
+                        format.push(' ');
+                    }
+                    format.push('(');
+                    let math = math
+                        || matches!(previous.as_deref(), Some("calc" | "min" | "max" | "clamp"));
+                    write_tokens(group.stream(), format, args, Prev::None, math)?;
+                    format.push(')');
+                    prev = Prev::Word;
+                }
+                _ => return Err(syn::Error::new(group.span(), "Unexpected token")),
+            },
+            TokenTree::Ident(ident) => {
+                if prev == Prev::Word {
+                    format.push(' ');
+                }
+                format.push_str(&ident.to_string());
+                function = Some(ident.to_string().to_ascii_lowercase());
+                prev = Prev::Word;
+            }
+            TokenTree::Literal(literal) => {
+                if prev == Prev::Word || prev == Prev::Placeholder {
+                    format.push(' ');
+                }
+                format.push_str(&literal.to_string().replace('{', "{{").replace('}', "}}"));
+                prev = Prev::Word;
+            }
+            TokenTree::Punct(punct) => match punct.as_char() {
+                '%' => {
+                    format.push('%');
+                    prev = Prev::Word;
+                }
+                '/' => {
+                    format.push_str(" / ");
+                    prev = Prev::Glue;
+                }
+                '#' => {
+                    if prev == Prev::Word || prev == Prev::Placeholder {
+                        format.push(' ');
+                    }
+                    format.push('#');
+                    prev = Prev::Glue;
+                }
+                c @ '+' | c @ '-' | c @ '*'
+                    if math && (prev == Prev::Word || prev == Prev::Placeholder) =>
+                {
+                    format.push(' ');
+                    format.push(c);
+                    format.push(' ');
+                    prev = Prev::Glue;
+                }
+                c @ ',' | c @ '-' | c @ '.' => {
+                    format.push(c);
+                    prev = Prev::Glue;
+                }
+                _ => return Err(syn::Error::new(punct.span(), "Unexpected token")),
+            },
+        }
+    }
+    Ok(())
+}
+
+impl ToTokens for Interpolations {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for Interpolation { name, format, args } in &self.list {
+            tokens.extend(quote! {
+                (
+                    ::std::string::String::from(#name),
+                    ::std::format!(#format, #(self.#args),*),
+                ),
+            })
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::Wrapper;
+use crate::util::crate_name;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use sulafat_style::{
+    Gap, GridArea, GridLine, GridTemplate, TrackBreadth, TrackListItem, TrackRepeat, TrackSize,
+};
+
+impl ToTokens for Wrapper<&f64> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&Gap> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let row = Wrapper(&self.0.row);
+        let column = Wrapper(&self.0.column);
+        tokens.extend(quote! {
+            ::#sulafat_style::Gap {
+                row: #row,
+                column: #column,
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TrackBreadth> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TrackBreadth::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::TrackBreadth::Length(#length) }
+            }
+            TrackBreadth::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::TrackBreadth::Parcentage(#parcentage) }
+            }
+            TrackBreadth::Fr(fr) => quote! { ::#sulafat_style::TrackBreadth::Fr(#fr) },
+            TrackBreadth::Auto => quote! { ::#sulafat_style::TrackBreadth::Auto },
+            TrackBreadth::MinContent => quote! { ::#sulafat_style::TrackBreadth::MinContent },
+            TrackBreadth::MaxContent => quote! { ::#sulafat_style::TrackBreadth::MaxContent },
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TrackSize> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TrackSize::Breadth(breadth) => {
+                let breadth = Wrapper(breadth);
+                quote! { ::#sulafat_style::TrackSize::Breadth(#breadth) }
+            }
+            TrackSize::MinMax(min, max) => {
+                let min = Wrapper(min);
+                let max = Wrapper(max);
+                quote! { ::#sulafat_style::TrackSize::MinMax(#min, #max) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TrackRepeat> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TrackRepeat::Count(count) => quote! { ::#sulafat_style::TrackRepeat::Count(#count) },
+            TrackRepeat::AutoFill => quote! { ::#sulafat_style::TrackRepeat::AutoFill },
+            TrackRepeat::AutoFit => quote! { ::#sulafat_style::TrackRepeat::AutoFit },
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TrackListItem> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TrackListItem::Size(size) => {
+                let size = Wrapper(size);
+                quote! { ::#sulafat_style::TrackListItem::Size(#size) }
+            }
+            TrackListItem::Repeat(repeat, sizes) => {
+                let repeat = Wrapper(repeat);
+                let sizes = sizes.iter().map(Wrapper);
+                quote! {
+                    ::#sulafat_style::TrackListItem::Repeat(#repeat, ::std::vec![#(#sizes),*])
+                }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&GridTemplate> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            GridTemplate::None => quote! { ::#sulafat_style::GridTemplate::None },
+            GridTemplate::Tracks(tracks) => {
+                let tracks = tracks.iter().map(Wrapper);
+                quote! { ::#sulafat_style::GridTemplate::Tracks(::std::vec![#(#tracks),*]) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&GridLine> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            GridLine::Auto => quote! { ::#sulafat_style::GridLine::Auto },
+            GridLine::Index(index) => quote! { ::#sulafat_style::GridLine::Index(#index) },
+            GridLine::Span(span) => quote! { ::#sulafat_style::GridLine::Span(#span) },
+            GridLine::Name(name) => {
+                quote! { ::#sulafat_style::GridLine::Name(::std::string::String::from(#name)) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&GridArea> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let lines = self.0 .0.iter().map(Wrapper);
+        tokens.extend(quote! { ::#sulafat_style::GridArea(::std::vec![#(#lines),*]) })
+    }
+}
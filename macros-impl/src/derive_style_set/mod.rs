@@ -0,0 +1,283 @@
+mod animation;
+mod block;
+mod css;
+mod declaration;
+mod interpolation;
+mod layout;
+mod rule;
+mod typography;
+mod value;
+
+use heck::SnakeCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use std::cell::Cell;
+use sulafat_style::{Keyframes, StyleBlock, StyleBody, StyleRule};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse2, Attribute, ItemStruct, LitStr, Token,
+};
+
+use crate::util::crate_name;
+use interpolation::Interpolations;
+
+#[cfg(feature = "export-css")]
+use {
+    crate::util::out_dir,
+    std::{
+        cell::RefCell,
+        fs::File,
+        io::{BufWriter, Write},
+        path::Path,
+    },
+    sulafat_style::{CSSRenderer, StyleRenderer},
+};
+
+pub(crate) struct Wrapper<T>(pub(crate) T);
+
+impl<T> Wrapper<T> {
+    fn as_ref(&self) -> Wrapper<&T> {
+        Wrapper(&self.0)
+    }
+}
+
+pub struct StyleRules {
+    name: String,
+    extends: Vec<syn::Path>,
+    rules: Vec<StyleRule>,
+    blocks: Vec<StyleBlock>,
+    keyframes: Vec<Keyframes>,
+    interpolations: Interpolations,
+}
+
+fn generate_name() -> String {
+    thread_local! {
+        static I: Cell<u64> = const { Cell::new(0) };
+    }
+    format!(
+        "sulafat-{}",
+        I.with(|i| {
+            let j = i.get();
+            i.set(j + 1);
+            j
+        })
+    )
+}
+
+impl Parse for StyleRules {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let buffer;
+        let mut extends = vec![];
+        let (name, input) = if input.parse::<Token![.]>().is_ok() {
+            let ident = input.parse::<Ident>()?;
+            if value::peek_keyword(input, "extends") {
+                input.parse::<Ident>()?;
+                loop {
+                    extends.push(input.parse::<syn::Path>()?);
+                    if input.parse::<Token![,]>().is_err() {
+                        break;
+                    }
+                }
+            }
+            braced!(buffer in input);
+            (ident.to_string(), &buffer)
+        } else {
+            (generate_name(), input)
+        };
+        let mut interpolations = Interpolations::new(&name);
+        let StyleBody {
+            rules,
+            blocks,
+            keyframes,
+        } = if input.peek(LitStr) {
+            let lit = input.parse::<LitStr>()?;
+            if !input.is_empty() {
+                return Err(input.error("Unexpected token"));
+            }
+            css::parse_block(&lit)?
+        } else {
+            let mut rules = vec![];
+            let mut blocks = vec![];
+            let mut keyframes = vec![];
+            block::parse_body(
+                input,
+                &[],
+                &[],
+                &mut rules,
+                &mut blocks,
+                &mut keyframes,
+                &mut interpolations,
+            )?;
+            StyleBody {
+                rules,
+                blocks,
+                keyframes,
+            }
+        };
+        Ok(Self {
+            name,
+            extends,
+            rules,
+            blocks,
+            keyframes,
+            interpolations,
+        })
+    }
+}
+
+impl ToTokens for StyleRules {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for rule in &self.rules {
+            let rule = Wrapper(rule);
+            tokens.extend(quote! {
+                #rule,
+            })
+        }
+    }
+}
+
+fn parse_attrs(attrs: &[Attribute]) -> syn::Result<StyleRules> {
+    for attr in attrs {
+        let path = &attr.path;
+        if path.leading_colon.is_none()
+            && path.segments.len() == 1
+            && path.segments[0].ident == "style_set"
+        {
+            return attr.parse_args();
+        }
+    }
+    let name = generate_name();
+    Ok(StyleRules {
+        interpolations: Interpolations::new(&name),
+        name,
+        extends: vec![],
+        rules: vec![],
+        blocks: vec![],
+        keyframes: vec![],
+    })
+}
+
+/// `#[theme(Tokens)]` checks that every `var(--name)` in the style set is a field of
+/// `Tokens`, so that a missing token is a compile error rather than an unset property.
+fn theme_check(attrs: &[Attribute], rules: &StyleRules) -> syn::Result<TokenStream> {
+    let theme = match attrs.iter().find(|attr| attr.path.is_ident("theme")) {
+        Some(attr) => attr.parse_args::<syn::Path>()?,
+        None => return Ok(TokenStream::new()),
+    };
+    let span = theme.segments.last().unwrap().ident.span();
+    let all = rules
+        .rules
+        .iter()
+        .chain(rules.blocks.iter().flat_map(|block| &block.rules))
+        .chain(
+            rules
+                .keyframes
+                .iter()
+                .flat_map(|keyframes| &keyframes.frames)
+                .flat_map(|frame| &frame.rules),
+        );
+    let mut fields = vec![];
+    for rule in all {
+        match rule {
+            StyleRule::Var(_, name) if !rules.interpolations.contains(name) => {
+                let field = syn::parse_str::<Ident>(&name.to_snake_case()).map_err(|_| {
+                    syn::Error::new(span, format!("--{} is not a token of the theme.", name))
+                })?;
+                fields.push(Ident::new(&field.to_string(), span));
+            }
+            _ => {}
+        }
+    }
+    Ok(quote! {
+        const _: fn(&#theme) = |theme| {
+            #(let _ = &theme.#fields;)*
+        };
+    })
+}
+
+#[cfg(feature = "export-css")]
+fn file<F: FnOnce(&mut BufWriter<File>)>(path: &Path, f: F) {
+    thread_local! {
+        static FILE : RefCell<Option<BufWriter<File>>> = const { RefCell::new(None) };
+    }
+    FILE.with(|cell| {
+        let mut borrow = cell.borrow_mut();
+        f(borrow.get_or_insert_with(|| {
+            let file = File::create(path).unwrap();
+            BufWriter::new(file)
+        }))
+    })
+}
+
+fn derive_style_set_impl(items: TokenStream) -> syn::Result<TokenStream> {
+    let item = parse2::<ItemStruct>(items)?;
+    let ident = &item.ident;
+    let rules = parse_attrs(&item.attrs)?;
+    let theme = theme_check(&item.attrs, &rules)?;
+    let name = &rules.name;
+    #[cfg(feature = "export-css")]
+    if !rules.rules.is_empty() || !rules.blocks.is_empty() || !rules.keyframes.is_empty() {
+        if let Some(path) = out_dir() {
+            let path = Path::new(&path).join("style.css");
+            let mut renderer = CSSRenderer::default();
+            renderer.name(name);
+            for rule in &rules.rules {
+                renderer.render(rule);
+            }
+            for block in &rules.blocks {
+                renderer.block(block);
+            }
+            for keyframes in &rules.keyframes {
+                renderer.keyframes(keyframes);
+            }
+            let css = renderer.finish();
+            file(&path, |writer| write!(writer, "{}", css).unwrap())
+        }
+    }
+    let blocks = rules.blocks.iter().map(Wrapper);
+    let keyframes = rules.keyframes.iter().map(Wrapper);
+    let extends = &rules.extends;
+    let interpolations = &rules.interpolations;
+    let sulafat_style = crate_name("sulafat-style");
+    Ok(quote! {
+        impl ::#sulafat_style::StyleSet for #ident {
+            fn name() -> String {
+                #name.to_string()
+            }
+            fn rules() -> &'static [::#sulafat_style::StyleRule] {
+                static RULES: ::std::sync::OnceLock<::std::vec::Vec<::#sulafat_style::StyleRule>> =
+                    ::std::sync::OnceLock::new();
+                RULES.get_or_init(|| ::std::vec![#rules])
+            }
+            fn blocks() -> &'static [::#sulafat_style::StyleBlock] {
+                static BLOCKS: ::std::sync::OnceLock<::std::vec::Vec<::#sulafat_style::StyleBlock>> =
+                    ::std::sync::OnceLock::new();
+                BLOCKS.get_or_init(|| ::std::vec![#(#blocks),*])
+            }
+            fn keyframes() -> &'static [::#sulafat_style::Keyframes] {
+                static KEYFRAMES: ::std::sync::OnceLock<::std::vec::Vec<::#sulafat_style::Keyframes>> =
+                    ::std::sync::OnceLock::new();
+                KEYFRAMES.get_or_init(|| ::std::vec![#(#keyframes),*])
+            }
+            fn extends<V: ::#sulafat_style::StyleSetVisitor>(visitor: &mut V) {
+                #(visitor.visit::<#extends>();)*
+            }
+            fn variables(&self) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                ::std::vec![#interpolations]
+            }
+        }
+        const _: () = {
+            thread_local! {
+                static A: () = {
+                    ::#sulafat_style::export::<#ident>();
+                };
+            }
+        };
+        #theme
+    })
+}
+
+pub fn derive_style_set(items: TokenStream) -> TokenStream {
+    derive_style_set_impl(items).unwrap_or_else(|e| e.into_compile_error())
+}
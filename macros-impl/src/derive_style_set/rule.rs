@@ -0,0 +1,134 @@
+use super::{declaration::parse_declaration, Wrapper};
+use crate::util::crate_name;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use sulafat_style::StyleRule;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+macro_rules! style_rules {
+    ($($variant:ident,)*) => {
+        impl ToTokens for Wrapper<&StyleRule> {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                let sulafat_style = crate_name("sulafat-style");
+                tokens.extend(match &self.0 {
+                    $(StyleRule::$variant(value) => {
+                        let value = Wrapper(value);
+                        quote! { ::#sulafat_style::StyleRule::$variant(#value) }
+                    })*
+                    StyleRule::Var(property, name) => quote! {
+                        ::#sulafat_style::StyleRule::Var(
+                            ::std::string::String::from(#property),
+                            ::std::string::String::from(#name),
+                        )
+                    },
+                })
+            }
+        }
+    };
+}
+
+style_rules! {
+    Left,
+    Right,
+    WritingMode,
+    Top,
+    Bottom,
+    Position,
+    ZIndex,
+    Width,
+    Height,
+    MinWidth,
+    MinHeight,
+    MaxWidth,
+    MaxHeight,
+    BoxSizing,
+    Margin,
+    MarginTop,
+    MarginRight,
+    MarginBottom,
+    MarginLeft,
+    Padding,
+    PaddingTop,
+    PaddingRight,
+    PaddingBottom,
+    PaddingLeft,
+    Border,
+    BorderWidth,
+    BorderStyle,
+    Display,
+    Visibility,
+    Overflow,
+    OverflowX,
+    OverflowY,
+    Color,
+    BackgroundColor,
+    BorderColor,
+    FlexDirection,
+    FlexWrap,
+    JustifyContent,
+    AlignItems,
+    Gap,
+    RowGap,
+    ColumnGap,
+    FlexGrow,
+    FlexShrink,
+    FlexBasis,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridArea,
+    TextOrientation,
+    TextCombineUpright,
+    FontFamily,
+    FontSize,
+    FontWeight,
+    LineHeight,
+    LetterSpacing,
+    TextAlign,
+    TextEmphasis,
+    RubyPosition,
+    Direction,
+    InlineSize,
+    BlockSize,
+    MinInlineSize,
+    MinBlockSize,
+    MaxInlineSize,
+    MaxBlockSize,
+    MarginInline,
+    MarginInlineStart,
+    MarginInlineEnd,
+    MarginBlock,
+    MarginBlockStart,
+    MarginBlockEnd,
+    PaddingInline,
+    PaddingInlineStart,
+    PaddingInlineEnd,
+    PaddingBlock,
+    PaddingBlockStart,
+    PaddingBlockEnd,
+    InsetInline,
+    InsetInlineStart,
+    InsetInlineEnd,
+    InsetBlock,
+    InsetBlockStart,
+    InsetBlockEnd,
+    Opacity,
+    Transition,
+    Animation,
+}
+
+impl Parse for Wrapper<StyleRule> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let rule = parse_declaration(input)?;
+        input.parse::<Token![;]>()?;
+        Ok(Self(rule))
+    }
+}
+
+impl ToTokens for Wrapper<StyleRule> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
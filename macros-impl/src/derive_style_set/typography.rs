@@ -0,0 +1,142 @@
+use super::{value::option_tokens, Wrapper};
+use crate::util::crate_name;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use sulafat_style::{
+    FamilyName, FontFamily, FontSize, FontWeight, LetterSpacing, LineHeight, TextCombineUpright,
+    TextEmphasis, TextEmphasisStyle,
+};
+
+impl ToTokens for Wrapper<&TextCombineUpright> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            TextCombineUpright::None => quote! { ::#sulafat_style::TextCombineUpright::None },
+            TextCombineUpright::All => quote! { ::#sulafat_style::TextCombineUpright::All },
+            TextCombineUpright::Digits(digits) => {
+                quote! { ::#sulafat_style::TextCombineUpright::Digits(#digits) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&FamilyName> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            FamilyName::Generic(generic) => {
+                let generic = Wrapper(generic);
+                quote! { ::#sulafat_style::FamilyName::Generic(#generic) }
+            }
+            FamilyName::Named(name) => {
+                quote! { ::#sulafat_style::FamilyName::Named(::std::string::String::from(#name)) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&FontFamily> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let names = self.0 .0.iter().map(Wrapper);
+        tokens.extend(quote! { ::#sulafat_style::FontFamily(::std::vec![#(#names),*]) })
+    }
+}
+
+impl ToTokens for Wrapper<&FontSize> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            FontSize::XxSmall => quote! { ::#sulafat_style::FontSize::XxSmall },
+            FontSize::XSmall => quote! { ::#sulafat_style::FontSize::XSmall },
+            FontSize::Small => quote! { ::#sulafat_style::FontSize::Small },
+            FontSize::Medium => quote! { ::#sulafat_style::FontSize::Medium },
+            FontSize::Large => quote! { ::#sulafat_style::FontSize::Large },
+            FontSize::XLarge => quote! { ::#sulafat_style::FontSize::XLarge },
+            FontSize::XxLarge => quote! { ::#sulafat_style::FontSize::XxLarge },
+            FontSize::Smaller => quote! { ::#sulafat_style::FontSize::Smaller },
+            FontSize::Larger => quote! { ::#sulafat_style::FontSize::Larger },
+            FontSize::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::FontSize::Length(#length) }
+            }
+            FontSize::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::FontSize::Parcentage(#parcentage) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&FontWeight> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            FontWeight::Normal => quote! { ::#sulafat_style::FontWeight::Normal },
+            FontWeight::Bold => quote! { ::#sulafat_style::FontWeight::Bold },
+            FontWeight::Bolder => quote! { ::#sulafat_style::FontWeight::Bolder },
+            FontWeight::Lighter => quote! { ::#sulafat_style::FontWeight::Lighter },
+            FontWeight::Number(weight) => quote! { ::#sulafat_style::FontWeight::Number(#weight) },
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&LineHeight> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            LineHeight::Normal => quote! { ::#sulafat_style::LineHeight::Normal },
+            LineHeight::Number(number) => quote! { ::#sulafat_style::LineHeight::Number(#number) },
+            LineHeight::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::LineHeight::Length(#length) }
+            }
+            LineHeight::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::LineHeight::Parcentage(#parcentage) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&LetterSpacing> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            LetterSpacing::Normal => quote! { ::#sulafat_style::LetterSpacing::Normal },
+            LetterSpacing::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::LetterSpacing::Length(#length) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<&TextEmphasis> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let style = match &self.0.style {
+            TextEmphasisStyle::None => quote! { ::#sulafat_style::TextEmphasisStyle::None },
+            TextEmphasisStyle::Shape { fill, shape } => {
+                let fill = option_tokens(fill.as_ref().map(Wrapper));
+                let shape = option_tokens(shape.as_ref().map(Wrapper));
+                quote! {
+                    ::#sulafat_style::TextEmphasisStyle::Shape {
+                        fill: #fill,
+                        shape: #shape,
+                    }
+                }
+            }
+            TextEmphasisStyle::String(string) => quote! {
+                ::#sulafat_style::TextEmphasisStyle::String(::std::string::String::from(#string))
+            },
+        };
+        let color = option_tokens(self.0.color.as_ref().map(Wrapper));
+        tokens.extend(quote! {
+            ::#sulafat_style::TextEmphasis {
+                style: #style,
+                color: #color,
+            }
+        })
+    }
+}
@@ -0,0 +1,501 @@
+use super::Wrapper;
+use crate::util::crate_name;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use sulafat_style::{
+    AlignItems, AnimationDirection, AnimationFillMode, AnimationPlayState, Border, BorderStyle,
+    BoxSizing, Calc, Color, ColorScheme, Direction, DisplayType, FlexDirection, FlexWrap,
+    GenericFamily, JustifyContent, Length, LengthOrPercentage, LengthOrPercentageOrAuto,
+    LengthOrPercentageOrNone, LineWidth, LogicalPair, MediaType, Orientation, Overflow, Parcentage,
+    Position, ReducedMotion, Rgba, RubyPosition, Sides, StepPosition, TextAlign, TextEmphasisFill,
+    TextEmphasisShape, TextOrientation, Visibility, WritingMode, ZIndex,
+};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Lit, Token,
+};
+
+pub(super) fn parse_value<T>(input: ParseStream) -> syn::Result<T>
+where
+    Wrapper<T>: Parse,
+{
+    Ok(input.parse::<Wrapper<T>>()?.0)
+}
+
+pub(super) fn parse_name(input: ParseStream) -> syn::Result<(String, Span)> {
+    let ident = Ident::parse_any(input)?;
+    let span = ident.span();
+    let mut name = ident.unraw().to_string();
+    while input.peek(Token![-]) && input.peek2(Ident::peek_any) {
+        input.parse::<Token![-]>()?;
+        name.push('-');
+        name.push_str(&Ident::parse_any(input)?.unraw().to_string());
+    }
+    Ok((name, span))
+}
+
+pub(super) fn peek_keyword(input: ParseStream, keyword: &str) -> bool {
+    let fork = input.fork();
+    matches!(parse_name(&fork), Ok((name, _)) if name == keyword)
+}
+
+pub(super) struct Number {
+    pub(super) value: f64,
+    pub(super) unit: String,
+    pub(super) span: Span,
+}
+
+impl Parse for Number {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let sign = if input.parse::<Option<Token![-]>>()?.is_some() {
+            -1.
+        } else {
+            1.
+        };
+        let lit = input.parse::<Lit>()?;
+        let span = lit.span();
+        let (value, unit) = match &lit {
+            Lit::Int(lit) => (lit.base10_parse::<f64>()?, lit.suffix().to_string()),
+            Lit::Float(lit) => (lit.base10_parse::<f64>()?, lit.suffix().to_string()),
+            _ => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("Unexpected value {}", &lit.into_token_stream().to_string()),
+                ))
+            }
+        };
+        let unit = if unit.is_empty() && input.peek(Token![%]) {
+            input.parse::<Token![%]>()?;
+            "%".to_string()
+        } else {
+            unit
+        };
+        Ok(Self {
+            value: sign * value,
+            unit,
+            span,
+        })
+    }
+}
+
+impl Number {
+    pub(super) fn length(&self) -> Option<Length> {
+        Length::from_unit(self.value, &self.unit)
+    }
+
+    pub(super) fn error(&self) -> syn::Error {
+        if self.unit.is_empty() {
+            syn::Error::new(self.span, "Suffix is required.")
+        } else {
+            syn::Error::new(self.span, format!("Unexpected suffix {}", self.unit))
+        }
+    }
+}
+
+impl Parse for Wrapper<Length> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let number = input.parse::<Number>()?;
+        Ok(Wrapper(number.length().ok_or_else(|| number.error())?))
+    }
+}
+
+impl ToTokens for Wrapper<Length> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&Length> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            Length::Em(value) => quote! { ::#sulafat_style::Length::Em(#value) },
+            Length::Rem(value) => quote! { ::#sulafat_style::Length::Rem(#value) },
+            Length::Ch(value) => quote! { ::#sulafat_style::Length::Ch(#value) },
+            Length::Ex(value) => quote! { ::#sulafat_style::Length::Ex(#value) },
+            Length::Px(value) => quote! { ::#sulafat_style::Length::Px(#value) },
+            Length::Pt(value) => quote! { ::#sulafat_style::Length::Pt(#value) },
+            Length::Cm(value) => quote! { ::#sulafat_style::Length::Cm(#value) },
+            Length::Mm(value) => quote! { ::#sulafat_style::Length::Mm(#value) },
+            Length::In(value) => quote! { ::#sulafat_style::Length::In(#value) },
+            Length::Vh(value) => quote! { ::#sulafat_style::Length::Vh(#value) },
+            Length::Vw(value) => quote! { ::#sulafat_style::Length::Vw(#value) },
+            Length::Vmin(value) => quote! { ::#sulafat_style::Length::Vmin(#value) },
+            Length::Vmax(value) => quote! { ::#sulafat_style::Length::Vmax(#value) },
+        })
+    }
+}
+
+impl ToTokens for Wrapper<Parcentage> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&Parcentage> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let value = self.0 .0;
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(quote! {::#sulafat_style::Parcentage(#value)})
+    }
+}
+
+impl ToTokens for Wrapper<&Calc> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let boxed = |calc: &Calc| {
+            let calc = Wrapper(calc);
+            quote! { ::std::boxed::Box::new(#calc) }
+        };
+        tokens.extend(match &self.0 {
+            Calc::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::Calc::Length(#length) }
+            }
+            Calc::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::Calc::Parcentage(#parcentage) }
+            }
+            Calc::Number(number) => quote! { ::#sulafat_style::Calc::Number(#number) },
+            Calc::Add(lhs, rhs) => {
+                let (lhs, rhs) = (boxed(lhs), boxed(rhs));
+                quote! { ::#sulafat_style::Calc::Add(#lhs, #rhs) }
+            }
+            Calc::Sub(lhs, rhs) => {
+                let (lhs, rhs) = (boxed(lhs), boxed(rhs));
+                quote! { ::#sulafat_style::Calc::Sub(#lhs, #rhs) }
+            }
+            Calc::Mul(lhs, rhs) => {
+                let (lhs, rhs) = (boxed(lhs), boxed(rhs));
+                quote! { ::#sulafat_style::Calc::Mul(#lhs, #rhs) }
+            }
+            Calc::Div(lhs, rhs) => {
+                let (lhs, rhs) = (boxed(lhs), boxed(rhs));
+                quote! { ::#sulafat_style::Calc::Div(#lhs, #rhs) }
+            }
+            Calc::Min(args) => {
+                let args = args.iter().map(Wrapper);
+                quote! { ::#sulafat_style::Calc::Min(::std::vec![#(#args),*]) }
+            }
+            Calc::Max(args) => {
+                let args = args.iter().map(Wrapper);
+                quote! { ::#sulafat_style::Calc::Max(::std::vec![#(#args),*]) }
+            }
+            Calc::Clamp(min, value, max) => {
+                let (min, value, max) = (boxed(min), boxed(value), boxed(max));
+                quote! { ::#sulafat_style::Calc::Clamp(#min, #value, #max) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<LengthOrPercentage> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&LengthOrPercentage> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            LengthOrPercentage::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::LengthOrPercentage::Length(#length) }
+            }
+            LengthOrPercentage::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::LengthOrPercentage::Parcentage(#parcentage) }
+            }
+            LengthOrPercentage::Calc(calc) => {
+                let calc = Wrapper(calc);
+                quote! { ::#sulafat_style::LengthOrPercentage::Calc(#calc) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<LengthOrPercentageOrAuto> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&LengthOrPercentageOrAuto> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            LengthOrPercentageOrAuto::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::LengthOrPercentageOrAuto::Length(#length) }
+            }
+            LengthOrPercentageOrAuto::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::LengthOrPercentageOrAuto::Parcentage(#parcentage) }
+            }
+            LengthOrPercentageOrAuto::Calc(calc) => {
+                let calc = Wrapper(calc);
+                quote! { ::#sulafat_style::LengthOrPercentageOrAuto::Calc(#calc) }
+            }
+            LengthOrPercentageOrAuto::Auto => {
+                quote! { ::#sulafat_style::LengthOrPercentageOrAuto::Auto }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<LengthOrPercentageOrNone> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&LengthOrPercentageOrNone> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            LengthOrPercentageOrNone::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::LengthOrPercentageOrNone::Length(#length) }
+            }
+            LengthOrPercentageOrNone::Parcentage(parcentage) => {
+                let parcentage = Wrapper(parcentage);
+                quote! { ::#sulafat_style::LengthOrPercentageOrNone::Parcentage(#parcentage) }
+            }
+            LengthOrPercentageOrNone::Calc(calc) => {
+                let calc = Wrapper(calc);
+                quote! { ::#sulafat_style::LengthOrPercentageOrNone::Calc(#calc) }
+            }
+            LengthOrPercentageOrNone::None => {
+                quote! { ::#sulafat_style::LengthOrPercentageOrNone::None }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<LineWidth> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&LineWidth> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            LineWidth::Thin => quote! { ::#sulafat_style::LineWidth::Thin },
+            LineWidth::Medium => quote! { ::#sulafat_style::LineWidth::Medium },
+            LineWidth::Thick => quote! { ::#sulafat_style::LineWidth::Thick },
+            LineWidth::Length(length) => {
+                let length = Wrapper(length);
+                quote! { ::#sulafat_style::LineWidth::Length(#length) }
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<ZIndex> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&ZIndex> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            ZIndex::Auto => quote! { ::#sulafat_style::ZIndex::Auto },
+            ZIndex::Integer(value) => quote! { ::#sulafat_style::ZIndex::Integer(#value) },
+        })
+    }
+}
+
+impl<T> ToTokens for Wrapper<Sides<T>>
+where
+    for<'a> Wrapper<&'a T>: ToTokens,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl<T> ToTokens for Wrapper<&Sides<T>>
+where
+    for<'a> Wrapper<&'a T>: ToTokens,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let top = Wrapper(&self.0.top);
+        let right = Wrapper(&self.0.right);
+        let bottom = Wrapper(&self.0.bottom);
+        let left = Wrapper(&self.0.left);
+        tokens.extend(quote! {
+            ::#sulafat_style::Sides {
+                top: #top,
+                right: #right,
+                bottom: #bottom,
+                left: #left,
+            }
+        })
+    }
+}
+
+impl<T> ToTokens for Wrapper<&LogicalPair<T>>
+where
+    for<'a> Wrapper<&'a T>: ToTokens,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let start = Wrapper(&self.0.start);
+        let end = Wrapper(&self.0.end);
+        tokens.extend(quote! {
+            ::#sulafat_style::LogicalPair {
+                start: #start,
+                end: #end,
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<Border> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&Border> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        let width = option_tokens(self.0.width.as_ref().map(Wrapper));
+        let style = option_tokens(self.0.style.as_ref().map(Wrapper));
+        let color = option_tokens(self.0.color.as_ref().map(Wrapper));
+        tokens.extend(quote! {
+            ::#sulafat_style::Border {
+                width: #width,
+                style: #style,
+                color: #color,
+            }
+        })
+    }
+}
+
+impl ToTokens for Wrapper<Color> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&Color> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            Color::Rgba(Rgba { r, g, b, a }) => quote! {
+                ::#sulafat_style::Color::Rgba(::#sulafat_style::Rgba {
+                    r: #r,
+                    g: #g,
+                    b: #b,
+                    a: #a,
+                })
+            },
+            Color::CurrentColor => quote! { ::#sulafat_style::Color::CurrentColor },
+        })
+    }
+}
+
+pub(super) fn option_tokens<T: ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::std::option::Option::Some(#value) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+macro_rules! keyword {
+    ($($ty:ident { $($variant:ident),* $(,)? })*) => {$(
+        impl Parse for Wrapper<$ty> {
+            fn parse(input: ParseStream) -> syn::Result<Self> {
+                let (name, span) = parse_name(input)?;
+                $ty::from_keyword(&name)
+                    .map(Wrapper)
+                    .ok_or_else(|| syn::Error::new(span, format!("Unexpected value {}", name)))
+            }
+        }
+
+        impl ToTokens for Wrapper<$ty> {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                self.as_ref().to_tokens(tokens)
+            }
+        }
+
+        impl ToTokens for Wrapper<&$ty> {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                let sulafat_style = crate_name("sulafat-style");
+                tokens.extend(match &self.0 {
+                    $($ty::$variant => quote! { ::#sulafat_style::$ty::$variant },)*
+                })
+            }
+        }
+    )*};
+}
+
+keyword! {
+    BorderStyle { None, Hidden, Dotted, Dashed, Solid, Double, Groove, Ridge, Inset, Outset }
+    BoxSizing { ContentBox, BorderBox }
+    DisplayType {
+        None, Block, Inline, InlineBlock, ListItem, Contents, Flex, InlineFlex, Grid, InlineGrid,
+    }
+    FlexDirection { Row, RowReverse, Column, ColumnReverse }
+    FlexWrap { Nowrap, Wrap, WrapReverse }
+    JustifyContent {
+        Normal, Start, End, FlexStart, FlexEnd, Center, Left, Right, SpaceBetween, SpaceAround,
+        SpaceEvenly, Stretch,
+    }
+    AlignItems { Normal, Stretch, Start, End, FlexStart, FlexEnd, Center, Baseline }
+    TextOrientation { Mixed, Upright, Sideways }
+    Direction { Ltr, Rtl }
+    ColorScheme { Light, Dark }
+    Orientation { Portrait, Landscape }
+    MediaType { All, Screen, Print }
+    ReducedMotion { NoPreference, Reduce }
+    TextAlign { Start, End, Left, Right, Center, Justify, MatchParent }
+    RubyPosition { Over, Under, InterCharacter, Alternate }
+    GenericFamily { Serif, SansSerif, Monospace, Cursive, Fantasy, SystemUi }
+    TextEmphasisFill { Filled, Open }
+    TextEmphasisShape { Dot, Circle, DoubleCircle, Triangle, Sesame }
+    Overflow { Visible, Hidden, Clip, Scroll, Auto }
+    Position { Static, Relative, Absolute, Fixed, Sticky }
+    Visibility { Visible, Hidden, Collapse }
+    StepPosition { JumpStart, JumpEnd, JumpNone, JumpBoth, Start, End }
+    AnimationDirection { Normal, Reverse, Alternate, AlternateReverse }
+    AnimationFillMode { None, Forwards, Backwards, Both }
+    AnimationPlayState { Running, Paused }
+}
+
+impl ToTokens for Wrapper<WritingMode> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Wrapper<&WritingMode> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sulafat_style = crate_name("sulafat-style");
+        tokens.extend(match &self.0 {
+            WritingMode::HorizontalTb => {
+                quote! { ::#sulafat_style::WritingMode::HorizontalTb }
+            }
+            WritingMode::VerticalRl => {
+                quote! { ::#sulafat_style::WritingMode::VerticalRl }
+            }
+            WritingMode::VerticalLr => {
+                quote! { ::#sulafat_style::WritingMode::VerticalLr }
+            }
+            WritingMode::SidewayzRl => {
+                quote! { ::#sulafat_style::WritingMode::SidewayzRl }
+            }
+            WritingMode::SidewayzLr => {
+                quote! { ::#sulafat_style::WritingMode::SidewayzLr }
+            }
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use heck::KebabCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse2, Fields, ItemStruct};
+
+use crate::util::crate_name;
+
+pub(crate) fn derive_theme_impl(items: TokenStream) -> syn::Result<TokenStream> {
+    let item = parse2::<ItemStruct>(items)?;
+    let ident = &item.ident;
+    let fields = match &item.fields {
+        Fields::Named(fields) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &item,
+                "Theme tokens must be named fields.",
+            ))
+        }
+    };
+    let names = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap().to_string().to_kebab_case());
+    let members = fields.iter().map(|field| &field.ident);
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let sulafat_style = crate_name("sulafat-style");
+    Ok(quote! {
+        impl #impl_generics ::#sulafat_style::Theme for #ident #ty_generics #where_clause {
+            fn variables(&self) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                ::std::vec![#((
+                    ::std::string::String::from(#names),
+                    ::std::string::ToString::to_string(&self.#members),
+                )),*]
+            }
+        }
+    })
+}
+
+pub fn derive_theme(items: TokenStream) -> TokenStream {
+    derive_theme_impl(items).unwrap_or_else(|e| e.into_compile_error())
+}
@@ -59,7 +59,7 @@ fn derive_variant_ident_impl(items: TokenStream) -> syn::Result<TokenStream> {
     }
     Ok(quote! {
         const _: () = {
-            #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, ::serde_derive::Serialize, ::serde_derive::Deserialize)]
+            #[derive(::std::fmt::Debug, ::std::marker::Copy, ::std::clone::Clone, ::std::cmp::Eq, ::std::cmp::PartialEq, ::std::cmp::Ord, ::std::cmp::PartialOrd, ::std::hash::Hash, ::serde_derive::Serialize, ::serde_derive::Deserialize)]
             #vis enum #types_ident {
                 #(#variants,)*
             }
@@ -98,7 +98,7 @@ mod test {
             .to_string(),
             quote! {
                 const _: () = {
-                    #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, ::serde_derive::Serialize, ::serde_derive::Deserialize)]
+                    #[derive(::std::fmt::Debug, ::std::marker::Copy, ::std::clone::Clone, ::std::cmp::Eq, ::std::cmp::PartialEq, ::std::cmp::Ord, ::std::cmp::PartialOrd, ::std::hash::Hash, ::serde_derive::Serialize, ::serde_derive::Deserialize)]
                     pub enum ElementVariantIdent {
                         Div,
                         Span,
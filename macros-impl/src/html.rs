@@ -0,0 +1,330 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse2,
+    spanned::Spanned,
+    Expr, LitStr, Pat, Token,
+};
+
+use crate::util::crate_name;
+
+/// The children of an element, or the whole input of `html!`.
+struct Nodes(Vec<Child>);
+
+enum Child {
+    Element(Element),
+    /// `"text"`
+    Text(LitStr),
+    /// `{expr}` where `expr` converts into a node.
+    Expr(Expr),
+    If(If),
+    For(For),
+}
+
+struct Element {
+    name: Ident,
+    attrs: Vec<Attr>,
+    children: Nodes,
+}
+
+/// `name="literal"` or `name={expr}`.
+struct Attr {
+    name: Ident,
+    value: Expr,
+}
+
+/// `if cond { .. } else if cond { .. } else { .. }`, also with `if let`.
+struct If {
+    cond: Expr,
+    then: Nodes,
+    otherwise: Option<Else>,
+}
+
+enum Else {
+    If(Box<If>),
+    Nodes(Nodes),
+}
+
+/// `for pat in expr { .. }`
+struct For {
+    pat: Pat,
+    expr: Expr,
+    body: Nodes,
+}
+
+fn peek_closing_tag(input: ParseStream) -> bool {
+    input.peek(Token![<]) && input.peek2(Token![/])
+}
+
+impl Parse for Nodes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut children = vec![];
+        while !input.is_empty() && !peek_closing_tag(input) {
+            children.push(input.parse()?);
+        }
+        Ok(Self(children))
+    }
+}
+
+fn parse_braced_nodes(input: ParseStream) -> syn::Result<Nodes> {
+    let content;
+    braced!(content in input);
+    let nodes = content.parse()?;
+    if !content.is_empty() {
+        return Err(content.error("Unexpected closing tag"));
+    }
+    Ok(nodes)
+}
+
+impl Parse for Child {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![<]) {
+            Ok(Child::Element(input.parse()?))
+        } else if lookahead.peek(LitStr) {
+            Ok(Child::Text(input.parse()?))
+        } else if lookahead.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            Ok(Child::Expr(content.parse()?))
+        } else if lookahead.peek(Token![if]) {
+            Ok(Child::If(input.parse()?))
+        } else if lookahead.peek(Token![for]) {
+            Ok(Child::For(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![<]>()?;
+        let name = input.parse::<Ident>()?;
+        let mut attrs = vec![];
+        while !input.peek(Token![>]) && !input.peek(Token![/]) {
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let value = if input.peek(syn::token::Brace) {
+                let content;
+                braced!(content in input);
+                content.parse()?
+            } else {
+                Expr::Lit(input.parse()?)
+            };
+            attrs.push(Attr { name, value });
+        }
+        if input.parse::<Option<Token![/]>>()?.is_some() {
+            input.parse::<Token![>]>()?;
+            return Ok(Self {
+                name,
+                attrs,
+                children: Nodes(vec![]),
+            });
+        }
+        input.parse::<Token![>]>()?;
+        let children = input.parse()?;
+        if input.is_empty() {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("`<{}>` is not closed", name),
+            ));
+        }
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let close = input.parse::<Ident>()?;
+        if close != name {
+            return Err(syn::Error::new(
+                close.span(),
+                format!("Expected `</{}>`", name),
+            ));
+        }
+        input.parse::<Token![>]>()?;
+        Ok(Self {
+            name,
+            attrs,
+            children,
+        })
+    }
+}
+
+impl Parse for If {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![if]>()?;
+        let cond = Expr::parse_without_eager_brace(input)?;
+        let then = parse_braced_nodes(input)?;
+        let otherwise = if input.parse::<Option<Token![else]>>()?.is_some() {
+            Some(if input.peek(Token![if]) {
+                Else::If(Box::new(input.parse()?))
+            } else {
+                Else::Nodes(parse_braced_nodes(input)?)
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            cond,
+            then,
+            otherwise,
+        })
+    }
+}
+
+impl Parse for For {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![for]>()?;
+        let pat = input.parse()?;
+        input.parse::<Token![in]>()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+        let body = parse_braced_nodes(input)?;
+        Ok(Self { pat, expr, body })
+    }
+}
+
+struct Generator {
+    vdom: Ident,
+}
+
+impl Generator {
+    /// A single child is the node itself, several become a [`List`](sulafat_vdom::List).
+    fn nodes(&self, nodes: &Nodes) -> syn::Result<TokenStream> {
+        let vdom = &self.vdom;
+        match &nodes.0[..] {
+            [child] => self.child(child),
+            children => {
+                let children = children
+                    .iter()
+                    .map(|child| self.child(child))
+                    .collect::<syn::Result<Vec<_>>>()?;
+                Ok(quote! {
+                    ::#vdom::Node::from(::#vdom::List::from(::std::vec![#(#children),*]))
+                })
+            }
+        }
+    }
+
+    fn child(&self, child: &Child) -> syn::Result<TokenStream> {
+        let vdom = &self.vdom;
+        Ok(match child {
+            Child::Element(element) => self.element(element)?,
+            Child::Text(text) => quote! { ::#vdom::Node::from(#text) },
+            Child::Expr(expr) => {
+                quote_spanned! { expr.span()=> ::#vdom::Node::from(#expr) }
+            }
+            Child::If(branch) => self.branch(branch)?,
+            Child::For(For { pat, expr, body }) => {
+                let body = self.nodes(body)?;
+                quote! {
+                    ::std::iter::Iterator::collect::<::#vdom::Node<_>>(
+                        ::std::iter::Iterator::map(
+                            ::std::iter::IntoIterator::into_iter(#expr),
+                            |#pat| #body,
+                        )
+                    )
+                }
+            }
+        })
+    }
+
+    fn branch(&self, branch: &If) -> syn::Result<TokenStream> {
+        let vdom = &self.vdom;
+        let cond = &branch.cond;
+        let then = self.nodes(&branch.then)?;
+        let otherwise = match &branch.otherwise {
+            Some(Else::If(branch)) => self.branch(branch)?,
+            Some(Else::Nodes(nodes)) => self.nodes(nodes)?,
+            None => quote! { ::#vdom::Node::from(::#vdom::List::from(::std::vec![])) },
+        };
+        Ok(quote! {
+            if #cond { #then } else { #otherwise }
+        })
+    }
+
+    fn element(&self, element: &Element) -> syn::Result<TokenStream> {
+        let vdom = &self.vdom;
+        let ty = match element.name.to_string().as_str() {
+            "div" => quote! { Div },
+            "span" => quote! { Span },
+            "ruby" => quote! { Ruby },
+            "rt" => quote! { Rt },
+            "rp" => quote! { Rp },
+            name => {
+                return Err(syn::Error::new(
+                    element.name.span(),
+                    format!("Unknown element `<{}>`", name),
+                ))
+            }
+        };
+        let mut key = None;
+        let mut attrs = vec![];
+        for Attr { name, value } in &element.attrs {
+            let span = value.span();
+            attrs.push(match name.to_string().as_str() {
+                "key" => {
+                    if key.is_some() {
+                        return Err(syn::Error::new(name.span(), "Duplicate `key`"));
+                    }
+                    key = Some(quote_spanned! { span=>
+                        ::std::option::Option::Some(::std::string::ToString::to_string(&#value))
+                    });
+                    continue;
+                }
+                "id" => quote_spanned! { span=>
+                    ::#vdom::id(::std::string::ToString::to_string(&#value))
+                },
+                "style" => quote_spanned! { span=> ::#vdom::style(#value) },
+                "theme" => quote_spanned! { span=> ::#vdom::theme(#value) },
+                "on_click" => quote_spanned! { span=> ::#vdom::on_click(#value) },
+                "on_pointer_move" => quote_spanned! { span=> ::#vdom::on_pointer_move(#value) },
+                attr => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("Unknown attribute `{}`", attr),
+                    ))
+                }
+            });
+        }
+        let key = key.unwrap_or_else(|| quote! { ::std::option::Option::None });
+        let children = element
+            .children
+            .0
+            .iter()
+            .map(|child| self.child(child))
+            .collect::<syn::Result<Vec<_>>>()?;
+        Ok(quote! {
+            ::#vdom::Node::from(::#vdom::#ty::new(::#vdom::Common::new(
+                #key,
+                ::#vdom::AttributeList::from(::std::vec![#(#attrs),*]),
+                ::#vdom::List::from(::std::vec![#(#children),*]),
+            )))
+        })
+    }
+}
+
+fn html_impl(items: TokenStream) -> syn::Result<TokenStream> {
+    let nodes = parse2::<Nodes>(items)?;
+    let generator = Generator {
+        vdom: crate_name("sulafat-vdom"),
+    };
+    generator.nodes(&nodes)
+}
+
+/// JSX-like markup that evaluates to a `Node<Msg>`.
+///
+/// ```ignore
+/// html! {
+///     <div id="list" style={ListStyle}>
+///         if items.is_empty() { <span>"No items"</span> }
+///         for item in items {
+///             <div key={item.id} on_click={move |_| Msg::Select(item.id)}>
+///                 {item.name.clone()}
+///             </div>
+///         }
+///     </div>
+/// }
+/// ```
+pub fn html(items: TokenStream) -> TokenStream {
+    html_impl(items).unwrap_or_else(|e| e.into_compile_error())
+}
@@ -0,0 +1,269 @@
+use std::{collections::HashSet, env, fs, path::PathBuf};
+
+use heck::{ShoutySnakeCase, SnakeCase};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use serde_json::{Map, Value};
+use sulafat_style::{Color, FontWeight, Length, ParseError, Time, TimingFunction};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse2, Attribute, Ident, LitStr, Token, Visibility,
+};
+
+use crate::{derive_style_set::Wrapper, derive_theme::derive_theme_impl, util::crate_name};
+
+/// `pub struct Tokens = "tokens.json";`
+struct Input {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    path: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let path = input.parse()?;
+        input.parse::<Option<Token![;]>>()?;
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            path,
+        })
+    }
+}
+
+enum TokenValue {
+    Color(Color),
+    Dimension(Length),
+    Number(f64),
+    Duration(Time),
+    CubicBezier(TimingFunction),
+    FontWeight(FontWeight),
+}
+
+struct DesignToken {
+    /// The dotted path in the file, e.g. `color.primary`.
+    path: String,
+    description: Option<String>,
+    value: TokenValue,
+}
+
+/// Walks a file in the format of the Design Tokens Community Group: a token is an object
+/// with `$value`, or a bare value, and `$type` may be given by an enclosing group.
+fn collect(
+    group: &Map<String, Value>,
+    path: &mut Vec<String>,
+    ty: Option<&str>,
+    tokens: &mut Vec<DesignToken>,
+) -> Result<(), String> {
+    let ty = group.get("$type").and_then(Value::as_str).or(ty);
+    for (key, value) in group {
+        if key.starts_with('$') {
+            continue;
+        }
+        path.push(key.clone());
+        match value {
+            Value::Object(object) if !object.contains_key("$value") => {
+                collect(object, path, ty, tokens)?
+            }
+            value => {
+                let (ty, description, value) = match value {
+                    Value::Object(object) => (
+                        object.get("$type").and_then(Value::as_str).or(ty),
+                        object.get("$description").and_then(Value::as_str),
+                        &object["$value"],
+                    ),
+                    value => (ty, None, value),
+                };
+                let path = path.join(".");
+                let value =
+                    token_value(ty, value).map_err(|error| format!("{}: {}", path, error))?;
+                tokens.push(DesignToken {
+                    path,
+                    description: description.map(str::to_string),
+                    value,
+                });
+            }
+        }
+        path.pop();
+    }
+    Ok(())
+}
+
+fn message(error: ParseError) -> String {
+    error.message().to_string()
+}
+
+fn token_value(ty: Option<&str>, value: &Value) -> Result<TokenValue, String> {
+    let ty = ty.ok_or("`$type` is required.")?;
+    let string = || {
+        value
+            .as_str()
+            .ok_or_else(|| format!("A string is expected for {}.", ty))
+    };
+    let number = || {
+        value
+            .as_f64()
+            .ok_or_else(|| format!("A number is expected for {}.", ty))
+    };
+    Ok(match ty {
+        "color" => TokenValue::Color(string()?.parse().map_err(message)?),
+        "dimension" if value.as_f64() == Some(0.) => TokenValue::Dimension(Length::Px(0.)),
+        "dimension" => TokenValue::Dimension(string()?.parse().map_err(message)?),
+        "number" => TokenValue::Number(number()?),
+        "duration" => TokenValue::Duration(string()?.parse().map_err(message)?),
+        "cubicBezier" => TokenValue::CubicBezier(match value {
+            Value::Array(values) => {
+                match values.iter().map(Value::as_f64).collect::<Vec<_>>()[..] {
+                    [Some(x1), Some(y1), Some(x2), Some(y2)] => {
+                        TimingFunction::CubicBezier(x1, y1, x2, y2)
+                    }
+                    _ => return Err("4 numbers are expected.".to_string()),
+                }
+            }
+            _ => string()?.parse().map_err(message)?,
+        }),
+        "fontWeight" => TokenValue::FontWeight(
+            match value {
+                Value::Number(weight) => weight.to_string().parse(),
+                _ => string()?.parse(),
+            }
+            .map_err(message)?,
+        ),
+        _ => {
+            return Err(format!(
+                "Unsupported type {}. color, dimension, number, duration, cubicBezier and \
+                 fontWeight are supported.",
+                ty
+            ))
+        }
+    })
+}
+
+fn read_tokens(path: &LitStr) -> Result<(PathBuf, Vec<DesignToken>), String> {
+    let mut file = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+    file.push(path.value());
+    let text =
+        fs::read_to_string(&file).map_err(|error| format!("{}: {}", file.display(), error))?;
+    let value = if file
+        .extension()
+        .is_some_and(|extension| extension == "toml")
+    {
+        let value = toml::from_str::<toml::Value>(&text).map_err(|error| error.to_string())?;
+        serde_json::to_value(value).map_err(|error| error.to_string())?
+    } else {
+        serde_json::from_str::<Value>(&text).map_err(|error| error.to_string())?
+    };
+    let mut tokens = vec![];
+    match &value {
+        Value::Object(group) => collect(group, &mut vec![], None, &mut tokens)?,
+        _ => return Err("A group of tokens is expected.".to_string()),
+    }
+    Ok((file, tokens))
+}
+
+fn include_tokens_impl(items: TokenStream) -> syn::Result<TokenStream> {
+    let Input {
+        attrs,
+        vis,
+        ident,
+        path,
+    } = parse2(items)?;
+    let (file, tokens) = read_tokens(&path).map_err(|error| syn::Error::new(path.span(), error))?;
+    let sulafat_style = crate_name("sulafat-style");
+    let mut names = HashSet::new();
+    let mut fields = vec![];
+    let mut consts = vec![];
+    let mut types = vec![];
+    let mut values = vec![];
+    let mut docs = vec![];
+    for token in &tokens {
+        let name = token
+            .path
+            .split('.')
+            .map(|segment| segment.to_snake_case())
+            .collect::<Vec<_>>()
+            .join("_");
+        let field = syn::parse_str::<Ident>(&name)
+            .ok()
+            .filter(|_| names.insert(name.clone()))
+            .ok_or_else(|| {
+                syn::Error::new(
+                    path.span(),
+                    format!("{}: `{}` is not a unique identifier.", token.path, name),
+                )
+            })?;
+        consts.push(Ident::new(&name.to_shouty_snake_case(), Span::call_site()));
+        fields.push(field);
+        let (ty, value) = match &token.value {
+            TokenValue::Color(color) => (
+                quote! { ::#sulafat_style::Color },
+                Wrapper(color).into_token_stream(),
+            ),
+            TokenValue::Dimension(length) => (
+                quote! { ::#sulafat_style::Length },
+                Wrapper(length).into_token_stream(),
+            ),
+            TokenValue::Number(number) => (quote! { f64 }, number.into_token_stream()),
+            TokenValue::Duration(time) => (
+                quote! { ::#sulafat_style::Time },
+                Wrapper(time).into_token_stream(),
+            ),
+            TokenValue::CubicBezier(function) => (
+                quote! { ::#sulafat_style::TimingFunction },
+                Wrapper(function).into_token_stream(),
+            ),
+            TokenValue::FontWeight(weight) => (
+                quote! { ::#sulafat_style::FontWeight },
+                Wrapper(weight).into_token_stream(),
+            ),
+        };
+        types.push(ty);
+        values.push(value);
+        docs.push(match &token.description {
+            Some(description) => format!("`{}`: {}", token.path, description),
+            None => format!("`{}`", token.path),
+        });
+    }
+    let item = quote! {
+        #(#attrs)*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #vis struct #ident {
+            #(pub #fields: #types,)*
+        }
+    };
+    let theme = derive_theme_impl(item.clone())?;
+    let file = file.to_string_lossy();
+    Ok(quote! {
+        #item
+        impl #ident {
+            #(
+                #[doc = #docs]
+                pub const #consts: #types = #values;
+            )*
+        }
+        impl ::std::default::Default for #ident {
+            fn default() -> Self {
+                Self {
+                    #(#fields: Self::#consts,)*
+                }
+            }
+        }
+        #theme
+        const _: &str = ::std::include_str!(#file);
+    })
+}
+
+/// Reads design tokens from a JSON or TOML file relative to the crate, and defines a
+/// [`Theme`](sulafat_style::Theme) with a field and an associated constant per token.
+/// Style sets that name it with `#[theme(..)]` fail to compile when they refer to a
+/// token the file does not have.
+pub fn include_tokens(items: TokenStream) -> TokenStream {
+    include_tokens_impl(items).unwrap_or_else(|e| e.into_compile_error())
+}
@@ -3,7 +3,10 @@ mod derive_clone;
 mod derive_partial_eq;
 mod derive_serialize;
 mod derive_style_set;
+mod derive_theme;
 mod derive_variant_ident;
+mod html;
+mod include_tokens;
 pub(crate) mod util;
 
 pub use dbg::dbg;
@@ -11,4 +14,7 @@ pub use derive_clone::derive_clone;
 pub use derive_partial_eq::derive_partial_eq;
 pub use derive_serialize::derive_serialize;
 pub use derive_style_set::derive_style_set;
+pub use derive_theme::derive_theme;
 pub use derive_variant_ident::derive_variant_ident;
+pub use html::html;
+pub use include_tokens::include_tokens;
@@ -2,7 +2,10 @@ pub(crate) mod fq_name;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+};
 use syn::{punctuated::Punctuated, GenericParam, Generics, Lifetime, Token};
 
 #[cfg(feature = "export-css")]
@@ -22,11 +25,11 @@ impl<'a> ToTokens for Param<'a> {
     }
 }
 
-impl<'a> ToString for Param<'a> {
-    fn to_string(&self) -> String {
+impl<'a> Display for Param<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Param::LifeTime(lifetime) => lifetime.to_string(),
-            Param::Ident(ident) => ident.to_string(),
+            Param::LifeTime(lifetime) => Display::fmt(lifetime, f),
+            Param::Ident(ident) => Display::fmt(ident, f),
         }
     }
 }
@@ -15,11 +15,26 @@ pub fn derive_serialize(items: TokenStream) -> TokenStream {
     sulafat_macros_impl::derive_serialize(items.into()).into()
 }
 
-#[proc_macro_derive(StyleSet, attributes(style_set))]
+#[proc_macro_derive(StyleSet, attributes(style_set, theme))]
 pub fn derive_style_set(items: TokenStream) -> TokenStream {
     sulafat_macros_impl::derive_style_set(items.into()).into()
 }
 
+#[proc_macro_derive(Theme)]
+pub fn derive_theme(items: TokenStream) -> TokenStream {
+    sulafat_macros_impl::derive_theme(items.into()).into()
+}
+
+#[proc_macro]
+pub fn html(items: TokenStream) -> TokenStream {
+    sulafat_macros_impl::html(items.into()).into()
+}
+
+#[proc_macro]
+pub fn include_tokens(items: TokenStream) -> TokenStream {
+    sulafat_macros_impl::include_tokens(items.into()).into()
+}
+
 #[proc_macro_derive(PartialEq)]
 pub fn derive_partial_eq(items: TokenStream) -> TokenStream {
     sulafat_macros_impl::derive_partial_eq(items.into()).into()
@@ -0,0 +1,75 @@
+use std::cell::{Cell, RefCell};
+use sulafat_vdom::Scheduler;
+
+/// Requests an animation frame by the function given from the TypeScript runtime, once
+/// until the frame begins. A request made before the function is given is kept and made
+/// when it is given.
+#[derive(Default)]
+pub(crate) struct AnimationFrame {
+    requested: Cell<bool>,
+    request: RefCell<Option<Box<dyn Fn()>>>,
+}
+
+impl AnimationFrame {
+    pub(crate) fn set_request(&self, request: impl 'static + Fn()) {
+        if self.requested.get() {
+            request();
+        }
+        *self.request.borrow_mut() = Some(Box::new(request));
+    }
+
+    pub(crate) fn begin_frame(&self) {
+        self.requested.set(false);
+    }
+}
+
+impl Scheduler for AnimationFrame {
+    fn request_render(&self) {
+        if !self.requested.replace(true) {
+            if let Some(request) = &*self.request.borrow() {
+                request();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AnimationFrame;
+    use std::{cell::Cell, rc::Rc};
+    use sulafat_vdom::Scheduler;
+
+    fn counter(frame: &AnimationFrame) -> Rc<Cell<u32>> {
+        let count = Rc::new(Cell::new(0));
+        let c = count.clone();
+        frame.set_request(move || c.set(c.get() + 1));
+        count
+    }
+
+    #[test]
+    fn once_in_frame() {
+        let frame = AnimationFrame::default();
+        let count = counter(&frame);
+        assert_eq!(count.get(), 0);
+        frame.request_render();
+        frame.request_render();
+        assert_eq!(count.get(), 1);
+        frame.begin_frame();
+        frame.request_render();
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn request_before_set() {
+        let frame = AnimationFrame::default();
+        frame.request_render();
+        frame.request_render();
+        let count = counter(&frame);
+        assert_eq!(count.get(), 1);
+        frame.request_render();
+        assert_eq!(count.get(), 1);
+        frame.begin_frame();
+        frame.request_render();
+        assert_eq!(count.get(), 2);
+    }
+}
@@ -0,0 +1,151 @@
+use bincode::serialize;
+use std::{cell::RefCell, rc::Rc, task::Context};
+use sulafat_vdom::{EventHandler, Manager, Program};
+
+/// A [`Manager`] of any program, driven by the exported functions.
+pub(crate) trait Runtime {
+    fn full_render(&mut self) -> Vec<u8>;
+    fn render(&mut self) -> Option<Vec<u8>>;
+    fn on_event(&mut self, event_handler: &EventHandler);
+    fn resolve(&mut self, context: &mut Context);
+}
+
+impl<P: Program> Runtime for Manager<P> {
+    fn full_render(&mut self) -> Vec<u8> {
+        serialize(&Manager::full_render(self)).unwrap()
+    }
+
+    fn render(&mut self) -> Option<Vec<u8>> {
+        Manager::render(self).map(|patch| serialize(&patch).unwrap())
+    }
+
+    fn on_event(&mut self, event_handler: &EventHandler) {
+        Manager::on_event(self, event_handler)
+    }
+
+    fn resolve(&mut self, context: &mut Context) {
+        Manager::resolve(self, context)
+    }
+}
+
+/// A runtime shared out of [`Instances`], so that the program it runs can use them.
+pub(crate) type SharedRuntime = Rc<RefCell<Box<dyn Runtime>>>;
+
+struct Instance {
+    mount_point: String,
+    runtime: SharedRuntime,
+}
+
+/// The started programs. The id of an instance is the order it was started in.
+#[derive(Default)]
+pub(crate) struct Instances {
+    instances: Vec<Instance>,
+}
+
+impl Instances {
+    pub(crate) fn push(&mut self, mount_point: &str, runtime: Box<dyn Runtime>) -> u32 {
+        self.instances.push(Instance {
+            mount_point: mount_point.into(),
+            runtime: Rc::new(RefCell::new(runtime)),
+        });
+        self.instances.len() as u32 - 1
+    }
+
+    pub(crate) fn count(&self) -> u32 {
+        self.instances.len() as u32
+    }
+
+    pub(crate) fn mount_point(&self, id: u32) -> Option<&str> {
+        self.instances
+            .get(id as usize)
+            .map(|instance| instance.mount_point.as_str())
+    }
+
+    pub(crate) fn runtime(&self, id: u32) -> Option<SharedRuntime> {
+        self.instances
+            .get(id as usize)
+            .map(|instance| instance.runtime.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Instances;
+    use bincode::{deserialize, serialize};
+    use std::rc::Rc;
+    use sulafat_vdom::{
+        on_click, program::Event, ClosureId, Common, Div, EventHandler, Manager, NativeScheduler,
+        Node, Program,
+    };
+
+    struct Counter;
+
+    fn increment(_: ()) -> u32 {
+        1
+    }
+
+    impl Program for Counter {
+        type Model = u32;
+        type Msg = u32;
+        fn init() -> Self::Model {
+            0
+        }
+        fn update(model: &Self::Model, msg: &Self::Msg) -> Self::Model {
+            model + msg
+        }
+        fn view(model: &Self::Model) -> Node<Self::Msg> {
+            Div::new(Common::new(
+                None,
+                vec![on_click(increment as fn(()) -> u32)].into(),
+                vec![model.to_string().into()].into(),
+            ))
+            .into()
+        }
+    }
+
+    fn instances() -> Instances {
+        let scheduler = Rc::new(NativeScheduler::new());
+        let mut instances = Instances::default();
+        for (id, mount_point) in ["#first", "#second"].iter().enumerate() {
+            let mut manager = Manager::<Counter>::new();
+            manager.set_scheduler(scheduler.clone());
+            assert_eq!(instances.push(mount_point, manager), id as u32);
+        }
+        instances
+    }
+
+    fn click() -> EventHandler {
+        let closure_id = ClosureId::FnPtr(increment as fn(()) -> u32 as usize);
+        deserialize(&serialize(&(closure_id, Event::OnClick)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn mount_points() {
+        let instances = instances();
+        assert_eq!(instances.count(), 2);
+        assert_eq!(instances.mount_point(0), Some("#first"));
+        assert_eq!(instances.mount_point(1), Some("#second"));
+        assert_eq!(instances.mount_point(2), None);
+    }
+
+    #[test]
+    fn route_events() {
+        let instances = instances();
+        let runtime = |id| instances.runtime(id).unwrap();
+        let first = runtime(0).borrow_mut().full_render();
+        assert_eq!(runtime(1).borrow_mut().full_render(), first);
+
+        runtime(1).borrow_mut().on_event(&click());
+        assert_eq!(runtime(0).borrow_mut().render(), None);
+        assert!(runtime(1).borrow_mut().render().is_some());
+        assert_eq!(runtime(0).borrow_mut().full_render(), first);
+        assert_ne!(runtime(1).borrow_mut().full_render(), first);
+    }
+
+    #[test]
+    fn unknown_instance() {
+        let instances = instances();
+        assert!(instances.runtime(2).is_none());
+        assert_eq!(instances.count(), 2);
+    }
+}
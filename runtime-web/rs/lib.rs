@@ -1,18 +1,20 @@
+mod frame;
+mod instances;
 mod utils;
 
-use bincode::{deserialize, serialize};
+use bincode::deserialize;
+use frame::AnimationFrame;
+use instances::{Instances, Runtime};
+use js_sys::Function;
 use std::{
     cell::RefCell,
     future::Future,
     pin::Pin,
+    rc::Rc,
     task::{Context, Poll},
     thread_local,
 };
-use sulafat_macros::StyleSet;
-use sulafat_vdom::{
-    cmd::Cmd, on_click, random::range, style, timer::timeout, Common, Div, EventHandler, Manager,
-    Node, Program,
-};
+use sulafat_vdom::{EventHandler, Manager, Program};
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -21,138 +23,148 @@ use wasm_bindgen::prelude::*;
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
-#[wasm_bindgen]
-extern "C" {
-    fn alert(s: &str);
+thread_local! {
+    static INSTANCES: RefCell<Instances> = Default::default();
+    static SCHEDULER: Rc<AnimationFrame> = Default::default();
 }
 
-struct MyProgram;
+fn unknown_instance() -> JsValue {
+    JsValue::from_str("存在しないインスタンスです")
+}
 
-enum Msg {
-    Update,
-    Timeout,
-    Random(u32),
+/// Runs `f` with the runtime of `instance`. The instances are not borrowed while `f` runs
+/// the program, which may start or look up instances.
+fn with_runtime<R>(instance: u32, f: impl FnOnce(&mut dyn Runtime) -> R) -> Option<R> {
+    let runtime = INSTANCES.with(|instances| instances.borrow().runtime(instance))?;
+    let mut runtime = runtime.borrow_mut();
+    Some(f(&mut **runtime))
 }
 
-#[derive(PartialEq)]
-struct Model {
-    len: usize,
-    rand: u32,
-    count: u32,
+/// Starts `P`, which is mounted on the element matching `mount_point`, a CSS selector,
+/// when the TypeScript runtime is initialized. Returns the id of the instance.
+///
+/// It can be called more than once, also with the same program, to mount each on its
+/// own element.
+///
+/// ```ignore
+/// #[wasm_bindgen(start)]
+/// pub fn main() {
+///     sulafat_runtime_web::start::<MyProgram>("#mount-point");
+/// }
+/// ```
+pub fn start<P: Program>(mount_point: &str) -> u32 {
+    utils::set_panic_hook();
+    let mut manager = Manager::<P>::new();
+    manager.set_scheduler(SCHEDULER.with(|scheduler| scheduler.clone()));
+    INSTANCES.with(|instances| instances.borrow_mut().push(mount_point, manager))
 }
 
-impl Program for MyProgram {
-    type Model = Model;
-    type Msg = Msg;
-    fn init_cmd() -> (Self::Model, Cmd<Self::Msg>) {
-        (
-            Model {
-                len: 0,
-                rand: 0,
-                count: 0,
-            },
-            Cmd::batch(vec![
-                timeout(|| Msg::Timeout, 1000),
-                range(Msg::Random, 0..10),
-            ]),
-        )
-    }
-    fn update_cmd(model: &Self::Model, msg: &Self::Msg) -> (Self::Model, Cmd<Self::Msg>) {
-        match *msg {
-            Msg::Update => (
-                Model {
-                    len: model.len + 1,
-                    ..*model
-                },
-                range(Msg::Random, 0..10),
-            ),
-            Msg::Timeout => (
-                Model {
-                    count: model.count + 1,
-                    ..*model
-                },
-                timeout(|| Msg::Timeout, 1000),
-            ),
-            Msg::Random(rand) => (Model { rand, ..*model }, Cmd::none()),
-        }
-    }
-    fn view(model: &Self::Model) -> Node<Self::Msg> {
-        let children = (0..model.len + 2).map(|index| {
-            let text = match index {
-                0 => "Update".into(),
-                1 => format!("{}, {}, {}", model.len, model.rand, model.count),
-                _ => format!("{}", index - 2),
-            };
-
-            #[derive(StyleSet)]
-            #[style_set{
-                .style1 {
-                    left: 10px;
-                }
-            }]
-            struct Style1;
-
-            #[derive(StyleSet)]
-            #[style_set{
-                .style2 {
-                    left: 20px;
-                }
-            }]
-            struct Style2;
-
-            let attr = if index == 0 {
-                vec![on_click(|_| Msg::Update)]
-            } else {
-                vec![if index % 2 == 1 {
-                    style(Style1)
-                } else {
-                    style(Style2)
-                }]
-            };
-            Div::new(Common::new(None, attr.into(), vec![text.into()].into())).into()
-        });
-        children.collect()
-    }
+#[wasm_bindgen]
+pub fn internal_instance_count() -> u32 {
+    INSTANCES.with(|instances| instances.borrow().count())
 }
 
-thread_local! {
-    static MANAGER: RefCell<Box<Manager<MyProgram>>> = RefCell::new(Manager::new());
+#[wasm_bindgen]
+pub fn internal_mount_point(instance: u32) -> Result<String, JsValue> {
+    INSTANCES.with(|instances| {
+        instances
+            .borrow()
+            .mount_point(instance)
+            .map(Into::into)
+            .ok_or_else(unknown_instance)
+    })
 }
 
 #[wasm_bindgen]
-pub fn internal_init() -> Vec<u8> {
-    utils::set_panic_hook();
-    wasm_bindgen_futures::spawn_local(Resolver);
-    MANAGER
-        .with(|manager| serialize(manager.borrow_mut().full_render()))
-        .unwrap()
+pub fn internal_set_request_frame(request: Function) {
+    SCHEDULER.with(|scheduler| {
+        scheduler.set_request(move || {
+            request.call0(&JsValue::NULL).unwrap();
+        })
+    });
 }
 
 #[wasm_bindgen]
-pub fn internal_render() -> Option<Vec<u8>> {
-    MANAGER.with(|manager| {
-        let mut manager = manager.borrow_mut();
-        manager.diff().map(|diff| serialize(&diff).unwrap())
-    })
+pub fn internal_begin_frame() {
+    SCHEDULER.with(|scheduler| scheduler.begin_frame());
+}
+
+#[wasm_bindgen]
+pub fn internal_init(instance: u32) -> Result<Vec<u8>, JsValue> {
+    let buffer =
+        with_runtime(instance, |runtime| runtime.full_render()).ok_or_else(unknown_instance)?;
+    wasm_bindgen_futures::spawn_local(Resolver(instance));
+    Ok(buffer)
+}
+
+#[wasm_bindgen]
+pub fn internal_render(instance: u32) -> Result<Option<Vec<u8>>, JsValue> {
+    with_runtime(instance, |runtime| runtime.render()).ok_or_else(unknown_instance)
 }
 
-struct Resolver;
+struct Resolver(u32);
 
 impl Future for Resolver {
     type Output = ();
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        MANAGER.with(|manager| {
-            manager.borrow_mut().resolve(cx);
-        });
+        with_runtime(self.0, |runtime| runtime.resolve(cx));
         Poll::Ready(())
     }
 }
 
 #[wasm_bindgen]
-pub fn internal_on_event(data: Vec<u8>) {
-    MANAGER.with(|manager| {
-        let mut manager = manager.borrow_mut();
-        manager.on_event(&deserialize::<EventHandler>(&data).unwrap());
-        wasm_bindgen_futures::spawn_local(Resolver)
+pub fn internal_on_event(instance: u32, data: Vec<u8>) -> Result<(), JsValue> {
+    with_runtime(instance, |runtime| {
+        runtime.on_event(&deserialize::<EventHandler>(&data).unwrap())
     })
+    .ok_or_else(unknown_instance)?;
+    wasm_bindgen_futures::spawn_local(Resolver(instance));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{internal_instance_count, internal_mount_point, start, with_runtime};
+    use bincode::{deserialize, serialize};
+    use sulafat_vdom::{
+        on_click, program::Event, ClosureId, Common, Div, EventHandler, Node, Program,
+    };
+
+    struct Peek;
+
+    fn peek(_: ()) {}
+
+    impl Program for Peek {
+        type Model = u32;
+        type Msg = ();
+        fn init() -> Self::Model {
+            internal_instance_count()
+        }
+        fn update(_: &Self::Model, _: &Self::Msg) -> Self::Model {
+            internal_mount_point(0).unwrap();
+            internal_instance_count()
+        }
+        fn view(model: &Self::Model) -> Node<Self::Msg> {
+            Div::new(Common::new(
+                None,
+                vec![on_click(peek as fn(()))].into(),
+                vec![model.to_string().into()].into(),
+            ))
+            .into()
+        }
+    }
+
+    #[test]
+    fn reenter_instances() {
+        assert_eq!(start::<Peek>("#first"), 0);
+        assert_eq!(start::<Peek>("#second"), 1);
+        with_runtime(1, |runtime| runtime.full_render()).unwrap();
+        let closure_id = ClosureId::FnPtr(peek as fn(()) as usize);
+        let click: EventHandler =
+            deserialize(&serialize(&(closure_id, Event::OnClick)).unwrap()).unwrap();
+        with_runtime(1, |runtime| runtime.on_event(&click)).unwrap();
+        assert!(with_runtime(1, |runtime| runtime.render())
+            .unwrap()
+            .is_some());
+    }
 }
@@ -1,26 +1,60 @@
-import wasm_init, {
-  internal_init,
-  internal_render,
-  internal_on_event,
-} from "../wasm/sulafat_runtime_web.js";
 import { Decoder } from "./bincode.js";
 import { todo, unreachable } from "./util.js";
 
-let root: Node | Node[];
+/** The module generated by wasm-pack for a crate calling `sulafat_runtime_web::start`. */
+export interface Wasm {
+  default(path?: string): Promise<unknown>;
+  internal_instance_count(): number;
+  internal_set_request_frame(request: () => void): void;
+  internal_begin_frame(): void;
+  internal_mount_point(instance: number): string;
+  internal_init(instance: number): Uint8Array;
+  internal_render(instance: number): Uint8Array | undefined;
+  internal_on_event(instance: number, data: Uint8Array): void;
+}
+
+/** A program started by `start`, mounted on its own element. */
+interface Instance {
+  id: number;
+  mountPoint: Element | null;
+  root: Node | Node[];
+}
+
+let wasm: Wasm;
+const instances: Instance[] = [];
+/** The instance whose nodes are being deserialized or patched. */
+let current: Instance;
 
-export async function init() {
-  await wasm_init("/wasm/sulafat_runtime_web_bg.wasm");
-  const buffer = internal_init();
-  console.log(buffer);
-  const decoder = new Decoder(buffer.buffer);
-  const node = deserializeNode(decoder);
-  decoder.end();
-  root = node;
-  mount(node);
+export async function init(module: Wasm, path?: string) {
+  await module.default(path);
+  wasm = module;
+  wasm.internal_set_request_frame(() => requestAnimationFrame(render));
+  mountStarted();
+}
+
+/** Mounts the instances started since the last call. */
+export function mountStarted() {
+  const count = wasm.internal_instance_count();
+  for (let id = instances.length; id < count; id += 1) {
+    current = {
+      id,
+      mountPoint: document.querySelector(wasm.internal_mount_point(id)),
+      root: [],
+    };
+    instances.push(current);
+    const buffer = wasm.internal_init(id);
+    console.log(buffer);
+    const decoder = new Decoder(buffer.buffer);
+    deserializeStyleSheet(decoder);
+    const node = deserializeNode(decoder);
+    decoder.end();
+    current.root = node;
+    mount(node);
+  }
 }
 
 function mount(node: Node | Node[]) {
-  const mountPoint = document.getElementById("mount-point");
+  const mountPoint = current.mountPoint;
   while (mountPoint?.childNodes.length) {
     mountPoint.firstChild?.remove();
   }
@@ -31,6 +65,61 @@ function mount(node: Node | Node[]) {
   }
 }
 
+/** The styles with the number of instances using each. */
+const styleSheet: Map<string, [HTMLStyleElement, number]> = new Map();
+
+function insertStyle(name: string, css: string) {
+  let entry = styleSheet.get(name);
+  if (!entry) {
+    entry = [document.createElement("style"), 0];
+    styleSheet.set(name, entry);
+    document.head.append(entry[0]);
+  }
+  entry[0].textContent = css;
+  entry[1] += 1;
+}
+
+function removeStyle(name: string) {
+  const entry = styleSheet.get(name);
+  if (!entry) {
+    throw Error("削除するスタイルがありません");
+  }
+  entry[1] -= 1;
+  if (entry[1] === 0) {
+    entry[0].remove();
+    styleSheet.delete(name);
+  }
+}
+
+function deserializeStyleSheet(decoder: Decoder) {
+  const len = decoder.u64();
+  for (let i = 0; i < len; i += 1) {
+    const name = decoder.string();
+    insertStyle(name, decoder.string());
+  }
+}
+
+const PATCH_STYLE_SHEET_OP_REMOVE = 0;
+const PATCH_STYLE_SHEET_OP_INSERT = 1;
+
+function applyStyleSheet(decoder: Decoder) {
+  const len = decoder.u64();
+  for (let i = 0; i < len; i += 1) {
+    switch (decoder.u32()) {
+      case PATCH_STYLE_SHEET_OP_REMOVE:
+        removeStyle(decoder.string());
+        break;
+      case PATCH_STYLE_SHEET_OP_INSERT: {
+        const name = decoder.string();
+        insertStyle(name, decoder.string());
+        break;
+      }
+      default:
+        unreachable();
+    }
+  }
+}
+
 const NODE_SINGLE = 0;
 const NODE_LIST = 1;
 
@@ -72,6 +161,9 @@ function deserializeSingle(decoder: Decoder): Node {
 
 const ELEMENT_DIV = 0;
 const ELEMENT_SPAN = 1;
+const ELEMENT_RUBY = 2;
+const ELEMENT_RT = 3;
+const ELEMENT_RP = 4;
 
 const eventHandlerMap: WeakMap<
   EventTarget,
@@ -115,6 +207,21 @@ function deserializeElement(decoder: Decoder): Element {
       );
       break;
     }
+    case ELEMENT_RUBY: {
+      element = document.createElementNS(
+        "http://www.w3.org/1999/xhtml",
+        "ruby"
+      );
+      break;
+    }
+    case ELEMENT_RT: {
+      element = document.createElementNS("http://www.w3.org/1999/xhtml", "rt");
+      break;
+    }
+    case ELEMENT_RP: {
+      element = document.createElementNS("http://www.w3.org/1999/xhtml", "rp");
+      break;
+    }
     default:
       unreachable();
   }
@@ -125,17 +232,19 @@ function deserializeElement(decoder: Decoder): Element {
         element.setAttribute("id", a[1]);
         break;
       case ATTRIBUTE_ON_CLICK: {
+        const instance = current.id;
         const id = a[1];
         const buf = new Uint8Array(16);
         buf.set(id);
         registerEventListener(element, "click", () => {
           console.log(id);
           buf.set(new Uint8Array(new Uint32Array([0]).buffer), 12);
-          internal_on_event(buf);
+          wasm.internal_on_event(instance, buf);
         });
         break;
       }
       case ATTRIBUTE_ON_POINTER_MOVE: {
+        const instance = current.id;
         const id = a[1];
         const buf = new Uint8Array(32);
         buf.set(id);
@@ -143,19 +252,13 @@ function deserializeElement(decoder: Decoder): Element {
         registerEventListener(element, "pointermove", (e: PointerEvent) => {
           console.log(id);
           buf.set(new Uint8Array(new Float64Array([e.x, e.y]).buffer), 16);
-          internal_on_event(buf);
+          wasm.internal_on_event(instance, buf);
         });
         break;
       }
-      case ATTRIBUTE_STYLE: {
-        const style = a[1];
-        if (typeof style === "string") {
-          element.setAttribute("class", style);
-        } else {
-          element.setAttribute("style", style.join(";"));
-        }
+      case ATTRIBUTE_STYLE:
+        setStyle(element, a[1]);
         break;
-      }
     }
   }
   element.append(...children);
@@ -183,7 +286,7 @@ type Attr = {
   [K in keyof AttrTypes]: [K, AttrTypes[K]];
 }[keyof AttrTypes];
 
-type Style = string | string[];
+type Style = [classes: string[], variables: [string, string][]];
 
 function* deserializeAttr(decoder: Decoder): Generator<Attr> {
   const len = decoder.u64();
@@ -212,90 +315,89 @@ function deserializeHandlerId(decoder: Decoder): Uint8Array {
   return decoder.read(12);
 }
 
-const STYLE_STATIC = 0;
-const STYLE_DYNAMIC = 1;
-
 function deserializeStyle(decoder: Decoder): Style {
-  switch (decoder.u32()) {
-    case STYLE_STATIC:
-      return decoder.string();
-    case STYLE_DYNAMIC: {
-      const len = decoder.u64();
-      const rules: string[] = [];
-      for (let i = 0; i < len; i += 1) {
-        rules.push(deserializeStyleRule(decoder));
-      }
-      return rules;
-    }
-    default:
-      unreachable();
+  const len = decoder.u64();
+  const classes: string[] = [];
+  for (let i = 0; i < len; i += 1) {
+    classes.push(decoder.string());
   }
+  const variablesLen = decoder.u64();
+  const variables: [string, string][] = [];
+  for (let i = 0; i < variablesLen; i += 1) {
+    const name = decoder.string();
+    variables.push([name, decoder.string()]);
+  }
+  return [classes, variables];
 }
 
-const STYLE_RULE_LEFT = 0;
-const STYLE_RULE_RIGHT = 1;
-const LENGTH_OR_PARCENTAGE_LENGTH = 0;
-const LENGTH_OR_PARCENTAGE_PARCENTAGE = 1;
-const LENGTH_EM = 0;
-const LENGTH_PX = 1;
-const LENGTH_VH = 2;
-const LENGTH_VW = 3;
+function setStyle(element: Element, [classes, variables]: Style) {
+  element.setAttribute("class", classes.join(" "));
+  element.removeAttribute("style");
+  const style = (element as HTMLElement).style;
+  for (const [name, value] of variables) {
+    style.setProperty(`--${name}`, value);
+  }
+}
 
-function deserializeStyleRule(decoder: Decoder): string {
-  switch (decoder.u32()) {
-    case STYLE_RULE_LEFT:
-      switch (decoder.u32()) {
-        case LENGTH_OR_PARCENTAGE_LENGTH:
-          switch (decoder.u32()) {
-            case LENGTH_EM:
-              return `left: ${decoder.f64()}em`;
-            case LENGTH_PX:
-              return `left: ${decoder.f64()}px`;
-            case LENGTH_VH:
-              return `left: ${decoder.f64()}vh`;
-            case LENGTH_VW:
-              return `left: ${decoder.f64()}vw`;
-            default:
-              unreachable();
-          }
-        case LENGTH_OR_PARCENTAGE_PARCENTAGE:
-          return `left: ${decoder.f64()}%`;
-        default:
-          unreachable();
+const PATCH_STYLE_LIST_OP_REMOVE = 0;
+const PATCH_STYLE_LIST_OP_INSERT = 1;
+const PATCH_STYLE_LIST_OP_REMOVE_VARIABLE = 2;
+const PATCH_STYLE_LIST_OP_SET_VARIABLE = 3;
+
+function applyStyle(element: Element, decoder: Decoder) {
+  const list = (element.getAttribute("class") ?? "").split(" ").filter((c) => c);
+  const len = decoder.u64();
+  for (let i = 0; i < len; i += 1) {
+    switch (decoder.u32()) {
+      case PATCH_STYLE_LIST_OP_REMOVE: {
+        const index = list.indexOf(decoder.string());
+        if (index < 0) {
+          throw Error("削除するクラスがありません");
+        }
+        list.splice(index, 1);
+        break;
       }
-    case STYLE_RULE_RIGHT:
-      switch (decoder.u32()) {
-        case LENGTH_OR_PARCENTAGE_LENGTH:
-          switch (decoder.u32()) {
-            case LENGTH_EM:
-              return `right: ${decoder.f64()}em`;
-            case LENGTH_PX:
-              return `right: ${decoder.f64()}px`;
-            case LENGTH_VH:
-              return `right: ${decoder.f64()}vh`;
-            case LENGTH_VW:
-              return `right: ${decoder.f64()}vw`;
-            default:
-              unreachable();
-          }
-        case LENGTH_OR_PARCENTAGE_PARCENTAGE:
-          return `right: ${decoder.f64()}%`;
-        default:
-          unreachable();
+      case PATCH_STYLE_LIST_OP_INSERT: {
+        const index = decoder.u64();
+        if (index > list.length) {
+          throw Error("挿入位置が範囲外です");
+        }
+        list.splice(index, 0, decoder.string());
+        break;
       }
-    default:
-      unreachable();
+      case PATCH_STYLE_LIST_OP_REMOVE_VARIABLE:
+        (element as HTMLElement).style.removeProperty(`--${decoder.string()}`);
+        break;
+      case PATCH_STYLE_LIST_OP_SET_VARIABLE: {
+        const name = decoder.string();
+        (element as HTMLElement).style.setProperty(`--${name}`, decoder.string());
+        break;
+      }
+      default:
+        unreachable();
+    }
   }
+  element.setAttribute("class", list.join(" "));
 }
 
+/** Applies the patches of the instances updated since the last frame. */
 export function render() {
-  const buffer = internal_render();
-  if (buffer) {
-    console.log(buffer);
-    const node = applyNode(root, new Decoder(buffer.buffer));
-    if (root != node) {
-      root = node;
-      mount(node);
+  wasm.internal_begin_frame();
+  for (const instance of instances) {
+    current = instance;
+    const buffer = wasm.internal_render(instance.id);
+    if (buffer) {
+      console.log(buffer);
+      const decoder = new Decoder(buffer.buffer);
+      applyStyleSheet(decoder);
+      if (decoder.bool()) {
+        const node = applyNode(instance.root, decoder);
+        if (instance.root != node) {
+          instance.root = node;
+          mount(node);
+        }
+      }
+      decoder.end();
     }
   }
 }
@@ -421,6 +523,9 @@ function applyList(list: Node[], decoder: Decoder): Node[] {
 const PATCH_ELEMENT_REPLACE = 0;
 const PATCH_ELEMENT_DIV = 1;
 const PATCH_ELEMENT_SPAN = 2;
+const PATCH_ELEMENT_RUBY = 3;
+const PATCH_ELEMENT_RT = 4;
+const PATCH_ELEMENT_RP = 5;
 
 function applyElement(element: Element, decoder: Decoder): Element {
   switch (decoder.u32()) {
@@ -444,6 +549,33 @@ function applyElement(element: Element, decoder: Decoder): Element {
       }
       return applySpan(element as HTMLSpanElement, decoder);
     }
+    case PATCH_ELEMENT_RUBY: {
+      if (
+        element.namespaceURI !== "http://www.w3.org/1999/xhtml" ||
+        element.localName !== "ruby"
+      ) {
+        throw Error("rubyではありません");
+      }
+      return applyRuby(element as HTMLElement, decoder);
+    }
+    case PATCH_ELEMENT_RT: {
+      if (
+        element.namespaceURI !== "http://www.w3.org/1999/xhtml" ||
+        element.localName !== "rt"
+      ) {
+        throw Error("rtではありません");
+      }
+      return applyRt(element as HTMLElement, decoder);
+    }
+    case PATCH_ELEMENT_RP: {
+      if (
+        element.namespaceURI !== "http://www.w3.org/1999/xhtml" ||
+        element.localName !== "rp"
+      ) {
+        throw Error("rpではありません");
+      }
+      return applyRp(element as HTMLElement, decoder);
+    }
     default:
       unreachable();
   }
@@ -457,8 +589,21 @@ function applySpan(span: HTMLSpanElement, decoder: Decoder): HTMLSpanElement {
   return applyCommon(span, decoder);
 }
 
+function applyRuby(ruby: HTMLElement, decoder: Decoder): HTMLElement {
+  return applyCommon(ruby, decoder);
+}
+
+function applyRt(rt: HTMLElement, decoder: Decoder): HTMLElement {
+  return applyCommon(rt, decoder);
+}
+
+function applyRp(rp: HTMLElement, decoder: Decoder): HTMLElement {
+  return applyCommon(rp, decoder);
+}
+
 const PATCH_ATTRIBUTE_REMOVE = 0;
 const PATCH_ATTRIBUTE_INSERT = 1;
+const PATCH_ATTRIBUTE_STYLE = 2;
 
 function applyCommon<E extends Element>(element: E, decoder: Decoder): E {
   const len = decoder.u64();
@@ -478,6 +623,7 @@ function applyCommon<E extends Element>(element: E, decoder: Decoder): E {
           case ATTRIBUTE_STYLE:
             element.removeAttribute("class");
             element.removeAttribute("style");
+            break;
           default:
             unreachable();
         }
@@ -489,17 +635,19 @@ function applyCommon<E extends Element>(element: E, decoder: Decoder): E {
             break;
           case ATTRIBUTE_ON_CLICK: {
             unregisterEventListener(element, "click");
+            const instance = current.id;
             const id = deserializeHandlerId(decoder);
             const buf = new Uint8Array(16);
             buf.set(id);
             registerEventListener(element, "click", () => {
               console.log(id);
               buf.set(new Uint8Array(new Uint32Array([0]).buffer), 12);
-              internal_on_event(buf);
+              wasm.internal_on_event(instance, buf);
             });
             break;
           }
           case ATTRIBUTE_ON_POINTER_MOVE: {
+            const instance = current.id;
             const id = deserializeHandlerId(decoder);
             const buf = new Uint8Array(32);
             buf.set(id);
@@ -507,25 +655,20 @@ function applyCommon<E extends Element>(element: E, decoder: Decoder): E {
             registerEventListener(element, "pointermove", (e: PointerEvent) => {
               console.log(id);
               buf.set(new Uint8Array(new Float64Array([e.x, e.y]).buffer), 16);
-              internal_on_event(buf);
+              wasm.internal_on_event(instance, buf);
             });
             break;
           }
-          case ATTRIBUTE_STYLE: {
-            const style = deserializeStyle(decoder);
-            if (typeof style === "string") {
-              element.setAttribute("class", style);
-              element.removeAttribute("style");
-            } else {
-              element.removeAttribute("class");
-              element.setAttribute("style", style.join(";"));
-            }
+          case ATTRIBUTE_STYLE:
+            setStyle(element, deserializeStyle(decoder));
             break;
-          }
           default:
             unreachable();
         }
         break;
+      case PATCH_ATTRIBUTE_STYLE:
+        applyStyle(element, decoder);
+        break;
       default:
         unreachable();
     }
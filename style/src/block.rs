@@ -0,0 +1,89 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{MediaQuery, StyleRule};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Pseudo {
+    /// `:hover`, `:focus-visible`, ...
+    Class(String),
+    /// `::before`, `::after`, ...
+    Element(String),
+}
+
+impl Display for Pseudo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Pseudo::Class(name) => write!(f, ":{}", name),
+            Pseudo::Element(name) => write!(f, "::{}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SupportsCondition {
+    Declaration(StyleRule),
+    Not(Box<SupportsCondition>),
+    And(Vec<SupportsCondition>),
+    Or(Vec<SupportsCondition>),
+}
+
+impl Display for SupportsCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut Formatter<'_>, conditions: &[SupportsCondition], separator| {
+            for (index, condition) in conditions.iter().enumerate() {
+                if index != 0 {
+                    f.write_str(separator)?;
+                }
+                write!(f, "({})", condition)?;
+            }
+            Ok(())
+        };
+        match self {
+            SupportsCondition::Declaration(rule) => {
+                f.write_str(rule.to_string().trim_end_matches(';'))
+            }
+            SupportsCondition::Not(condition) => write!(f, "not ({})", condition),
+            SupportsCondition::And(conditions) => join(f, conditions, " and "),
+            SupportsCondition::Or(conditions) => join(f, conditions, " or "),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Condition {
+    Media(Vec<MediaQuery>),
+    Supports(SupportsCondition),
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::Media(queries) => {
+                f.write_str("@media ")?;
+                for (index, query) in queries.iter().enumerate() {
+                    if index != 0 {
+                        f.write_str(",")?;
+                    }
+                    Display::fmt(query, f)?;
+                }
+                Ok(())
+            }
+            Condition::Supports(SupportsCondition::Declaration(rule)) => {
+                write!(f, "@supports ({})", rule.to_string().trim_end_matches(';'))
+            }
+            Condition::Supports(condition) => write!(f, "@supports {}", condition),
+        }
+    }
+}
+
+/// Rules that only apply in a state or context, such as `&:hover { .. }` or
+/// `@media (..) { .. }` nested in a `#[style_set]`. Nested blocks are flattened, so
+/// `conditions` lists the enclosing at-rules from the outermost one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StyleBlock {
+    pub conditions: Vec<Condition>,
+    pub selector: Vec<Pseudo>,
+    pub rules: Vec<StyleRule>,
+}
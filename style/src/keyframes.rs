@@ -0,0 +1,46 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Parcentage, StyleRule};
+
+/// The rules of one step of an animation. `from` and `to` are `0%` and `100%`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyframe {
+    pub selectors: Vec<Parcentage>,
+    pub rules: Vec<StyleRule>,
+}
+
+impl Display for Keyframe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, selector) in self.selectors.iter().enumerate() {
+            if index != 0 {
+                f.write_str(",")?;
+            }
+            Display::fmt(selector, f)?;
+        }
+        f.write_str("{")?;
+        for rule in &self.rules {
+            Display::fmt(rule, f)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// `@keyframes name { .. }` declared in a `#[style_set]`. The name is global like any
+/// other `@keyframes`, so `animation` rules of every style set can refer to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyframes {
+    pub name: String,
+    pub frames: Vec<Keyframe>,
+}
+
+impl Display for Keyframes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "@keyframes {}{{", self.name)?;
+        for frame in &self.frames {
+            Display::fmt(frame, f)?;
+        }
+        f.write_str("}")
+    }
+}
@@ -1,21 +1,132 @@
+mod block;
+mod keyframes;
+mod logical;
+mod media;
+mod parser;
+mod pixel;
 mod value;
 
+pub use block::{Condition, Pseudo, StyleBlock, SupportsCondition};
+pub use keyframes::{Keyframe, Keyframes};
+pub use logical::{LogicalResolver, LogicalSide, PhysicalSide};
+pub use media::{MediaFeature, MediaQuery};
+pub use parser::{
+    is_property, parse_block, parse_declaration, parse_declarations, ParseError, StyleBody,
+};
+pub use pixel::PixelResolver;
+
 use serde_derive::{Deserialize, Serialize};
 use std::{
     cell::RefCell,
     fmt::{self, Display, Formatter, Write},
 };
-pub use value::{Length, LengthOrPercentage, Parcentage, WritingMode};
+pub use value::{
+    AlignItems, Animation, AnimationDirection, AnimationFillMode, AnimationPlayState, Border,
+    BorderStyle, BoxSizing, Calc, Color, ColorScheme, Direction, DisplayType, FamilyName,
+    FlexDirection, FlexWrap, FontFamily, FontSize, FontWeight, Gap, GenericFamily, GridArea,
+    GridLine, GridTemplate, IterationCount, JustifyContent, Length, LengthOrPercentage,
+    LengthOrPercentageOrAuto, LengthOrPercentageOrNone, LetterSpacing, LineHeight, LineWidth,
+    LogicalPair, MediaType, Orientation, Overflow, Parcentage, Position, ReducedMotion, Rgba,
+    RubyPosition, Sides, SingleAnimation, SingleTransition, StepPosition, TextAlign,
+    TextCombineUpright, TextEmphasis, TextEmphasisFill, TextEmphasisShape, TextEmphasisStyle,
+    TextOrientation, Time, TimingFunction, TrackBreadth, TrackListItem, TrackRepeat, TrackSize,
+    Transition, TransitionProperty, Visibility, WritingMode, ZIndex,
+};
 
 // やりたいこと
 // * CSSを自動で出力（Web)
-// * テーマによる動的なスタイル切り替え
 //
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum StyleRule {
     Left(LengthOrPercentage),
     Right(LengthOrPercentage),
     WritingMode(WritingMode),
+    Top(LengthOrPercentage),
+    Bottom(LengthOrPercentage),
+    Position(Position),
+    ZIndex(ZIndex),
+    Width(LengthOrPercentageOrAuto),
+    Height(LengthOrPercentageOrAuto),
+    MinWidth(LengthOrPercentageOrAuto),
+    MinHeight(LengthOrPercentageOrAuto),
+    MaxWidth(LengthOrPercentageOrNone),
+    MaxHeight(LengthOrPercentageOrNone),
+    BoxSizing(BoxSizing),
+    Margin(Sides<LengthOrPercentageOrAuto>),
+    MarginTop(LengthOrPercentageOrAuto),
+    MarginRight(LengthOrPercentageOrAuto),
+    MarginBottom(LengthOrPercentageOrAuto),
+    MarginLeft(LengthOrPercentageOrAuto),
+    Padding(Sides<LengthOrPercentage>),
+    PaddingTop(LengthOrPercentage),
+    PaddingRight(LengthOrPercentage),
+    PaddingBottom(LengthOrPercentage),
+    PaddingLeft(LengthOrPercentage),
+    Border(Border),
+    BorderWidth(Sides<LineWidth>),
+    BorderStyle(Sides<BorderStyle>),
+    Display(DisplayType),
+    Visibility(Visibility),
+    Overflow(Overflow),
+    OverflowX(Overflow),
+    OverflowY(Overflow),
+    Color(Color),
+    BackgroundColor(Color),
+    BorderColor(Sides<Color>),
+    FlexDirection(FlexDirection),
+    FlexWrap(FlexWrap),
+    JustifyContent(JustifyContent),
+    AlignItems(AlignItems),
+    Gap(Gap),
+    RowGap(LengthOrPercentage),
+    ColumnGap(LengthOrPercentage),
+    FlexGrow(f64),
+    FlexShrink(f64),
+    FlexBasis(LengthOrPercentageOrAuto),
+    GridTemplateColumns(GridTemplate),
+    GridTemplateRows(GridTemplate),
+    GridArea(GridArea),
+    TextOrientation(TextOrientation),
+    TextCombineUpright(TextCombineUpright),
+    FontFamily(FontFamily),
+    FontSize(FontSize),
+    FontWeight(FontWeight),
+    LineHeight(LineHeight),
+    LetterSpacing(LetterSpacing),
+    TextAlign(TextAlign),
+    TextEmphasis(TextEmphasis),
+    RubyPosition(RubyPosition),
+    Direction(Direction),
+    InlineSize(LengthOrPercentageOrAuto),
+    BlockSize(LengthOrPercentageOrAuto),
+    MinInlineSize(LengthOrPercentageOrAuto),
+    MinBlockSize(LengthOrPercentageOrAuto),
+    MaxInlineSize(LengthOrPercentageOrNone),
+    MaxBlockSize(LengthOrPercentageOrNone),
+    MarginInline(LogicalPair<LengthOrPercentageOrAuto>),
+    MarginInlineStart(LengthOrPercentageOrAuto),
+    MarginInlineEnd(LengthOrPercentageOrAuto),
+    MarginBlock(LogicalPair<LengthOrPercentageOrAuto>),
+    MarginBlockStart(LengthOrPercentageOrAuto),
+    MarginBlockEnd(LengthOrPercentageOrAuto),
+    PaddingInline(LogicalPair<LengthOrPercentage>),
+    PaddingInlineStart(LengthOrPercentage),
+    PaddingInlineEnd(LengthOrPercentage),
+    PaddingBlock(LogicalPair<LengthOrPercentage>),
+    PaddingBlockStart(LengthOrPercentage),
+    PaddingBlockEnd(LengthOrPercentage),
+    InsetInline(LogicalPair<LengthOrPercentage>),
+    InsetInlineStart(LengthOrPercentage),
+    InsetInlineEnd(LengthOrPercentage),
+    InsetBlock(LogicalPair<LengthOrPercentage>),
+    InsetBlockStart(LengthOrPercentage),
+    InsetBlockEnd(LengthOrPercentage),
+    Opacity(f64),
+    Transition(Transition),
+    Animation(Animation),
+    /// `property:var(--name);`, a declaration whose value is supplied per element by
+    /// [`StyleSet::variables`].
+    Var(String, String),
 }
 
 impl Display for StyleRule {
@@ -28,49 +139,210 @@ impl Display for StyleRule {
                 write!(f, "right:{};", value)
             }
             StyleRule::WritingMode(value) => write!(f, "writing-mode:{};", value),
+            StyleRule::Top(value) => write!(f, "top:{};", value),
+            StyleRule::Bottom(value) => write!(f, "bottom:{};", value),
+            StyleRule::Position(value) => write!(f, "position:{};", value),
+            StyleRule::ZIndex(value) => write!(f, "z-index:{};", value),
+            StyleRule::Width(value) => write!(f, "width:{};", value),
+            StyleRule::Height(value) => write!(f, "height:{};", value),
+            StyleRule::MinWidth(value) => write!(f, "min-width:{};", value),
+            StyleRule::MinHeight(value) => write!(f, "min-height:{};", value),
+            StyleRule::MaxWidth(value) => write!(f, "max-width:{};", value),
+            StyleRule::MaxHeight(value) => write!(f, "max-height:{};", value),
+            StyleRule::BoxSizing(value) => write!(f, "box-sizing:{};", value),
+            StyleRule::Margin(value) => write!(f, "margin:{};", value),
+            StyleRule::MarginTop(value) => write!(f, "margin-top:{};", value),
+            StyleRule::MarginRight(value) => write!(f, "margin-right:{};", value),
+            StyleRule::MarginBottom(value) => write!(f, "margin-bottom:{};", value),
+            StyleRule::MarginLeft(value) => write!(f, "margin-left:{};", value),
+            StyleRule::Padding(value) => write!(f, "padding:{};", value),
+            StyleRule::PaddingTop(value) => write!(f, "padding-top:{};", value),
+            StyleRule::PaddingRight(value) => write!(f, "padding-right:{};", value),
+            StyleRule::PaddingBottom(value) => write!(f, "padding-bottom:{};", value),
+            StyleRule::PaddingLeft(value) => write!(f, "padding-left:{};", value),
+            StyleRule::Border(value) => write!(f, "border:{};", value),
+            StyleRule::BorderWidth(value) => write!(f, "border-width:{};", value),
+            StyleRule::BorderStyle(value) => write!(f, "border-style:{};", value),
+            StyleRule::Display(value) => write!(f, "display:{};", value),
+            StyleRule::Visibility(value) => write!(f, "visibility:{};", value),
+            StyleRule::Overflow(value) => write!(f, "overflow:{};", value),
+            StyleRule::OverflowX(value) => write!(f, "overflow-x:{};", value),
+            StyleRule::OverflowY(value) => write!(f, "overflow-y:{};", value),
+            StyleRule::Color(value) => write!(f, "color:{};", value),
+            StyleRule::BackgroundColor(value) => write!(f, "background-color:{};", value),
+            StyleRule::BorderColor(value) => write!(f, "border-color:{};", value),
+            StyleRule::FlexDirection(value) => write!(f, "flex-direction:{};", value),
+            StyleRule::FlexWrap(value) => write!(f, "flex-wrap:{};", value),
+            StyleRule::JustifyContent(value) => write!(f, "justify-content:{};", value),
+            StyleRule::AlignItems(value) => write!(f, "align-items:{};", value),
+            StyleRule::Gap(value) => write!(f, "gap:{};", value),
+            StyleRule::RowGap(value) => write!(f, "row-gap:{};", value),
+            StyleRule::ColumnGap(value) => write!(f, "column-gap:{};", value),
+            StyleRule::FlexGrow(value) => write!(f, "flex-grow:{};", value),
+            StyleRule::FlexShrink(value) => write!(f, "flex-shrink:{};", value),
+            StyleRule::FlexBasis(value) => write!(f, "flex-basis:{};", value),
+            StyleRule::GridTemplateColumns(value) => {
+                write!(f, "grid-template-columns:{};", value)
+            }
+            StyleRule::GridTemplateRows(value) => write!(f, "grid-template-rows:{};", value),
+            StyleRule::GridArea(value) => write!(f, "grid-area:{};", value),
+            StyleRule::TextOrientation(value) => write!(f, "text-orientation:{};", value),
+            StyleRule::TextCombineUpright(value) => write!(f, "text-combine-upright:{};", value),
+            StyleRule::FontFamily(value) => write!(f, "font-family:{};", value),
+            StyleRule::FontSize(value) => write!(f, "font-size:{};", value),
+            StyleRule::FontWeight(value) => write!(f, "font-weight:{};", value),
+            StyleRule::LineHeight(value) => write!(f, "line-height:{};", value),
+            StyleRule::LetterSpacing(value) => write!(f, "letter-spacing:{};", value),
+            StyleRule::TextAlign(value) => write!(f, "text-align:{};", value),
+            StyleRule::TextEmphasis(value) => write!(f, "text-emphasis:{};", value),
+            StyleRule::RubyPosition(value) => write!(f, "ruby-position:{};", value),
+            StyleRule::Direction(value) => write!(f, "direction:{};", value),
+            StyleRule::InlineSize(value) => write!(f, "inline-size:{};", value),
+            StyleRule::BlockSize(value) => write!(f, "block-size:{};", value),
+            StyleRule::MinInlineSize(value) => write!(f, "min-inline-size:{};", value),
+            StyleRule::MinBlockSize(value) => write!(f, "min-block-size:{};", value),
+            StyleRule::MaxInlineSize(value) => write!(f, "max-inline-size:{};", value),
+            StyleRule::MaxBlockSize(value) => write!(f, "max-block-size:{};", value),
+            StyleRule::MarginInline(value) => write!(f, "margin-inline:{};", value),
+            StyleRule::MarginInlineStart(value) => write!(f, "margin-inline-start:{};", value),
+            StyleRule::MarginInlineEnd(value) => write!(f, "margin-inline-end:{};", value),
+            StyleRule::MarginBlock(value) => write!(f, "margin-block:{};", value),
+            StyleRule::MarginBlockStart(value) => write!(f, "margin-block-start:{};", value),
+            StyleRule::MarginBlockEnd(value) => write!(f, "margin-block-end:{};", value),
+            StyleRule::PaddingInline(value) => write!(f, "padding-inline:{};", value),
+            StyleRule::PaddingInlineStart(value) => write!(f, "padding-inline-start:{};", value),
+            StyleRule::PaddingInlineEnd(value) => write!(f, "padding-inline-end:{};", value),
+            StyleRule::PaddingBlock(value) => write!(f, "padding-block:{};", value),
+            StyleRule::PaddingBlockStart(value) => write!(f, "padding-block-start:{};", value),
+            StyleRule::PaddingBlockEnd(value) => write!(f, "padding-block-end:{};", value),
+            StyleRule::InsetInline(value) => write!(f, "inset-inline:{};", value),
+            StyleRule::InsetInlineStart(value) => write!(f, "inset-inline-start:{};", value),
+            StyleRule::InsetInlineEnd(value) => write!(f, "inset-inline-end:{};", value),
+            StyleRule::InsetBlock(value) => write!(f, "inset-block:{};", value),
+            StyleRule::InsetBlockStart(value) => write!(f, "inset-block-start:{};", value),
+            StyleRule::InsetBlockEnd(value) => write!(f, "inset-block-end:{};", value),
+            StyleRule::Opacity(value) => write!(f, "opacity:{};", value),
+            StyleRule::Transition(value) => write!(f, "transition:{};", value),
+            StyleRule::Animation(value) => write!(f, "animation:{};", value),
+            StyleRule::Var(property, name) => write!(f, "{}:var(--{});", property, name),
         }
     }
 }
 
 pub trait StyleSet: 'static {
+    /// Derived sets build the slices they return once on first use into a `static`, which
+    /// lives for the rest of the program and is shared by all threads.
     fn rules() -> &'static [StyleRule];
+    /// Pseudo-class, pseudo-element, `@media` and `@supports` blocks.
+    ///
+    /// These can only be expressed by a stylesheet. Inline (dynamic) styles carry
+    /// `rules()` alone, so the blocks take effect only through the exported CSS.
+    fn blocks() -> &'static [StyleBlock] {
+        &[]
+    }
+    /// `@keyframes` declared alongside the rules. They are exported and registered
+    /// together with the class.
+    fn keyframes() -> &'static [Keyframes] {
+        &[]
+    }
     fn name() -> String;
+    /// Visits the style sets this one extends, in declaration order. An element styled
+    /// with `Self` also carries their classes.
+    fn extends<V: StyleSetVisitor>(_visitor: &mut V) {}
+    /// Values of the [`StyleRule::Var`] declarations of this instance, as custom
+    /// property names without `--` and CSS values.
+    fn variables(&self) -> Vec<(String, String)> {
+        vec![]
+    }
     fn render<R: StyleRenderer>(renderer: &mut R) {
         for rule in Self::rules() {
             renderer.render(rule);
         }
+        for block in Self::blocks() {
+            renderer.block(block);
+        }
+        for keyframes in Self::keyframes() {
+            renderer.keyframes(keyframes);
+        }
     }
 }
 
+/// Design tokens shared by style sets. Each token is a custom property that style sets
+/// refer to with `var(--token)`, so switching the theme of a subtree restyles it without
+/// touching the classes.
+pub trait Theme: 'static {
+    /// Custom property names without `--` and their values.
+    fn variables(&self) -> Vec<(String, String)>;
+    /// The tokens as a rule for `selector`, e.g. `:root{--primary:#ff0000;}`.
+    fn css(&self, selector: &str) -> String {
+        let mut css = format!("{}{{", selector);
+        for (name, value) in self.variables() {
+            write!(css, "--{}:{};", name, value).unwrap();
+        }
+        css.push('}');
+        css
+    }
+}
+
+pub trait StyleSetVisitor {
+    fn visit<S: StyleSet>(&mut self);
+}
+
 pub trait StyleRenderer {
     type Output;
     fn name(&mut self, name: &str);
     fn render(&mut self, rule: &StyleRule);
+    /// Renderers that cannot express selectors or at-rules ignore blocks by default.
+    fn block(&mut self, _block: &StyleBlock) {}
+    fn keyframes(&mut self, _keyframes: &Keyframes) {}
     fn finish(self) -> Self::Output;
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct CSSRenderer {
     string: String,
+    name: String,
+    blocks: String,
 }
 
 impl StyleRenderer for CSSRenderer {
     type Output = String;
     fn name(&mut self, name: &str) {
+        self.name = name.to_string();
         write!(self.string, ".{}{{", name).unwrap();
     }
     fn render(&mut self, rule: &StyleRule) {
         write!(self.string, "{}", rule).unwrap()
     }
+    fn block(&mut self, block: &StyleBlock) {
+        for condition in &block.conditions {
+            write!(self.blocks, "{}{{", condition).unwrap();
+        }
+        write!(self.blocks, ".{}", self.name).unwrap();
+        for pseudo in &block.selector {
+            write!(self.blocks, "{}", pseudo).unwrap();
+        }
+        write!(self.blocks, "{{").unwrap();
+        for rule in &block.rules {
+            write!(self.blocks, "{}", rule).unwrap();
+        }
+        write!(self.blocks, "}}").unwrap();
+        for _ in &block.conditions {
+            write!(self.blocks, "}}").unwrap();
+        }
+    }
+    fn keyframes(&mut self, keyframes: &Keyframes) {
+        write!(self.blocks, "{}", keyframes).unwrap();
+    }
     fn finish(mut self) -> Self::Output {
-        write!(self.string, "}}").unwrap();
+        write!(self.string, "}}{}", self.blocks).unwrap();
         self.string
     }
 }
 
 thread_local! {
     static EXPORTS: RefCell<Vec<String>> = {
-        println!("");
+        println!();
         Default::default()
     };
 }
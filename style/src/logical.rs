@@ -0,0 +1,313 @@
+use crate::{
+    Direction, LengthOrPercentage, LengthOrPercentageOrAuto, LogicalPair, StyleRule, WritingMode,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhysicalSide {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl PhysicalSide {
+    pub fn opposite(&self) -> Self {
+        match self {
+            PhysicalSide::Top => PhysicalSide::Bottom,
+            PhysicalSide::Right => PhysicalSide::Left,
+            PhysicalSide::Bottom => PhysicalSide::Top,
+            PhysicalSide::Left => PhysicalSide::Right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogicalSide {
+    InlineStart,
+    InlineEnd,
+    BlockStart,
+    BlockEnd,
+}
+
+/// Maps logical properties to physical ones, for renderers that only understand
+/// `top`/`right`/`bottom`/`left` and `width`/`height`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogicalResolver {
+    pub writing_mode: WritingMode,
+    pub direction: Direction,
+}
+
+impl Default for LogicalResolver {
+    fn default() -> Self {
+        Self::new(WritingMode::HorizontalTb, Direction::Ltr)
+    }
+}
+
+impl LogicalResolver {
+    pub fn new(writing_mode: WritingMode, direction: Direction) -> Self {
+        Self {
+            writing_mode,
+            direction,
+        }
+    }
+
+    pub fn side(&self, side: LogicalSide) -> PhysicalSide {
+        let ltr = self.direction == Direction::Ltr;
+        let (inline_start, block_start) = match self.writing_mode {
+            WritingMode::HorizontalTb => (
+                if ltr {
+                    PhysicalSide::Left
+                } else {
+                    PhysicalSide::Right
+                },
+                PhysicalSide::Top,
+            ),
+            WritingMode::VerticalRl | WritingMode::SidewayzRl => (
+                if ltr {
+                    PhysicalSide::Top
+                } else {
+                    PhysicalSide::Bottom
+                },
+                PhysicalSide::Right,
+            ),
+            WritingMode::VerticalLr => (
+                if ltr {
+                    PhysicalSide::Top
+                } else {
+                    PhysicalSide::Bottom
+                },
+                PhysicalSide::Left,
+            ),
+            WritingMode::SidewayzLr => (
+                if ltr {
+                    PhysicalSide::Bottom
+                } else {
+                    PhysicalSide::Top
+                },
+                PhysicalSide::Left,
+            ),
+        };
+        match side {
+            LogicalSide::InlineStart => inline_start,
+            LogicalSide::InlineEnd => inline_start.opposite(),
+            LogicalSide::BlockStart => block_start,
+            LogicalSide::BlockEnd => block_start.opposite(),
+        }
+    }
+
+    /// Resolves a single rule. Physical rules are returned unchanged, and logical
+    /// shorthands expand into two physical rules.
+    pub fn resolve(&self, rule: &StyleRule) -> Vec<StyleRule> {
+        use LogicalSide::*;
+        match rule {
+            StyleRule::InlineSize(value) => {
+                vec![self.by_axis(StyleRule::Width, StyleRule::Height)(
+                    value.clone(),
+                )]
+            }
+            StyleRule::BlockSize(value) => {
+                vec![self.by_axis(StyleRule::Height, StyleRule::Width)(
+                    value.clone(),
+                )]
+            }
+            StyleRule::MinInlineSize(value) => {
+                vec![self.by_axis(StyleRule::MinWidth, StyleRule::MinHeight)(
+                    value.clone(),
+                )]
+            }
+            StyleRule::MinBlockSize(value) => {
+                vec![self.by_axis(StyleRule::MinHeight, StyleRule::MinWidth)(
+                    value.clone(),
+                )]
+            }
+            StyleRule::MaxInlineSize(value) => {
+                vec![self.by_axis(StyleRule::MaxWidth, StyleRule::MaxHeight)(
+                    value.clone(),
+                )]
+            }
+            StyleRule::MaxBlockSize(value) => {
+                vec![self.by_axis(StyleRule::MaxHeight, StyleRule::MaxWidth)(
+                    value.clone(),
+                )]
+            }
+            StyleRule::MarginInline(pair) => self.pair(pair, InlineStart, InlineEnd, margin),
+            StyleRule::MarginInlineStart(value) => vec![margin(self.side(InlineStart), value)],
+            StyleRule::MarginInlineEnd(value) => vec![margin(self.side(InlineEnd), value)],
+            StyleRule::MarginBlock(pair) => self.pair(pair, BlockStart, BlockEnd, margin),
+            StyleRule::MarginBlockStart(value) => vec![margin(self.side(BlockStart), value)],
+            StyleRule::MarginBlockEnd(value) => vec![margin(self.side(BlockEnd), value)],
+            StyleRule::PaddingInline(pair) => self.pair(pair, InlineStart, InlineEnd, padding),
+            StyleRule::PaddingInlineStart(value) => vec![padding(self.side(InlineStart), value)],
+            StyleRule::PaddingInlineEnd(value) => vec![padding(self.side(InlineEnd), value)],
+            StyleRule::PaddingBlock(pair) => self.pair(pair, BlockStart, BlockEnd, padding),
+            StyleRule::PaddingBlockStart(value) => vec![padding(self.side(BlockStart), value)],
+            StyleRule::PaddingBlockEnd(value) => vec![padding(self.side(BlockEnd), value)],
+            StyleRule::InsetInline(pair) => self.pair(pair, InlineStart, InlineEnd, inset),
+            StyleRule::InsetInlineStart(value) => vec![inset(self.side(InlineStart), value)],
+            StyleRule::InsetInlineEnd(value) => vec![inset(self.side(InlineEnd), value)],
+            StyleRule::InsetBlock(pair) => self.pair(pair, BlockStart, BlockEnd, inset),
+            StyleRule::InsetBlockStart(value) => vec![inset(self.side(BlockStart), value)],
+            StyleRule::InsetBlockEnd(value) => vec![inset(self.side(BlockEnd), value)],
+            _ => vec![rule.clone()],
+        }
+    }
+
+    /// Resolves a rule set. `writing-mode` and `direction` rules in the set take
+    /// precedence over the resolver's own values, as they would in a browser.
+    pub fn resolve_all(&self, rules: &[StyleRule]) -> Vec<StyleRule> {
+        let mut resolver = *self;
+        for rule in rules {
+            match rule {
+                StyleRule::WritingMode(writing_mode) => resolver.writing_mode = *writing_mode,
+                StyleRule::Direction(direction) => resolver.direction = *direction,
+                _ => {}
+            }
+        }
+        rules
+            .iter()
+            .flat_map(|rule| resolver.resolve(rule))
+            .collect()
+    }
+
+    /// Picks the physical property for the inline axis: `horizontal` in horizontal
+    /// writing modes, `vertical` otherwise.
+    fn by_axis<T>(
+        &self,
+        horizontal: fn(T) -> StyleRule,
+        vertical: fn(T) -> StyleRule,
+    ) -> fn(T) -> StyleRule {
+        if self.writing_mode.is_horizontal() {
+            horizontal
+        } else {
+            vertical
+        }
+    }
+
+    fn pair<T>(
+        &self,
+        pair: &LogicalPair<T>,
+        start: LogicalSide,
+        end: LogicalSide,
+        f: fn(PhysicalSide, &T) -> StyleRule,
+    ) -> Vec<StyleRule> {
+        vec![
+            f(self.side(start), &pair.start),
+            f(self.side(end), &pair.end),
+        ]
+    }
+}
+
+fn margin(side: PhysicalSide, value: &LengthOrPercentageOrAuto) -> StyleRule {
+    match side {
+        PhysicalSide::Top => StyleRule::MarginTop(value.clone()),
+        PhysicalSide::Right => StyleRule::MarginRight(value.clone()),
+        PhysicalSide::Bottom => StyleRule::MarginBottom(value.clone()),
+        PhysicalSide::Left => StyleRule::MarginLeft(value.clone()),
+    }
+}
+
+fn padding(side: PhysicalSide, value: &LengthOrPercentage) -> StyleRule {
+    match side {
+        PhysicalSide::Top => StyleRule::PaddingTop(value.clone()),
+        PhysicalSide::Right => StyleRule::PaddingRight(value.clone()),
+        PhysicalSide::Bottom => StyleRule::PaddingBottom(value.clone()),
+        PhysicalSide::Left => StyleRule::PaddingLeft(value.clone()),
+    }
+}
+
+fn inset(side: PhysicalSide, value: &LengthOrPercentage) -> StyleRule {
+    match side {
+        PhysicalSide::Top => StyleRule::Top(value.clone()),
+        PhysicalSide::Right => StyleRule::Right(value.clone()),
+        PhysicalSide::Bottom => StyleRule::Bottom(value.clone()),
+        PhysicalSide::Left => StyleRule::Left(value.clone()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LogicalResolver, LogicalSide, PhysicalSide};
+    use crate::{
+        Direction, Length, LengthOrPercentage, LengthOrPercentageOrAuto, LogicalPair, StyleRule,
+        WritingMode,
+    };
+
+    fn px(value: f64) -> LengthOrPercentage {
+        LengthOrPercentage::Length(Length::Px(value))
+    }
+
+    #[test]
+    fn sides() {
+        let cases = [
+            (
+                WritingMode::HorizontalTb,
+                Direction::Ltr,
+                PhysicalSide::Left,
+                PhysicalSide::Top,
+            ),
+            (
+                WritingMode::HorizontalTb,
+                Direction::Rtl,
+                PhysicalSide::Right,
+                PhysicalSide::Top,
+            ),
+            (
+                WritingMode::VerticalRl,
+                Direction::Ltr,
+                PhysicalSide::Top,
+                PhysicalSide::Right,
+            ),
+            (
+                WritingMode::VerticalRl,
+                Direction::Rtl,
+                PhysicalSide::Bottom,
+                PhysicalSide::Right,
+            ),
+            (
+                WritingMode::VerticalLr,
+                Direction::Ltr,
+                PhysicalSide::Top,
+                PhysicalSide::Left,
+            ),
+            (
+                WritingMode::SidewayzLr,
+                Direction::Ltr,
+                PhysicalSide::Bottom,
+                PhysicalSide::Left,
+            ),
+        ];
+        for &(writing_mode, direction, inline_start, block_start) in &cases {
+            let resolver = LogicalResolver::new(writing_mode, direction);
+            assert_eq!(resolver.side(LogicalSide::InlineStart), inline_start);
+            assert_eq!(
+                resolver.side(LogicalSide::InlineEnd),
+                inline_start.opposite()
+            );
+            assert_eq!(resolver.side(LogicalSide::BlockStart), block_start);
+            assert_eq!(resolver.side(LogicalSide::BlockEnd), block_start.opposite());
+        }
+    }
+
+    #[test]
+    fn resolve_all() {
+        let rules = [
+            StyleRule::InlineSize(LengthOrPercentageOrAuto::Auto),
+            StyleRule::PaddingInline(LogicalPair {
+                start: px(1.),
+                end: px(2.),
+            }),
+            StyleRule::InsetBlockStart(px(0.)),
+            StyleRule::WritingMode(WritingMode::VerticalRl),
+        ];
+        assert_eq!(
+            LogicalResolver::default().resolve_all(&rules),
+            vec![
+                StyleRule::Height(LengthOrPercentageOrAuto::Auto),
+                StyleRule::PaddingTop(px(1.)),
+                StyleRule::PaddingBottom(px(2.)),
+                StyleRule::Right(px(0.)),
+                StyleRule::WritingMode(WritingMode::VerticalRl),
+            ]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{ColorScheme, Length, MediaType, Orientation, ReducedMotion};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MediaFeature {
+    MinWidth(Length),
+    MaxWidth(Length),
+    MinHeight(Length),
+    MaxHeight(Length),
+    Orientation(Orientation),
+    PrefersColorScheme(ColorScheme),
+    PrefersReducedMotion(ReducedMotion),
+}
+
+impl Display for MediaFeature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaFeature::MinWidth(value) => write!(f, "(min-width:{})", value),
+            MediaFeature::MaxWidth(value) => write!(f, "(max-width:{})", value),
+            MediaFeature::MinHeight(value) => write!(f, "(min-height:{})", value),
+            MediaFeature::MaxHeight(value) => write!(f, "(max-height:{})", value),
+            MediaFeature::Orientation(value) => write!(f, "(orientation:{})", value),
+            MediaFeature::PrefersColorScheme(value) => {
+                write!(f, "(prefers-color-scheme:{})", value)
+            }
+            MediaFeature::PrefersReducedMotion(value) => {
+                write!(f, "(prefers-reduced-motion:{})", value)
+            }
+        }
+    }
+}
+
+/// A single media query, e.g. `screen and (max-width:600px)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaQuery {
+    pub media_type: Option<MediaType>,
+    pub features: Vec<MediaFeature>,
+}
+
+impl Display for MediaQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        if let Some(media_type) = &self.media_type {
+            Display::fmt(media_type, f)?;
+            first = false;
+        }
+        for feature in &self.features {
+            if !first {
+                f.write_str(" and ")?;
+            }
+            Display::fmt(feature, f)?;
+            first = false;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use super::{tokenizer::TokenKind, Parse, ParseError, Parser};
+use crate::{
+    Animation, AnimationDirection, AnimationFillMode, AnimationPlayState, IterationCount,
+    SingleAnimation, SingleTransition, StepPosition, Time, TimingFunction, Transition,
+    TransitionProperty,
+};
+
+impl Parse for Time {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let number = parser.parse_number()?;
+        Time::from_unit(number.value, &number.unit).ok_or_else(|| number.error())
+    }
+}
+
+impl Parse for TimingFunction {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_ident() {
+            let (name, offset) = parser.parse_ident()?;
+            return TimingFunction::from_keyword(&name)
+                .ok_or_else(|| ParseError::new(offset, format!("Unexpected value {}", name)));
+        }
+        let (name, offset) = parser.parse_function()?;
+        let function = match name.as_str() {
+            "cubic-bezier" => {
+                let mut args = [0.; 4];
+                for (index, arg) in args.iter_mut().enumerate() {
+                    if index != 0 {
+                        parser.parse_punct(',')?;
+                    }
+                    let number = parser.parse_number()?;
+                    if !number.unit.is_empty() {
+                        return Err(number.error());
+                    }
+                    if index % 2 == 0 && !(0. ..=1.).contains(&number.value) {
+                        return Err(ParseError::new(number.offset, "0 to 1 is expected."));
+                    }
+                    *arg = number.value;
+                }
+                TimingFunction::CubicBezier(args[0], args[1], args[2], args[3])
+            }
+            "steps" => {
+                let number = parser.parse_number()?;
+                if !number.unit.is_empty() || number.value.fract() != 0. || number.value < 1. {
+                    return Err(ParseError::new(
+                        number.offset,
+                        "Positive integer is expected.",
+                    ));
+                }
+                let position = if parser.parse_optional_punct(',') {
+                    let (name, offset) = parser.parse_ident()?;
+                    Some(StepPosition::from_keyword(&name).ok_or_else(|| {
+                        ParseError::new(offset, format!("Unexpected value {}", name))
+                    })?)
+                } else {
+                    None
+                };
+                TimingFunction::Steps(number.value as u32, position)
+            }
+            _ => {
+                return Err(ParseError::new(
+                    offset,
+                    format!("Unexpected function {}()", name),
+                ))
+            }
+        };
+        parser.parse_punct(')')?;
+        Ok(function)
+    }
+}
+
+/// Whether a timing function follows, which may be written in any position of the
+/// `transition` and `animation` shorthands.
+fn peek_timing_function(parser: &Parser) -> bool {
+    match parser.peek() {
+        Some(TokenKind::Ident(name)) => TimingFunction::from_keyword(name).is_some(),
+        _ => parser.peek_function("cubic-bezier") || parser.peek_function("steps"),
+    }
+}
+
+/// The duration, then the delay.
+fn parse_time(
+    parser: &mut Parser,
+    duration: &mut Option<Time>,
+    delay: &mut Option<Time>,
+) -> Result<(), ParseError> {
+    if duration.is_none() {
+        *duration = Some(parser.parse()?);
+    } else if delay.is_none() {
+        *delay = Some(parser.parse()?);
+    } else {
+        return Err(parser.error("Unexpected value"));
+    }
+    Ok(())
+}
+
+impl Parse for SingleTransition {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut transition = SingleTransition::default();
+        while !parser.at_end() && !parser.peek_punct(',') {
+            if let Some(TokenKind::Number(..)) = parser.peek() {
+                parse_time(parser, &mut transition.duration, &mut transition.delay)?;
+            } else if transition.timing_function.is_none() && peek_timing_function(parser) {
+                transition.timing_function = Some(parser.parse()?);
+            } else if transition.property.is_none() && parser.peek_ident() {
+                let (name, _) = parser.parse_ident()?;
+                let name = name.to_ascii_lowercase();
+                transition.property = Some(match name.as_str() {
+                    "none" => TransitionProperty::None,
+                    "all" => TransitionProperty::All,
+                    _ => TransitionProperty::Property(name),
+                });
+            } else {
+                return Err(parser.error("Unexpected value"));
+            }
+        }
+        if transition == SingleTransition::default() {
+            return Err(ParseError::new(offset, "Value is required."));
+        }
+        Ok(transition)
+    }
+}
+
+impl Parse for Transition {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let mut transitions = vec![parser.parse()?];
+        while parser.parse_optional_punct(',') {
+            transitions.push(parser.parse()?);
+        }
+        Ok(Transition(transitions))
+    }
+}
+
+impl Parse for SingleAnimation {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut animation = SingleAnimation::default();
+        while !parser.at_end() && !parser.peek_punct(',') {
+            let name = match parser.peek() {
+                Some(TokenKind::Number(_, unit)) if unit.is_empty() => {
+                    let number = parser.parse_number()?;
+                    if animation.iteration_count.is_some() || number.value < 0. {
+                        return Err(ParseError::new(number.offset, "Unexpected value"));
+                    }
+                    animation.iteration_count = Some(IterationCount::Number(number.value));
+                    continue;
+                }
+                Some(TokenKind::Number(..)) => {
+                    parse_time(parser, &mut animation.duration, &mut animation.delay)?;
+                    continue;
+                }
+                Some(TokenKind::Ident(name)) => name.to_ascii_lowercase(),
+                _ => String::new(),
+            };
+            if animation.timing_function.is_none() && peek_timing_function(parser) {
+                animation.timing_function = Some(parser.parse()?);
+            } else if name == "infinite" && animation.iteration_count.is_none() {
+                parser.parse_ident()?;
+                animation.iteration_count = Some(IterationCount::Infinite);
+            } else if let (Some(direction), None) =
+                (AnimationDirection::from_keyword(&name), animation.direction)
+            {
+                parser.parse_ident()?;
+                animation.direction = Some(direction);
+            } else if let (Some(fill_mode), None) =
+                (AnimationFillMode::from_keyword(&name), animation.fill_mode)
+            {
+                parser.parse_ident()?;
+                animation.fill_mode = Some(fill_mode);
+            } else if let (Some(play_state), None) = (
+                AnimationPlayState::from_keyword(&name),
+                animation.play_state,
+            ) {
+                parser.parse_ident()?;
+                animation.play_state = Some(play_state);
+            } else if animation.name.is_none() && !name.is_empty() {
+                animation.name = Some(parser.parse_ident()?.0);
+            } else {
+                return Err(parser.error("Unexpected value"));
+            }
+        }
+        if animation == SingleAnimation::default() {
+            return Err(ParseError::new(offset, "Value is required."));
+        }
+        Ok(animation)
+    }
+}
+
+impl Parse for Animation {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let mut animations = vec![parser.parse()?];
+        while parser.parse_optional_punct(',') {
+            animations.push(parser.parse()?);
+        }
+        Ok(Animation(animations))
+    }
+}
@@ -0,0 +1,245 @@
+use super::{rule::parse_declaration, Parse, ParseError, Parser};
+use crate::{
+    Condition, Keyframe, Keyframes, MediaFeature, MediaQuery, Parcentage, Pseudo, StyleBlock,
+    StyleRule, SupportsCondition,
+};
+
+/// Parses rules and nested blocks up to `}` or the end of the source. Nested blocks are
+/// flattened into `blocks`, each carrying the conditions and selector it is nested in.
+/// `@keyframes` are only allowed at the top level.
+pub(super) fn parse_body(
+    parser: &mut Parser,
+    conditions: &[Condition],
+    selector: &[Pseudo],
+    rules: &mut Vec<StyleRule>,
+    blocks: &mut Vec<StyleBlock>,
+    keyframes: &mut Vec<Keyframes>,
+) -> Result<(), ParseError> {
+    loop {
+        while parser.parse_optional_punct(';') {}
+        if parser.peek().is_none() || parser.peek_punct('}') {
+            return Ok(());
+        }
+        if parser.peek_punct('&') {
+            let offset = parser.offset();
+            parser.parse_punct('&')?;
+            let mut selector = selector.to_vec();
+            let len = selector.len();
+            while parser.parse_optional_punct(':') {
+                if parser.parse_optional_punct(':') {
+                    selector.push(Pseudo::Element(parser.parse_ident()?.0));
+                } else {
+                    selector.push(Pseudo::Class(parser.parse_ident()?.0));
+                }
+            }
+            if selector.len() == len {
+                return Err(ParseError::new(
+                    offset,
+                    "Pseudo-class or pseudo-element is expected.",
+                ));
+            }
+            parse_nested(parser, conditions, &selector, blocks, keyframes)?;
+        } else if parser.peek_punct('@') {
+            parser.parse_punct('@')?;
+            let (name, offset) = parser.parse_ident()?;
+            if name.eq_ignore_ascii_case("keyframes") {
+                if !conditions.is_empty() || !selector.is_empty() {
+                    return Err(ParseError::new(
+                        offset,
+                        "@keyframes is only allowed at the top level.",
+                    ));
+                }
+                keyframes.push(parser.parse()?);
+                continue;
+            }
+            let condition = match name.to_ascii_lowercase().as_str() {
+                "media" => Condition::Media(parse_media_queries(parser)?),
+                "supports" => Condition::Supports(parser.parse()?),
+                _ => {
+                    return Err(ParseError::new(
+                        offset,
+                        format!("Unexpected at-rule @{}", name),
+                    ))
+                }
+            };
+            let mut conditions = conditions.to_vec();
+            conditions.push(condition);
+            parse_nested(parser, &conditions, selector, blocks, keyframes)?;
+        } else {
+            rules.push(parse_declaration(parser)?);
+            if !parser.peek_punct('}') && parser.peek().is_some() {
+                parser.parse_punct(';')?;
+            }
+        }
+    }
+}
+
+fn parse_nested(
+    parser: &mut Parser,
+    conditions: &[Condition],
+    selector: &[Pseudo],
+    blocks: &mut Vec<StyleBlock>,
+    keyframes: &mut Vec<Keyframes>,
+) -> Result<(), ParseError> {
+    parser.parse_punct('{')?;
+    let mut rules = vec![];
+    let mut nested = vec![];
+    parse_body(
+        parser,
+        conditions,
+        selector,
+        &mut rules,
+        &mut nested,
+        keyframes,
+    )?;
+    parser.parse_punct('}')?;
+    if !rules.is_empty() {
+        blocks.push(StyleBlock {
+            conditions: conditions.to_vec(),
+            selector: selector.to_vec(),
+            rules,
+        });
+    }
+    blocks.extend(nested);
+    Ok(())
+}
+
+impl Parse for Keyframes {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let (name, _) = parser.parse_ident()?;
+        parser.parse_punct('{')?;
+        let mut frames = vec![];
+        while !parser.parse_optional_punct('}') {
+            let mut selectors = vec![parse_keyframe_selector(parser)?];
+            while parser.parse_optional_punct(',') {
+                selectors.push(parse_keyframe_selector(parser)?);
+            }
+            parser.parse_punct('{')?;
+            let mut rules = vec![];
+            loop {
+                while parser.parse_optional_punct(';') {}
+                if parser.parse_optional_punct('}') {
+                    break;
+                }
+                rules.push(parse_declaration(parser)?);
+                if !parser.peek_punct('}') {
+                    parser.parse_punct(';')?;
+                }
+            }
+            frames.push(Keyframe { selectors, rules });
+        }
+        Ok(Keyframes { name, frames })
+    }
+}
+
+/// `from`, `to` or a percentage.
+fn parse_keyframe_selector(parser: &mut Parser) -> Result<Parcentage, ParseError> {
+    if parser.peek_ident() {
+        let (name, offset) = parser.parse_ident()?;
+        return match name.to_ascii_lowercase().as_str() {
+            "from" => Ok(Parcentage(0.)),
+            "to" => Ok(Parcentage(100.)),
+            _ => Err(ParseError::new(
+                offset,
+                format!("Unexpected keyframe selector {}", name),
+            )),
+        };
+    }
+    let number = parser.parse_number()?;
+    match number.parcentage() {
+        Some(parcentage) if (0. ..=100.).contains(&parcentage.0) => Ok(parcentage),
+        Some(_) => Err(ParseError::new(number.offset, "0% to 100% is expected.")),
+        None => Err(number.error()),
+    }
+}
+
+fn parse_media_queries(parser: &mut Parser) -> Result<Vec<MediaQuery>, ParseError> {
+    let mut queries = vec![];
+    loop {
+        let mut query = MediaQuery {
+            media_type: None,
+            features: vec![],
+        };
+        if parser.peek_punct('(') {
+            query.features.push(parser.parse()?);
+        } else {
+            query.media_type = Some(parser.parse()?);
+        }
+        while parser.peek_keyword("and") {
+            parser.parse_ident()?;
+            query.features.push(parser.parse()?);
+        }
+        queries.push(query);
+        if !parser.parse_optional_punct(',') {
+            return Ok(queries);
+        }
+    }
+}
+
+impl Parse for MediaFeature {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        parser.parse_punct('(')?;
+        let (name, offset) = parser.parse_ident()?;
+        parser.parse_punct(':')?;
+        let feature = match name.to_ascii_lowercase().as_str() {
+            "min-width" => MediaFeature::MinWidth(parser.parse()?),
+            "max-width" => MediaFeature::MaxWidth(parser.parse()?),
+            "min-height" => MediaFeature::MinHeight(parser.parse()?),
+            "max-height" => MediaFeature::MaxHeight(parser.parse()?),
+            "orientation" => MediaFeature::Orientation(parser.parse()?),
+            "prefers-color-scheme" => MediaFeature::PrefersColorScheme(parser.parse()?),
+            "prefers-reduced-motion" => MediaFeature::PrefersReducedMotion(parser.parse()?),
+            _ => {
+                return Err(ParseError::new(
+                    offset,
+                    format!("Unexpected media feature {}", name),
+                ))
+            }
+        };
+        parser.parse_punct(')')?;
+        Ok(feature)
+    }
+}
+
+impl Parse for SupportsCondition {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("not") {
+            parser.parse_ident()?;
+            return Ok(SupportsCondition::Not(Box::new(parse_supports_in_parens(
+                parser,
+            )?)));
+        }
+        let first = parse_supports_in_parens(parser)?;
+        let operator = if parser.peek_keyword("and") {
+            "and"
+        } else if parser.peek_keyword("or") {
+            "or"
+        } else {
+            return Ok(first);
+        };
+        let mut conditions = vec![first];
+        while parser.peek_keyword(operator) {
+            parser.parse_ident()?;
+            conditions.push(parse_supports_in_parens(parser)?);
+        }
+        if parser.peek_keyword("and") || parser.peek_keyword("or") {
+            return Err(parser.error("`and` and `or` cannot be mixed without parentheses."));
+        }
+        Ok(if operator == "and" {
+            SupportsCondition::And(conditions)
+        } else {
+            SupportsCondition::Or(conditions)
+        })
+    }
+}
+
+fn parse_supports_in_parens(parser: &mut Parser) -> Result<SupportsCondition, ParseError> {
+    parser.parse_punct('(')?;
+    let condition = if parser.peek_punct('(') || parser.peek_keyword("not") {
+        parser.parse()?
+    } else {
+        SupportsCondition::Declaration(parse_declaration(parser)?)
+    };
+    parser.parse_punct(')')?;
+    Ok(condition)
+}
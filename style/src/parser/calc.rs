@@ -0,0 +1,95 @@
+use super::{Parse, ParseError, Parser};
+use crate::Calc;
+
+/// Whether `calc()`, `min()`, `max()` or `clamp()` follows.
+pub(super) fn peek_math(parser: &Parser) -> bool {
+    ["calc", "min", "max", "clamp"]
+        .iter()
+        .any(|name| parser.peek_function(name))
+}
+
+impl Parse for Calc {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let (name, offset) = parser.parse_function()?;
+        let calc = match name.as_str() {
+            "calc" => parse_sum(parser)?,
+            "min" | "max" => {
+                let mut args = vec![parse_sum(parser)?];
+                while parser.parse_optional_punct(',') {
+                    args.push(parse_sum(parser)?);
+                }
+                if name == "min" {
+                    Calc::Min(args)
+                } else {
+                    Calc::Max(args)
+                }
+            }
+            "clamp" => {
+                let min = parse_sum(parser)?;
+                parser.parse_punct(',')?;
+                let value = parse_sum(parser)?;
+                parser.parse_punct(',')?;
+                let max = parse_sum(parser)?;
+                Calc::Clamp(Box::new(min), Box::new(value), Box::new(max))
+            }
+            _ => {
+                return Err(ParseError::new(
+                    offset,
+                    format!("Unexpected function {}()", name),
+                ))
+            }
+        };
+        parser.parse_punct(')')?;
+        Ok(calc)
+    }
+}
+
+fn parse_sum(parser: &mut Parser) -> Result<Calc, ParseError> {
+    let mut lhs = parse_product(parser)?;
+    loop {
+        lhs = if parser.parse_optional_punct('+') {
+            Calc::Add(Box::new(lhs), Box::new(parse_product(parser)?))
+        } else if parser.parse_optional_punct('-') {
+            Calc::Sub(Box::new(lhs), Box::new(parse_product(parser)?))
+        } else {
+            return Ok(lhs);
+        }
+    }
+}
+
+fn parse_product(parser: &mut Parser) -> Result<Calc, ParseError> {
+    let mut lhs = parse_operand(parser)?;
+    loop {
+        lhs = if parser.parse_optional_punct('*') {
+            Calc::Mul(Box::new(lhs), Box::new(parse_operand(parser)?))
+        } else if parser.parse_optional_punct('/') {
+            let offset = parser.offset();
+            let rhs = parse_operand(parser)?;
+            if rhs == Calc::Number(0.0) {
+                return Err(ParseError::new(offset, "Division by zero"));
+            }
+            Calc::Div(Box::new(lhs), Box::new(rhs))
+        } else {
+            return Ok(lhs);
+        }
+    }
+}
+
+fn parse_operand(parser: &mut Parser) -> Result<Calc, ParseError> {
+    if peek_math(parser) {
+        return parser.parse();
+    }
+    if parser.parse_optional_punct('(') {
+        let sum = parse_sum(parser)?;
+        parser.parse_punct(')')?;
+        return Ok(sum);
+    }
+    let number = parser.parse_number()?;
+    Ok(if number.unit.is_empty() {
+        Calc::Number(number.value)
+    } else if let Some(parcentage) = number.parcentage() {
+        Calc::Parcentage(parcentage)
+    } else {
+        Calc::Length(number.length().ok_or_else(|| number.error())?)
+    })
+}
@@ -0,0 +1,173 @@
+use super::{Parse, ParseError, Parser};
+use crate::{
+    Gap, GridArea, GridLine, GridTemplate, LengthOrPercentage, TrackBreadth, TrackListItem,
+    TrackRepeat, TrackSize,
+};
+
+impl Parse for f64 {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let number = parser.parse_number()?;
+        if !number.unit.is_empty() || number.value < 0. {
+            return Err(ParseError::new(
+                number.offset,
+                "Non-negative number is expected.",
+            ));
+        }
+        Ok(number.value)
+    }
+}
+
+impl Parse for Gap {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let row = parser.parse::<LengthOrPercentage>()?;
+        let column = if parser.at_end() {
+            row.clone()
+        } else {
+            parser.parse()?
+        };
+        Ok(Gap { row, column })
+    }
+}
+
+impl Parse for TrackBreadth {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_ident() {
+            let (name, offset) = parser.parse_ident()?;
+            return Ok(match name.to_ascii_lowercase().as_str() {
+                "auto" => TrackBreadth::Auto,
+                "min-content" => TrackBreadth::MinContent,
+                "max-content" => TrackBreadth::MaxContent,
+                _ => {
+                    return Err(ParseError::new(
+                        offset,
+                        format!("Unexpected value {}", name),
+                    ))
+                }
+            });
+        }
+        let number = parser.parse_number()?;
+        Ok(if number.unit.eq_ignore_ascii_case("fr") {
+            TrackBreadth::Fr(number.value)
+        } else if let Some(parcentage) = number.parcentage() {
+            TrackBreadth::Parcentage(parcentage)
+        } else {
+            TrackBreadth::Length(number.length().ok_or_else(|| number.error())?)
+        })
+    }
+}
+
+impl Parse for TrackSize {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_function("minmax") {
+            parser.parse_function()?;
+            let min = parser.parse()?;
+            parser.parse_punct(',')?;
+            let max = parser.parse()?;
+            parser.parse_punct(')')?;
+            return Ok(TrackSize::MinMax(min, max));
+        }
+        Ok(TrackSize::Breadth(parser.parse()?))
+    }
+}
+
+impl Parse for TrackRepeat {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_ident() {
+            let (name, offset) = parser.parse_ident()?;
+            return Ok(match name.to_ascii_lowercase().as_str() {
+                "auto-fill" => TrackRepeat::AutoFill,
+                "auto-fit" => TrackRepeat::AutoFit,
+                _ => {
+                    return Err(ParseError::new(
+                        offset,
+                        format!("Unexpected value {}", name),
+                    ))
+                }
+            });
+        }
+        let number = parser.parse_number()?;
+        if !number.unit.is_empty() || number.value.fract() != 0. || number.value < 1. {
+            return Err(ParseError::new(
+                number.offset,
+                "Positive integer is expected.",
+            ));
+        }
+        Ok(TrackRepeat::Count(number.value as u32))
+    }
+}
+
+impl Parse for TrackListItem {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_function("repeat") {
+            parser.parse_function()?;
+            let repeat = parser.parse()?;
+            parser.parse_punct(',')?;
+            let mut sizes = vec![parser.parse()?];
+            while !parser.at_end() {
+                sizes.push(parser.parse()?);
+            }
+            parser.parse_punct(')')?;
+            return Ok(TrackListItem::Repeat(repeat, sizes));
+        }
+        Ok(TrackListItem::Size(parser.parse()?))
+    }
+}
+
+impl Parse for GridTemplate {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("none") {
+            parser.parse_ident()?;
+            return Ok(GridTemplate::None);
+        }
+        let mut tracks = vec![parser.parse()?];
+        while !parser.at_end() {
+            tracks.push(parser.parse()?);
+        }
+        Ok(GridTemplate::Tracks(tracks))
+    }
+}
+
+impl Parse for GridLine {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let integer = |parser: &mut Parser| {
+            let number = parser.parse_number()?;
+            if !number.unit.is_empty() || number.value.fract() != 0. || number.value == 0. {
+                return Err(ParseError::new(
+                    number.offset,
+                    "Non-zero integer is expected.",
+                ));
+            }
+            Ok(number.value as i32)
+        };
+        if !parser.peek_ident() {
+            return Ok(GridLine::Index(integer(parser)?));
+        }
+        let (name, _) = parser.parse_ident()?;
+        Ok(match name.as_str() {
+            "auto" => GridLine::Auto,
+            "span" => {
+                let offset = parser.offset();
+                let value = integer(parser)?;
+                if value < 0 {
+                    return Err(ParseError::new(offset, "Positive integer is expected."));
+                }
+                GridLine::Span(value as u32)
+            }
+            _ => GridLine::Name(name),
+        })
+    }
+}
+
+impl Parse for GridArea {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut lines = vec![parser.parse()?];
+        while parser.parse_optional_punct('/') {
+            lines.push(parser.parse()?);
+        }
+        if lines.len() > 4 {
+            return Err(ParseError::new(offset, "1 to 4 values are expected."));
+        }
+        Ok(GridArea(lines))
+    }
+}
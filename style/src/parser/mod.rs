@@ -0,0 +1,498 @@
+mod animation;
+mod block;
+mod calc;
+mod layout;
+mod rule;
+mod tokenizer;
+mod typography;
+mod value;
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{
+    Color, FontFamily, FontWeight, Keyframes, Length, LengthOrPercentage, Parcentage, StyleBlock,
+    StyleRule, Time, TimingFunction,
+};
+pub use rule::is_property;
+use tokenizer::{tokenize, Token, TokenKind};
+
+/// An error of [`parse_declarations`], pointing at a byte offset of the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    offset: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            offset,
+            message: message.into(),
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.offset)
+    }
+}
+
+impl Error for ParseError {}
+
+pub(crate) struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn peek(&self) -> Option<&'a TokenKind> {
+        self.tokens.get(self.pos).map(|token| &token.kind)
+    }
+
+    pub(crate) fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    /// Offset of the next token, or the end of the source.
+    pub(crate) fn offset(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map(|token| token.start)
+            .unwrap_or(self.len)
+    }
+
+    pub(crate) fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.offset(), message)
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.tokens.get(self.pos) {
+            Some(_) => self.error("Unexpected token"),
+            None => self.error("Unexpected end of input"),
+        }
+    }
+
+    /// End of a value: `;`, `)`, `}` or the end of the source.
+    pub(crate) fn at_end(&self) -> bool {
+        matches!(
+            self.peek(),
+            None | Some(TokenKind::Punct(';'))
+                | Some(TokenKind::Punct(')'))
+                | Some(TokenKind::Punct('}'))
+        )
+    }
+
+    pub(crate) fn peek_ident(&self) -> bool {
+        matches!(self.peek(), Some(TokenKind::Ident(_)))
+    }
+
+    pub(crate) fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(TokenKind::Ident(name)) if name.eq_ignore_ascii_case(keyword))
+    }
+
+    pub(crate) fn peek_function(&self, function: &str) -> bool {
+        matches!(self.peek(), Some(TokenKind::Function(name)) if name.eq_ignore_ascii_case(function))
+    }
+
+    pub(crate) fn peek_punct(&self, punct: char) -> bool {
+        self.peek() == Some(&TokenKind::Punct(punct))
+    }
+
+    pub(crate) fn parse_ident(&mut self) -> Result<(String, usize), ParseError> {
+        match self.peek() {
+            Some(TokenKind::Ident(name)) => {
+                let offset = self.offset();
+                self.pos += 1;
+                Ok((name.clone(), offset))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    pub(crate) fn parse_number(&mut self) -> Result<Number, ParseError> {
+        match self.peek() {
+            Some(TokenKind::Number(value, unit)) => {
+                let offset = self.offset();
+                self.pos += 1;
+                Ok(Number {
+                    value: *value,
+                    unit: unit.clone(),
+                    offset,
+                })
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    pub(crate) fn parse_punct(&mut self, punct: char) -> Result<(), ParseError> {
+        if self.peek_punct(punct) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}` is expected.", punct)))
+        }
+    }
+
+    pub(crate) fn parse_optional_punct(&mut self, punct: char) -> bool {
+        self.parse_punct(punct).is_ok()
+    }
+
+    /// The name of a function token. Its arguments follow up to `)`.
+    pub(crate) fn parse_function(&mut self) -> Result<(String, usize), ParseError> {
+        match self.peek() {
+            Some(TokenKind::Function(name)) => {
+                let offset = self.offset();
+                self.pos += 1;
+                Ok((name.to_ascii_lowercase(), offset))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    pub(crate) fn parse<T: Parse>(&mut self) -> Result<T, ParseError> {
+        T::parse(self)
+    }
+}
+
+pub(crate) trait Parse: Sized {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError>;
+}
+
+pub(crate) struct Number {
+    pub(crate) value: f64,
+    pub(crate) unit: String,
+    pub(crate) offset: usize,
+}
+
+impl Number {
+    pub(crate) fn length(&self) -> Option<Length> {
+        Length::from_unit(self.value, &self.unit)
+    }
+
+    pub(crate) fn parcentage(&self) -> Option<Parcentage> {
+        if self.unit == "%" {
+            Some(Parcentage(self.value))
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn error(&self) -> ParseError {
+        if self.unit.is_empty() {
+            ParseError::new(self.offset, "Suffix is required.")
+        } else {
+            ParseError::new(self.offset, format!("Unexpected suffix {}", self.unit))
+        }
+    }
+}
+
+fn parse_with<T>(
+    css: &str,
+    f: impl FnOnce(&mut Parser) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let tokens = tokenize(css)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        len: css.len(),
+    };
+    let value = f(&mut parser)?;
+    if parser.peek().is_some() {
+        return Err(parser.unexpected());
+    }
+    Ok(value)
+}
+
+/// Parses a single `name: value` declaration. A trailing `;` is allowed.
+pub fn parse_declaration(css: &str) -> Result<StyleRule, ParseError> {
+    parse_with(css, |parser| {
+        let rule = rule::parse_declaration(parser)?;
+        parser.parse_optional_punct(';');
+        Ok(rule)
+    })
+}
+
+/// Parses a declaration block such as `left: 10px; color: red;`, without braces.
+pub fn parse_declarations(css: &str) -> Result<Vec<StyleRule>, ParseError> {
+    parse_with(css, |parser| {
+        let mut rules = vec![];
+        loop {
+            while parser.parse_optional_punct(';') {}
+            if parser.peek().is_none() {
+                return Ok(rules);
+            }
+            rules.push(rule::parse_declaration(parser)?);
+            if parser.peek().is_some() {
+                parser.parse_punct(';')?;
+            }
+        }
+    })
+}
+
+/// What [`parse_block`] returns, matching [`StyleSet`](crate::StyleSet)'s `rules()`,
+/// `blocks()` and `keyframes()`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyleBody {
+    pub rules: Vec<StyleRule>,
+    pub blocks: Vec<StyleBlock>,
+    pub keyframes: Vec<Keyframes>,
+}
+
+/// Parses the body of a style set: declarations, nested `&:hover { .. }`, `@media` and
+/// `@supports` blocks, and `@keyframes`.
+pub fn parse_block(css: &str) -> Result<StyleBody, ParseError> {
+    parse_with(css, |parser| {
+        let mut body = StyleBody::default();
+        block::parse_body(
+            parser,
+            &[],
+            &[],
+            &mut body.rules,
+            &mut body.blocks,
+            &mut body.keyframes,
+        )?;
+        Ok(body)
+    })
+}
+
+impl FromStr for StyleRule {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_declaration(s)
+    }
+}
+
+macro_rules! from_str {
+    ($($ty:ident)*) => {$(
+        /// Parses a value as it is written after `property:`.
+        impl FromStr for $ty {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_with(s, |parser| parser.parse())
+            }
+        }
+    )*};
+}
+
+from_str! {
+    Color Length LengthOrPercentage FontFamily FontWeight Time TimingFunction
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_block, parse_declaration, parse_declarations, StyleBody};
+    use crate::{
+        Color, Condition, FamilyName, FontFamily, GenericFamily, GridLine, Length,
+        LengthOrPercentage, LengthOrPercentageOrAuto, MediaFeature, MediaQuery, Pseudo, Sides,
+        StyleBlock, StyleRule, TrackBreadth, TrackListItem, TrackRepeat, TrackSize, WritingMode,
+    };
+
+    #[test]
+    fn declarations() {
+        assert_eq!(
+            parse_declarations(
+                "left: 10px; writing-mode: vertical-rl;; margin: 0 auto; color: #ff0000"
+            ),
+            Ok(vec![
+                StyleRule::Left(LengthOrPercentage::Length(Length::Px(10.))),
+                StyleRule::WritingMode(WritingMode::VerticalRl),
+                StyleRule::Margin(Sides {
+                    top: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    right: LengthOrPercentageOrAuto::Auto,
+                    bottom: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    left: LengthOrPercentageOrAuto::Auto,
+                }),
+                StyleRule::Color(Color::rgb(255, 0, 0)),
+            ])
+        );
+        assert_eq!(parse_declarations(" /* empty */ "), Ok(vec![]));
+    }
+
+    #[test]
+    fn values() {
+        let display = |css: &str| parse_declaration(css).unwrap().to_string();
+        assert_eq!(
+            display("font-family: \"Noto Sans\", Noto Serif JP, sans-serif"),
+            StyleRule::FontFamily(FontFamily(vec![
+                FamilyName::Named("Noto Sans".into()),
+                FamilyName::Named("Noto Serif JP".into()),
+                FamilyName::Generic(GenericFamily::SansSerif),
+            ]))
+            .to_string()
+        );
+        assert_eq!(
+            parse_declaration("grid-template-columns: repeat(auto-fill, minmax(100px, 1fr))"),
+            Ok(StyleRule::GridTemplateColumns(crate::GridTemplate::Tracks(
+                vec![TrackListItem::Repeat(
+                    TrackRepeat::AutoFill,
+                    vec![TrackSize::MinMax(
+                        TrackBreadth::Length(Length::Px(100.)),
+                        TrackBreadth::Fr(1.)
+                    )]
+                )]
+            )))
+        );
+        assert_eq!(
+            parse_declaration("grid-area: span 2 / -1"),
+            Ok(StyleRule::GridArea(crate::GridArea(vec![
+                GridLine::Span(2),
+                GridLine::Index(-1)
+            ])))
+        );
+        assert_eq!(
+            parse_declaration("background-color: rgba(0, 0, 255, 50%);"),
+            Ok(StyleRule::BackgroundColor(Color::rgba(0, 0, 255, 0.5)))
+        );
+        assert_eq!(
+            parse_declaration("left: var(--offset)"),
+            Ok(StyleRule::Var("left".into(), "offset".into()))
+        );
+        assert_eq!(display("z-index: -1"), "z-index:-1;");
+        assert_eq!(
+            display("width: calc(100% - (1rem + 2px) * 2)"),
+            "width:calc(100% - ((1rem + 2px) * 2));"
+        );
+        assert_eq!(
+            display("max-width: min(90vw,clamp(10pt, 50%, 3in))"),
+            "max-width:min(90vw,clamp(10pt,50%,3in));"
+        );
+        assert_eq!(
+            "LEFT: 1PX".parse::<StyleRule>(),
+            Ok(StyleRule::Left(LengthOrPercentage::Length(Length::Px(1.))))
+        );
+    }
+
+    #[test]
+    fn block() {
+        let StyleBody { rules, blocks, .. } = parse_block(
+            "color: red; &:hover { color: blue } @media (max-width: 600px) { &::after { top: 0 } }",
+        )
+        .unwrap();
+        assert_eq!(rules, vec![StyleRule::Color(Color::rgb(255, 0, 0))]);
+        assert_eq!(
+            blocks,
+            vec![
+                StyleBlock {
+                    conditions: vec![],
+                    selector: vec![Pseudo::Class("hover".into())],
+                    rules: vec![StyleRule::Color(Color::rgb(0, 0, 255))],
+                },
+                StyleBlock {
+                    conditions: vec![Condition::Media(vec![MediaQuery {
+                        media_type: None,
+                        features: vec![MediaFeature::MaxWidth(Length::Px(600.))],
+                    }])],
+                    selector: vec![Pseudo::Element("after".into())],
+                    rules: vec![StyleRule::Top(LengthOrPercentage::Length(Length::Px(0.)))],
+                },
+            ]
+        );
+        assert_eq!(
+            parse_block("&:hover { color: blue").unwrap_err().message(),
+            "`}` is expected."
+        );
+        assert_eq!(parse_block("@page {}").unwrap_err().offset(), 1);
+    }
+
+    #[test]
+    fn offsets() {
+        let offset = |css: &str| parse_block(css).unwrap_err().offset();
+        assert_eq!(offset("&:hover { color: blue"), 21);
+        assert_eq!(offset("@media (max-width: 600px) { left: 0"), 35);
+        assert_eq!(offset("left: 1px; lft: 1px"), 11);
+        assert_eq!(offset("left: 1px; right 1px"), 17);
+        let css = "font-family: \"游ゴシック\"; lft: 1px";
+        assert_eq!(offset(css), 32);
+        assert_eq!(&css[32..], "lft: 1px");
+    }
+
+    #[test]
+    fn errors() {
+        let error = |css: &str| {
+            let error = parse_declarations(css).unwrap_err();
+            (error.offset(), error.message().to_string())
+        };
+        assert_eq!(
+            error("left: 1px; lft: 1px"),
+            (11, "Unexpected rule name lft".into())
+        );
+        assert_eq!(error("left: 10"), (6, "Suffix is required.".into()));
+        assert_eq!(error("left: 10pc"), (6, "Unexpected suffix pc".into()));
+        assert_eq!(
+            error("margin: 1px 2px 3px 4px 5px"),
+            (8, "1 to 4 values are expected.".into())
+        );
+        assert_eq!(error("left: 1px 2px"), (10, "`;` is expected.".into()));
+        assert_eq!(error("left"), (4, "`:` is expected.".into()));
+        assert_eq!(error("color: #ggg"), (7, "Unexpected color #ggg".into()));
+        assert_eq!(error("left:"), (5, "Unexpected end of input".into()));
+        assert_eq!(
+            error("width: calc(1px / 0)"),
+            (18, "Division by zero".into())
+        );
+    }
+
+    #[test]
+    fn animations() {
+        let display = |css: &str| parse_declaration(css).unwrap().to_string();
+        assert_eq!(
+            display(
+                "transition: opacity .3s ease-in, width 1s cubic-bezier(0.1, 0.7, 1, 0.1) 200ms"
+            ),
+            "transition:opacity 0.3s ease-in,width 1s cubic-bezier(0.1,0.7,1,0.1) 200ms;"
+        );
+        assert_eq!(
+            display("animation: fade 2s steps(4, jump-end) infinite alternate both paused"),
+            "animation:2s steps(4,jump-end) infinite alternate both paused fade;"
+        );
+        assert_eq!(display("animation: none"), "animation:none;");
+        let keyframes = parse_block(
+            "animation: fade 1s; @keyframes fade { from { opacity: 0 } 50%, 75% { opacity: .5; } to { opacity: 1 } }",
+        )
+        .unwrap()
+        .keyframes;
+        assert_eq!(
+            keyframes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["@keyframes fade{0%{opacity:0;}50%,75%{opacity:0.5;}100%{opacity:1;}}"]
+        );
+        let error = |css: &str| {
+            let error = parse_block(css).unwrap_err();
+            (error.offset(), error.message().to_string())
+        };
+        assert_eq!(
+            error("transition: 1s 2s 3s"),
+            (18, "Unexpected value".into())
+        );
+        assert_eq!(
+            error("transition: 1px"),
+            (12, "Unexpected suffix px".into())
+        );
+        assert_eq!(
+            error("&:hover { @keyframes fade { } }"),
+            (11, "@keyframes is only allowed at the top level.".into())
+        );
+        assert_eq!(
+            error("@keyframes fade { 120% { opacity: 0 } }"),
+            (18, "0% to 100% is expected.".into())
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use super::{tokenizer::TokenKind, ParseError, Parser};
+use crate::{
+    AlignItems, Animation, Border, BorderStyle, BoxSizing, Color, Direction, DisplayType,
+    FlexDirection, FlexWrap, FontFamily, FontSize, FontWeight, Gap, GridArea, GridTemplate,
+    JustifyContent, LengthOrPercentage, LengthOrPercentageOrAuto, LengthOrPercentageOrNone,
+    LetterSpacing, LineHeight, LineWidth, LogicalPair, Overflow, Position, RubyPosition, Sides,
+    StyleRule, TextAlign, TextCombineUpright, TextEmphasis, TextOrientation, Transition,
+    Visibility, WritingMode, ZIndex,
+};
+
+macro_rules! style_rules {
+    ($($name:literal => $variant:ident($ty:ty),)*) => {
+        /// Whether `name` is a property [`StyleRule`] can hold.
+        pub fn is_property(name: &str) -> bool {
+            matches!(name, $($name)|*)
+        }
+
+        fn parse_value(name: &str, offset: usize, parser: &mut Parser) -> Result<StyleRule, ParseError> {
+            Ok(match name {
+                $($name => StyleRule::$variant(parser.parse::<$ty>()?),)*
+                _ => {
+                    return Err(ParseError::new(
+                        offset,
+                        format!("Unexpected rule name {}", name),
+                    ))
+                }
+            })
+        }
+    };
+}
+
+style_rules! {
+    "left" => Left(LengthOrPercentage),
+    "right" => Right(LengthOrPercentage),
+    "writing-mode" => WritingMode(WritingMode),
+    "top" => Top(LengthOrPercentage),
+    "bottom" => Bottom(LengthOrPercentage),
+    "position" => Position(Position),
+    "z-index" => ZIndex(ZIndex),
+    "width" => Width(LengthOrPercentageOrAuto),
+    "height" => Height(LengthOrPercentageOrAuto),
+    "min-width" => MinWidth(LengthOrPercentageOrAuto),
+    "min-height" => MinHeight(LengthOrPercentageOrAuto),
+    "max-width" => MaxWidth(LengthOrPercentageOrNone),
+    "max-height" => MaxHeight(LengthOrPercentageOrNone),
+    "box-sizing" => BoxSizing(BoxSizing),
+    "margin" => Margin(Sides<LengthOrPercentageOrAuto>),
+    "margin-top" => MarginTop(LengthOrPercentageOrAuto),
+    "margin-right" => MarginRight(LengthOrPercentageOrAuto),
+    "margin-bottom" => MarginBottom(LengthOrPercentageOrAuto),
+    "margin-left" => MarginLeft(LengthOrPercentageOrAuto),
+    "padding" => Padding(Sides<LengthOrPercentage>),
+    "padding-top" => PaddingTop(LengthOrPercentage),
+    "padding-right" => PaddingRight(LengthOrPercentage),
+    "padding-bottom" => PaddingBottom(LengthOrPercentage),
+    "padding-left" => PaddingLeft(LengthOrPercentage),
+    "border" => Border(Border),
+    "border-width" => BorderWidth(Sides<LineWidth>),
+    "border-style" => BorderStyle(Sides<BorderStyle>),
+    "display" => Display(DisplayType),
+    "visibility" => Visibility(Visibility),
+    "overflow" => Overflow(Overflow),
+    "overflow-x" => OverflowX(Overflow),
+    "overflow-y" => OverflowY(Overflow),
+    "color" => Color(Color),
+    "background-color" => BackgroundColor(Color),
+    "border-color" => BorderColor(Sides<Color>),
+    "flex-direction" => FlexDirection(FlexDirection),
+    "flex-wrap" => FlexWrap(FlexWrap),
+    "justify-content" => JustifyContent(JustifyContent),
+    "align-items" => AlignItems(AlignItems),
+    "gap" => Gap(Gap),
+    "row-gap" => RowGap(LengthOrPercentage),
+    "column-gap" => ColumnGap(LengthOrPercentage),
+    "flex-grow" => FlexGrow(f64),
+    "flex-shrink" => FlexShrink(f64),
+    "flex-basis" => FlexBasis(LengthOrPercentageOrAuto),
+    "grid-template-columns" => GridTemplateColumns(GridTemplate),
+    "grid-template-rows" => GridTemplateRows(GridTemplate),
+    "grid-area" => GridArea(GridArea),
+    "text-orientation" => TextOrientation(TextOrientation),
+    "text-combine-upright" => TextCombineUpright(TextCombineUpright),
+    "font-family" => FontFamily(FontFamily),
+    "font-size" => FontSize(FontSize),
+    "font-weight" => FontWeight(FontWeight),
+    "line-height" => LineHeight(LineHeight),
+    "letter-spacing" => LetterSpacing(LetterSpacing),
+    "text-align" => TextAlign(TextAlign),
+    "text-emphasis" => TextEmphasis(TextEmphasis),
+    "ruby-position" => RubyPosition(RubyPosition),
+    "direction" => Direction(Direction),
+    "inline-size" => InlineSize(LengthOrPercentageOrAuto),
+    "block-size" => BlockSize(LengthOrPercentageOrAuto),
+    "min-inline-size" => MinInlineSize(LengthOrPercentageOrAuto),
+    "min-block-size" => MinBlockSize(LengthOrPercentageOrAuto),
+    "max-inline-size" => MaxInlineSize(LengthOrPercentageOrNone),
+    "max-block-size" => MaxBlockSize(LengthOrPercentageOrNone),
+    "margin-inline" => MarginInline(LogicalPair<LengthOrPercentageOrAuto>),
+    "margin-inline-start" => MarginInlineStart(LengthOrPercentageOrAuto),
+    "margin-inline-end" => MarginInlineEnd(LengthOrPercentageOrAuto),
+    "margin-block" => MarginBlock(LogicalPair<LengthOrPercentageOrAuto>),
+    "margin-block-start" => MarginBlockStart(LengthOrPercentageOrAuto),
+    "margin-block-end" => MarginBlockEnd(LengthOrPercentageOrAuto),
+    "padding-inline" => PaddingInline(LogicalPair<LengthOrPercentage>),
+    "padding-inline-start" => PaddingInlineStart(LengthOrPercentage),
+    "padding-inline-end" => PaddingInlineEnd(LengthOrPercentage),
+    "padding-block" => PaddingBlock(LogicalPair<LengthOrPercentage>),
+    "padding-block-start" => PaddingBlockStart(LengthOrPercentage),
+    "padding-block-end" => PaddingBlockEnd(LengthOrPercentage),
+    "inset-inline" => InsetInline(LogicalPair<LengthOrPercentage>),
+    "inset-inline-start" => InsetInlineStart(LengthOrPercentage),
+    "inset-inline-end" => InsetInlineEnd(LengthOrPercentage),
+    "inset-block" => InsetBlock(LogicalPair<LengthOrPercentage>),
+    "inset-block-start" => InsetBlockStart(LengthOrPercentage),
+    "inset-block-end" => InsetBlockEnd(LengthOrPercentage),
+    "opacity" => Opacity(f64),
+    "transition" => Transition(Transition),
+    "animation" => Animation(Animation),
+}
+
+/// `name: value` without the trailing `;`. A value of `var(--name)` becomes
+/// [`StyleRule::Var`].
+pub(super) fn parse_declaration(parser: &mut Parser) -> Result<StyleRule, ParseError> {
+    let (name, offset) = parser.parse_ident()?;
+    let name = name.to_ascii_lowercase();
+    parser.parse_punct(':')?;
+    if parser.peek_function("var") {
+        if !is_property(&name) {
+            return Err(ParseError::new(
+                offset,
+                format!("Unexpected rule name {}", name),
+            ));
+        }
+        parser.parse_function()?;
+        let variable = match parser.peek() {
+            Some(TokenKind::Ident(variable)) if variable.starts_with("--") => {
+                variable[2..].to_string()
+            }
+            _ => return Err(parser.error("Custom property name is expected.")),
+        };
+        parser.next();
+        parser.parse_punct(')')?;
+        return Ok(StyleRule::Var(name, variable));
+    }
+    let rule = parse_value(&name, offset, parser)?;
+    if !parser.at_end() {
+        return Err(parser.error("`;` is expected."));
+    }
+    Ok(rule)
+}
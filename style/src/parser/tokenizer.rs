@@ -0,0 +1,270 @@
+use super::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TokenKind {
+    Ident(String),
+    /// `10px`, `50%` or `1.5`. The unit is empty for a plain number.
+    Number(f64, String),
+    /// `#fff`, without the `#`.
+    Hash(String),
+    String(String),
+    /// `name(`. The arguments follow as separate tokens up to the matching `)`.
+    Function(String),
+    Punct(char),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Token {
+    pub(crate) kind: TokenKind,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
+
+fn is_name(c: char) -> bool {
+    is_name_start(c) || c.is_ascii_digit() || c == '-'
+}
+
+struct Tokenizer<'a> {
+    css: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn peek(&self) -> Option<char> {
+        self.css[self.pos..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.css[self.pos..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn starts_name(&self) -> bool {
+        match (self.peek(), self.peek_nth(1)) {
+            (Some('-'), Some(c)) => is_name_start(c) || c == '-',
+            (Some(c), _) => is_name_start(c),
+            _ => false,
+        }
+    }
+
+    fn starts_number(&self) -> bool {
+        let digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+        match self.peek() {
+            Some('-') | Some('+') => {
+                digit(self.peek_nth(1)) || self.peek_nth(1) == Some('.') && digit(self.peek_nth(2))
+            }
+            Some('.') => digit(self.peek_nth(1)),
+            c => digit(c),
+        }
+    }
+
+    fn name(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(is_name) {
+            self.bump();
+        }
+        self.css[start..self.pos].to_string()
+    }
+
+    fn skip_whitespace(&mut self) -> Result<(), ParseError> {
+        loop {
+            if self.peek().is_some_and(char::is_whitespace) {
+                self.bump();
+            } else if self.css[self.pos..].starts_with("/*") {
+                let start = self.pos;
+                match self.css[self.pos + 2..].find("*/") {
+                    Some(end) => self.pos += end + 4,
+                    None => return Err(ParseError::new(start, "Unterminated comment")),
+                }
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<TokenKind, ParseError> {
+        let start = self.pos;
+        if let Some('-') | Some('+') = self.peek() {
+            self.bump();
+        }
+        let digits = |tokenizer: &mut Self| {
+            while tokenizer.peek().is_some_and(|c| c.is_ascii_digit()) {
+                tokenizer.bump();
+            }
+        };
+        digits(self);
+        if self.peek() == Some('.') && self.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+            digits(self);
+        }
+        if let Some('e') | Some('E') = self.peek() {
+            let exponent = match self.peek_nth(1) {
+                Some('-') | Some('+') => self.peek_nth(2),
+                c => c,
+            };
+            if exponent.is_some_and(|c| c.is_ascii_digit()) {
+                self.bump();
+                if let Some('-') | Some('+') = self.peek() {
+                    self.bump();
+                }
+                digits(self);
+            }
+        }
+        let value = self.css[start..self.pos]
+            .parse::<f64>()
+            .map_err(|_| ParseError::new(start, "Invalid number"))?;
+        let unit = if self.peek() == Some('%') {
+            self.bump();
+            "%".to_string()
+        } else if self.starts_name() {
+            self.name()
+        } else {
+            String::new()
+        };
+        Ok(TokenKind::Number(value, unit))
+    }
+
+    fn string(&mut self, quote: char) -> Result<TokenKind, ParseError> {
+        let start = self.pos;
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(ParseError::new(start, "Unterminated string")),
+                Some(c) if c == quote => return Ok(TokenKind::String(value)),
+                Some('\\') => {
+                    let hex_start = self.pos;
+                    while self.pos - hex_start < 6
+                        && self.peek().is_some_and(|c| c.is_ascii_hexdigit())
+                    {
+                        self.bump();
+                    }
+                    if self.pos == hex_start {
+                        match self.bump() {
+                            Some('\n') => {}
+                            Some(c) => value.push(c),
+                            None => return Err(ParseError::new(start, "Unterminated string")),
+                        }
+                    } else {
+                        let code = u32::from_str_radix(&self.css[hex_start..self.pos], 16)
+                            .ok()
+                            .and_then(std::char::from_u32)
+                            .unwrap_or('\u{fffd}');
+                        value.push(code);
+                        if self.peek().is_some_and(char::is_whitespace) {
+                            self.bump();
+                        }
+                    }
+                }
+                Some(c) => value.push(c),
+            }
+        }
+    }
+}
+
+/// Splits `css` into tokens, skipping whitespace and comments.
+pub(crate) fn tokenize(css: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokenizer = Tokenizer { css, pos: 0 };
+    let mut tokens = vec![];
+    loop {
+        tokenizer.skip_whitespace()?;
+        let start = tokenizer.pos;
+        let kind = match tokenizer.peek() {
+            None => return Ok(tokens),
+            Some('"') => tokenizer.string('"')?,
+            Some('\'') => tokenizer.string('\'')?,
+            Some('#') => {
+                tokenizer.bump();
+                let name = tokenizer.name();
+                if name.is_empty() {
+                    return Err(ParseError::new(start, "Unexpected character #"));
+                }
+                TokenKind::Hash(name)
+            }
+            _ if tokenizer.starts_number() => tokenizer.number()?,
+            _ if tokenizer.starts_name() => {
+                let name = tokenizer.name();
+                if tokenizer.peek() == Some('(') {
+                    tokenizer.bump();
+                    TokenKind::Function(name)
+                } else {
+                    TokenKind::Ident(name)
+                }
+            }
+            Some(c) if ":;,(){}&@+-*/".contains(c) => {
+                tokenizer.bump();
+                TokenKind::Punct(c)
+            }
+            Some(c) => {
+                return Err(ParseError::new(
+                    start,
+                    format!("Unexpected character {}", c),
+                ))
+            }
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: tokenizer.pos,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{tokenize, TokenKind};
+
+    fn kinds(css: &str) -> Vec<TokenKind> {
+        tokenize(css)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.kind)
+            .collect()
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(
+            kinds("margin: -1.5em 50% 0 /* auto */ auto;"),
+            vec![
+                TokenKind::Ident("margin".into()),
+                TokenKind::Punct(':'),
+                TokenKind::Number(-1.5, "em".into()),
+                TokenKind::Number(50., "%".into()),
+                TokenKind::Number(0., "".into()),
+                TokenKind::Ident("auto".into()),
+                TokenKind::Punct(';'),
+            ]
+        );
+        assert_eq!(
+            kinds(r#"rgb(1,2) #0f0 "a\"b" --x"#),
+            vec![
+                TokenKind::Function("rgb".into()),
+                TokenKind::Number(1., "".into()),
+                TokenKind::Punct(','),
+                TokenKind::Number(2., "".into()),
+                TokenKind::Punct(')'),
+                TokenKind::Hash("0f0".into()),
+                TokenKind::String("a\"b".into()),
+                TokenKind::Ident("--x".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn offsets() {
+        let tokens = tokenize("top:  10px").unwrap();
+        assert_eq!((tokens[2].start, tokens[2].end), (6, 10));
+        assert_eq!(tokenize("top: 'a").unwrap_err().offset(), 5);
+        assert_eq!(tokenize("top: ?").unwrap_err().offset(), 5);
+    }
+}
@@ -0,0 +1,177 @@
+use super::{tokenizer::TokenKind, Parse, ParseError, Parser};
+use crate::{
+    FamilyName, FontFamily, FontSize, FontWeight, GenericFamily, LetterSpacing, LineHeight,
+    TextCombineUpright, TextEmphasis, TextEmphasisFill, TextEmphasisShape, TextEmphasisStyle,
+};
+
+impl Parse for TextCombineUpright {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let (name, offset) = parser.parse_ident()?;
+        Ok(match name.to_ascii_lowercase().as_str() {
+            "none" => TextCombineUpright::None,
+            "all" => TextCombineUpright::All,
+            "digits" if parser.at_end() => TextCombineUpright::Digits(2),
+            "digits" => {
+                let number = parser.parse_number()?;
+                if !number.unit.is_empty() || !(2. ..=4.).contains(&number.value) {
+                    return Err(ParseError::new(number.offset, "2 to 4 is expected."));
+                }
+                TextCombineUpright::Digits(number.value as u8)
+            }
+            _ => {
+                return Err(ParseError::new(
+                    offset,
+                    format!("Unexpected value {}", name),
+                ))
+            }
+        })
+    }
+}
+
+impl Parse for FamilyName {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if let Some(TokenKind::String(name)) = parser.peek() {
+            parser.next();
+            return Ok(FamilyName::Named(name.clone()));
+        }
+        let (name, _) = parser.parse_ident()?;
+        if let Some(generic) = GenericFamily::from_keyword(&name) {
+            return Ok(FamilyName::Generic(generic));
+        }
+        // Unquoted family names are a sequence of identifiers, e.g. `Noto Serif JP`.
+        let mut name = name;
+        while parser.peek_ident() {
+            name.push(' ');
+            name.push_str(&parser.parse_ident()?.0);
+        }
+        Ok(FamilyName::Named(name))
+    }
+}
+
+impl Parse for FontFamily {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let mut names = vec![parser.parse()?];
+        while parser.parse_optional_punct(',') {
+            names.push(parser.parse()?);
+        }
+        Ok(FontFamily(names))
+    }
+}
+
+impl Parse for FontSize {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_ident() {
+            let (name, offset) = parser.parse_ident()?;
+            return FontSize::from_keyword(&name)
+                .ok_or_else(|| ParseError::new(offset, format!("Unexpected value {}", name)));
+        }
+        let number = parser.parse_number()?;
+        Ok(if let Some(parcentage) = number.parcentage() {
+            FontSize::Parcentage(parcentage)
+        } else {
+            FontSize::Length(number.length().ok_or_else(|| number.error())?)
+        })
+    }
+}
+
+impl Parse for FontWeight {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_ident() {
+            let (name, offset) = parser.parse_ident()?;
+            return Ok(match name.to_ascii_lowercase().as_str() {
+                "normal" => FontWeight::Normal,
+                "bold" => FontWeight::Bold,
+                "bolder" => FontWeight::Bolder,
+                "lighter" => FontWeight::Lighter,
+                _ => {
+                    return Err(ParseError::new(
+                        offset,
+                        format!("Unexpected value {}", name),
+                    ))
+                }
+            });
+        }
+        let number = parser.parse_number()?;
+        if !number.unit.is_empty()
+            || number.value.fract() != 0.
+            || !(1. ..=1000.).contains(&number.value)
+        {
+            return Err(ParseError::new(number.offset, "1 to 1000 is expected."));
+        }
+        Ok(FontWeight::Number(number.value as u16))
+    }
+}
+
+impl Parse for LineHeight {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("normal") {
+            parser.parse_ident()?;
+            return Ok(LineHeight::Normal);
+        }
+        let number = parser.parse_number()?;
+        Ok(if number.unit.is_empty() {
+            LineHeight::Number(number.value)
+        } else if let Some(parcentage) = number.parcentage() {
+            LineHeight::Parcentage(parcentage)
+        } else {
+            LineHeight::Length(number.length().ok_or_else(|| number.error())?)
+        })
+    }
+}
+
+impl Parse for LetterSpacing {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("normal") {
+            parser.parse_ident()?;
+            return Ok(LetterSpacing::Normal);
+        }
+        Ok(LetterSpacing::Length(parser.parse()?))
+    }
+}
+
+impl Parse for TextEmphasis {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut style = None;
+        let mut fill = None;
+        let mut shape = None;
+        let mut color = None;
+        while !parser.at_end() {
+            let name = match parser.peek() {
+                Some(TokenKind::String(string))
+                    if style.is_none() && fill.is_none() && shape.is_none() =>
+                {
+                    parser.next();
+                    style = Some(TextEmphasisStyle::String(string.clone()));
+                    continue;
+                }
+                Some(TokenKind::Ident(name)) => name.to_ascii_lowercase(),
+                _ => String::new(),
+            };
+            if name == "none" && style.is_none() && fill.is_none() && shape.is_none() {
+                parser.parse_ident()?;
+                style = Some(TextEmphasisStyle::None);
+            } else if let (Some(value), None, None) =
+                (TextEmphasisFill::from_keyword(&name), fill, &style)
+            {
+                parser.parse_ident()?;
+                fill = Some(value);
+            } else if let (Some(value), None, None) =
+                (TextEmphasisShape::from_keyword(&name), shape, &style)
+            {
+                parser.parse_ident()?;
+                shape = Some(value);
+            } else if color.is_none() {
+                color = Some(parser.parse()?);
+            } else {
+                return Err(parser.error("Unexpected value"));
+            }
+        }
+        let style = match style {
+            Some(style) => style,
+            None if fill.is_some() || shape.is_some() => TextEmphasisStyle::Shape { fill, shape },
+            None => return Err(ParseError::new(offset, "Style is required.")),
+        };
+        Ok(TextEmphasis { style, color })
+    }
+}
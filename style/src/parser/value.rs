@@ -0,0 +1,259 @@
+use super::{calc::peek_math, Number, Parse, ParseError, Parser};
+use crate::{
+    AlignItems, Border, BorderStyle, BoxSizing, Color, ColorScheme, Direction, DisplayType,
+    FlexDirection, FlexWrap, GenericFamily, JustifyContent, Length, LengthOrPercentage,
+    LengthOrPercentageOrAuto, LengthOrPercentageOrNone, LineWidth, LogicalPair, MediaType,
+    Orientation, Overflow, Position, ReducedMotion, RubyPosition, Sides, TextAlign,
+    TextEmphasisFill, TextEmphasisShape, TextOrientation, Visibility, WritingMode, ZIndex,
+};
+
+use super::tokenizer::TokenKind;
+
+impl Parse for Length {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let number = parser.parse_number()?;
+        number.length().ok_or_else(|| number.error())
+    }
+}
+
+impl Parse for LengthOrPercentage {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if peek_math(parser) {
+            return Ok(LengthOrPercentage::Calc(parser.parse()?));
+        }
+        let number = parser.parse_number()?;
+        Ok(if let Some(parcentage) = number.parcentage() {
+            LengthOrPercentage::Parcentage(parcentage)
+        } else {
+            LengthOrPercentage::Length(number.length().ok_or_else(|| number.error())?)
+        })
+    }
+}
+
+impl Parse for LengthOrPercentageOrAuto {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("auto") {
+            parser.parse_ident()?;
+            return Ok(LengthOrPercentageOrAuto::Auto);
+        }
+        Ok(match parser.parse::<LengthOrPercentage>()? {
+            LengthOrPercentage::Length(length) => LengthOrPercentageOrAuto::Length(length),
+            LengthOrPercentage::Parcentage(parcentage) => {
+                LengthOrPercentageOrAuto::Parcentage(parcentage)
+            }
+            LengthOrPercentage::Calc(calc) => LengthOrPercentageOrAuto::Calc(calc),
+        })
+    }
+}
+
+impl Parse for LengthOrPercentageOrNone {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("none") {
+            parser.parse_ident()?;
+            return Ok(LengthOrPercentageOrNone::None);
+        }
+        Ok(match parser.parse::<LengthOrPercentage>()? {
+            LengthOrPercentage::Length(length) => LengthOrPercentageOrNone::Length(length),
+            LengthOrPercentage::Parcentage(parcentage) => {
+                LengthOrPercentageOrNone::Parcentage(parcentage)
+            }
+            LengthOrPercentage::Calc(calc) => LengthOrPercentageOrNone::Calc(calc),
+        })
+    }
+}
+
+impl Parse for LineWidth {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_ident() {
+            let (name, offset) = parser.parse_ident()?;
+            return Ok(match name.to_ascii_lowercase().as_str() {
+                "thin" => LineWidth::Thin,
+                "medium" => LineWidth::Medium,
+                "thick" => LineWidth::Thick,
+                _ => {
+                    return Err(ParseError::new(
+                        offset,
+                        format!("Unexpected value {}", name),
+                    ))
+                }
+            });
+        }
+        Ok(LineWidth::Length(parser.parse()?))
+    }
+}
+
+impl Parse for ZIndex {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        if parser.peek_keyword("auto") {
+            parser.parse_ident()?;
+            return Ok(ZIndex::Auto);
+        }
+        let number = parser.parse_number()?;
+        if !number.unit.is_empty() || number.value.fract() != 0. {
+            return Err(ParseError::new(number.offset, "Integer is expected."));
+        }
+        Ok(ZIndex::Integer(number.value as i32))
+    }
+}
+
+impl<T: Parse + Clone> Parse for Sides<T> {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut values = vec![];
+        while !parser.at_end() {
+            values.push(parser.parse()?);
+        }
+        Sides::from_values(&values)
+            .ok_or_else(|| ParseError::new(offset, "1 to 4 values are expected."))
+    }
+}
+
+impl<T: Parse + Clone> Parse for LogicalPair<T> {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut values = vec![];
+        while !parser.at_end() {
+            values.push(parser.parse()?);
+        }
+        LogicalPair::from_values(&values)
+            .ok_or_else(|| ParseError::new(offset, "1 or 2 values are expected."))
+    }
+}
+
+impl Parse for Border {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        let mut border = Border {
+            width: None,
+            style: None,
+            color: None,
+        };
+        while !parser.at_end() {
+            let name = match parser.peek() {
+                Some(TokenKind::Ident(name)) => name.to_ascii_lowercase(),
+                _ => String::new(),
+            };
+            if let (Some(style), None) = (BorderStyle::from_keyword(&name), border.style) {
+                parser.parse_ident()?;
+                border.style = Some(style);
+            } else if border.color.is_none()
+                && (matches!(parser.peek(), Some(TokenKind::Hash(_)))
+                    || matches!(parser.peek(), Some(TokenKind::Function(_)))
+                    || !(name.is_empty() || name == "thin" || name == "medium" || name == "thick"))
+            {
+                border.color = Some(parser.parse()?);
+            } else if border.width.is_none() {
+                border.width = Some(parser.parse()?);
+            } else {
+                return Err(parser.error("Unexpected value"));
+            }
+        }
+        if border.width.is_none() && border.style.is_none() && border.color.is_none() {
+            return Err(ParseError::new(offset, "Value is required."));
+        }
+        Ok(border)
+    }
+}
+
+impl Parse for Color {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let offset = parser.offset();
+        match parser.peek() {
+            Some(TokenKind::Hash(hex)) => {
+                parser.next();
+                return Color::from_hex(hex)
+                    .ok_or_else(|| ParseError::new(offset, format!("Unexpected color #{}", hex)));
+            }
+            Some(TokenKind::Ident(name)) => {
+                parser.next();
+                return Color::from_name(&name.to_ascii_lowercase())
+                    .ok_or_else(|| ParseError::new(offset, format!("Unexpected color {}", name)));
+            }
+            _ => {}
+        }
+        let (name, _) = parser.parse_function()?;
+        let mut args = vec![];
+        while !parser.peek_punct(')') {
+            args.push(parser.parse_number()?);
+            if !parser.parse_optional_punct(',') {
+                parser.parse_optional_punct('/');
+            }
+        }
+        parser.parse_punct(')')?;
+        let alpha = |number: Option<&Number>| match number {
+            None => Ok(1.),
+            Some(number) if number.unit == "%" => Ok(number.value / 100.),
+            Some(number) if number.unit.is_empty() => Ok(number.value),
+            Some(number) => Err(number.error()),
+        };
+        let error = || ParseError::new(offset, format!("Unexpected arguments for {}()", name));
+        Ok(match (name.as_str(), args.as_slice()) {
+            ("rgb", [r, g, b])
+            | ("rgba", [r, g, b])
+            | ("rgb", [r, g, b, _])
+            | ("rgba", [r, g, b, _]) => {
+                let channel = |number: &Number| match number.unit.as_str() {
+                    "" => Ok(number.value.round().clamp(0., 255.) as u8),
+                    "%" => Ok((number.value * 2.55).round().clamp(0., 255.) as u8),
+                    _ => Err(number.error()),
+                };
+                Color::rgba(channel(r)?, channel(g)?, channel(b)?, alpha(args.get(3))?)
+            }
+            ("hsl", [h, s, l])
+            | ("hsla", [h, s, l])
+            | ("hsl", [h, s, l, _])
+            | ("hsla", [h, s, l, _]) => {
+                let hue = match h.unit.to_ascii_lowercase().as_str() {
+                    "" | "deg" => h.value,
+                    "turn" => h.value * 360.,
+                    _ => return Err(h.error()),
+                };
+                let parcentage = |number: &Number| {
+                    number
+                        .parcentage()
+                        .map(|parcentage| parcentage.0 / 100.)
+                        .ok_or_else(|| number.error())
+                };
+                Color::hsla(hue, parcentage(s)?, parcentage(l)?, alpha(args.get(3))?)
+            }
+            _ => return Err(error()),
+        })
+    }
+}
+
+macro_rules! keyword {
+    ($($ty:ident)*) => {$(
+        impl Parse for $ty {
+            fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+                let (name, offset) = parser.parse_ident()?;
+                $ty::from_keyword(&name)
+                    .ok_or_else(|| ParseError::new(offset, format!("Unexpected value {}", name)))
+            }
+        }
+    )*};
+}
+
+keyword! {
+    BorderStyle BoxSizing DisplayType FlexDirection FlexWrap JustifyContent AlignItems
+    TextOrientation Direction ColorScheme Orientation MediaType ReducedMotion TextAlign
+    RubyPosition GenericFamily TextEmphasisFill TextEmphasisShape Overflow Position Visibility
+}
+
+impl Parse for WritingMode {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let (name, offset) = parser.parse_ident()?;
+        Ok(match name.to_ascii_lowercase().as_str() {
+            "horizontal-tb" => WritingMode::HorizontalTb,
+            "vertical-rl" => WritingMode::VerticalRl,
+            "vertical-lr" => WritingMode::VerticalLr,
+            "sideways-rl" => WritingMode::SidewayzRl,
+            "sideways-lr" => WritingMode::SidewayzLr,
+            _ => {
+                return Err(ParseError::new(
+                    offset,
+                    format!("Unexpected value {}", name),
+                ))
+            }
+        })
+    }
+}
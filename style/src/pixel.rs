@@ -0,0 +1,123 @@
+use crate::{Calc, Length, LengthOrPercentage};
+
+/// Evaluates lengths and `calc()` expressions to pixels, for renderers without a CSS
+/// engine and for tests.
+///
+/// `ch` and `ex` are approximated as half an `em`, as browsers do when font metrics are
+/// not available.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PixelResolver {
+    pub font_size: f64,
+    pub root_font_size: f64,
+    pub viewport_width: f64,
+    pub viewport_height: f64,
+}
+
+impl Default for PixelResolver {
+    fn default() -> Self {
+        Self::new(16., 0., 0.)
+    }
+}
+
+impl PixelResolver {
+    pub fn new(font_size: f64, viewport_width: f64, viewport_height: f64) -> Self {
+        Self {
+            font_size,
+            root_font_size: font_size,
+            viewport_width,
+            viewport_height,
+        }
+    }
+
+    pub fn length(&self, length: &Length) -> f64 {
+        match *length {
+            Length::Em(em) => em * self.font_size,
+            Length::Rem(rem) => rem * self.root_font_size,
+            Length::Ch(ch) => ch * self.font_size / 2.,
+            Length::Ex(ex) => ex * self.font_size / 2.,
+            Length::Px(px) => px,
+            Length::Pt(pt) => pt * 96. / 72.,
+            Length::Cm(cm) => cm * 96. / 2.54,
+            Length::Mm(mm) => mm * 96. / 25.4,
+            Length::In(inch) => inch * 96.,
+            Length::Vh(vh) => vh * self.viewport_height / 100.,
+            Length::Vw(vw) => vw * self.viewport_width / 100.,
+            Length::Vmin(vmin) => vmin * self.viewport_width.min(self.viewport_height) / 100.,
+            Length::Vmax(vmax) => vmax * self.viewport_width.max(self.viewport_height) / 100.,
+        }
+    }
+
+    /// `basis` is the length a percentage refers to, e.g. the width of the containing
+    /// block.
+    pub fn calc(&self, calc: &Calc, basis: f64) -> f64 {
+        match calc {
+            Calc::Length(length) => self.length(length),
+            Calc::Parcentage(parcentage) => parcentage.0 * basis / 100.,
+            Calc::Number(number) => *number,
+            Calc::Add(lhs, rhs) => self.calc(lhs, basis) + self.calc(rhs, basis),
+            Calc::Sub(lhs, rhs) => self.calc(lhs, basis) - self.calc(rhs, basis),
+            Calc::Mul(lhs, rhs) => self.calc(lhs, basis) * self.calc(rhs, basis),
+            Calc::Div(lhs, rhs) => self.calc(lhs, basis) / self.calc(rhs, basis),
+            Calc::Min(args) => args
+                .iter()
+                .map(|arg| self.calc(arg, basis))
+                .fold(f64::INFINITY, f64::min),
+            Calc::Max(args) => args
+                .iter()
+                .map(|arg| self.calc(arg, basis))
+                .fold(f64::NEG_INFINITY, f64::max),
+            Calc::Clamp(min, value, max) => {
+                let min = self.calc(min, basis);
+                self.calc(value, basis).min(self.calc(max, basis)).max(min)
+            }
+        }
+    }
+
+    pub fn length_or_percentage(&self, value: &LengthOrPercentage, basis: f64) -> f64 {
+        match value {
+            LengthOrPercentage::Length(length) => self.length(length),
+            LengthOrPercentage::Parcentage(parcentage) => parcentage.0 * basis / 100.,
+            LengthOrPercentage::Calc(calc) => self.calc(calc, basis),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PixelResolver;
+    use crate::{parse_declaration, Length, StyleRule};
+
+    fn width(css: &str) -> f64 {
+        let resolver = PixelResolver {
+            root_font_size: 10.,
+            ..PixelResolver::new(20., 1000., 500.)
+        };
+        match parse_declaration(css).unwrap() {
+            StyleRule::Left(value) => resolver.length_or_percentage(&value, 200.),
+            rule => panic!("{}", rule),
+        }
+    }
+
+    #[test]
+    fn units() {
+        let resolver = PixelResolver::new(20., 1000., 500.);
+        assert_eq!(resolver.length(&Length::Em(2.)), 40.);
+        assert_eq!(resolver.length(&Length::In(1.)), 96.);
+        assert_eq!(resolver.length(&Length::Pt(72.)), 96.);
+        assert_eq!(resolver.length(&Length::Vmin(10.)), 50.);
+        assert_eq!(resolver.length(&Length::Vmax(10.)), 100.);
+        assert_eq!(width("left: 1.5rem"), 15.);
+        assert_eq!(width("left: 2ch"), 20.);
+        assert_eq!(width("left: 1ex"), 10.);
+    }
+
+    #[test]
+    fn calc() {
+        assert_eq!(width("left: calc(100% - 2em)"), 160.);
+        assert_eq!(width("left: calc((10px + 1rem) * 2)"), 40.);
+        assert_eq!(width("left: min(50%, 10vw, 300px)"), 100.);
+        assert_eq!(width("left: max(1em, 5vh)"), 25.);
+        assert_eq!(width("left: clamp(1rem, 10%, 15px)"), 15.);
+        assert_eq!(width("left: calc(1in / 4 + min(1px, 2px))"), 25.);
+    }
+}
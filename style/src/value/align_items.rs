@@ -0,0 +1,12 @@
+keyword! {
+    pub enum AlignItems {
+        Normal => "normal",
+        Stretch => "stretch",
+        Start => "start",
+        End => "end",
+        FlexStart => "flex-start",
+        FlexEnd => "flex-end",
+        Center => "center",
+        Baseline => "baseline",
+    }
+}
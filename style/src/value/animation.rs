@@ -0,0 +1,96 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Time, TimingFunction};
+
+keyword! {
+    pub enum AnimationDirection {
+        Normal => "normal",
+        Reverse => "reverse",
+        Alternate => "alternate",
+        AlternateReverse => "alternate-reverse",
+    }
+}
+
+keyword! {
+    pub enum AnimationFillMode {
+        None => "none",
+        Forwards => "forwards",
+        Backwards => "backwards",
+        Both => "both",
+    }
+}
+
+keyword! {
+    pub enum AnimationPlayState {
+        Running => "running",
+        Paused => "paused",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum IterationCount {
+    Infinite,
+    Number(f64),
+}
+
+impl Display for IterationCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IterationCount::Infinite => f.write_str("infinite"),
+            IterationCount::Number(count) => Display::fmt(count, f),
+        }
+    }
+}
+
+/// One comma separated item of `animation`. `name` refers to `@keyframes` declared by a
+/// style set, see [`StyleSet::keyframes`](crate::StyleSet::keyframes).
+///
+/// A lone `none` is read as the fill mode, which renders the same as the name `none`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SingleAnimation {
+    pub duration: Option<Time>,
+    pub timing_function: Option<TimingFunction>,
+    pub delay: Option<Time>,
+    pub iteration_count: Option<IterationCount>,
+    pub direction: Option<AnimationDirection>,
+    pub fill_mode: Option<AnimationFillMode>,
+    pub play_state: Option<AnimationPlayState>,
+    pub name: Option<String>,
+}
+
+impl Display for SingleAnimation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let duration = self
+            .duration
+            .or_else(|| self.delay.map(|_| Time::Seconds(0.)));
+        let components = [
+            duration.map(|value| value.to_string()),
+            self.timing_function.map(|value| value.to_string()),
+            self.delay.map(|value| value.to_string()),
+            self.iteration_count.map(|value| value.to_string()),
+            self.direction.map(|value| value.to_string()),
+            self.fill_mode.map(|value| value.to_string()),
+            self.play_state.map(|value| value.to_string()),
+            self.name.as_ref().map(ToString::to_string),
+        ];
+        let components = components.iter().flatten().cloned().collect::<Vec<_>>();
+        f.write_str(&components.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Animation(pub Vec<SingleAnimation>);
+
+impl Display for Animation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, animation) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_char(',')?;
+            }
+            Display::fmt(animation, f)?;
+        }
+        Ok(())
+    }
+}
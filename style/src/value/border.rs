@@ -0,0 +1,35 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{BorderStyle, Color, LineWidth};
+
+/// The `border` shorthand. Omitted parts are left to their initial values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Border {
+    pub width: Option<LineWidth>,
+    pub style: Option<BorderStyle>,
+    pub color: Option<Color>,
+}
+
+impl Display for Border {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if let Some(width) = &self.width {
+            write!(f, "{}{}", separator, width)?;
+            separator = " ";
+        }
+        if let Some(style) = &self.style {
+            write!(f, "{}{}", separator, style)?;
+            separator = " ";
+        }
+        if let Some(color) = &self.color {
+            write!(f, "{}{}", separator, color)?;
+            separator = " ";
+        }
+        if separator.is_empty() {
+            f.write_str("none")?;
+        }
+        Ok(())
+    }
+}
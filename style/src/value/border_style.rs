@@ -0,0 +1,14 @@
+keyword! {
+    pub enum BorderStyle {
+        None => "none",
+        Hidden => "hidden",
+        Dotted => "dotted",
+        Dashed => "dashed",
+        Solid => "solid",
+        Double => "double",
+        Groove => "groove",
+        Ridge => "ridge",
+        Inset => "inset",
+        Outset => "outset",
+    }
+}
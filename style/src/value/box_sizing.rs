@@ -0,0 +1,6 @@
+keyword! {
+    pub enum BoxSizing {
+        ContentBox => "content-box",
+        BorderBox => "border-box",
+    }
+}
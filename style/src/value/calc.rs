@@ -0,0 +1,79 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Length, Parcentage};
+
+/// A `calc()`, `min()`, `max()` or `clamp()` expression over lengths and percentages.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Calc {
+    Length(Length),
+    Parcentage(Parcentage),
+    Number(f64),
+    Add(Box<Calc>, Box<Calc>),
+    Sub(Box<Calc>, Box<Calc>),
+    Mul(Box<Calc>, Box<Calc>),
+    Div(Box<Calc>, Box<Calc>),
+    Min(Vec<Calc>),
+    Max(Vec<Calc>),
+    Clamp(Box<Calc>, Box<Calc>, Box<Calc>),
+}
+
+impl Calc {
+    /// Writes the expression without the surrounding `calc()`, parenthesizing nested
+    /// operations.
+    fn fmt_expr(&self, f: &mut Formatter<'_>, nested: bool) -> fmt::Result {
+        let (lhs, operator, rhs) = match self {
+            Calc::Length(length) => return Display::fmt(length, f),
+            Calc::Parcentage(parcentage) => return Display::fmt(parcentage, f),
+            Calc::Number(number) => return Display::fmt(number, f),
+            Calc::Min(args) => return fmt_function(f, "min", args),
+            Calc::Max(args) => return fmt_function(f, "max", args),
+            Calc::Clamp(min, value, max) => {
+                return fmt_function(f, "clamp", [&**min, &**value, &**max].iter().copied())
+            }
+            Calc::Add(lhs, rhs) => (lhs, " + ", rhs),
+            Calc::Sub(lhs, rhs) => (lhs, " - ", rhs),
+            Calc::Mul(lhs, rhs) => (lhs, " * ", rhs),
+            Calc::Div(lhs, rhs) => (lhs, " / ", rhs),
+        };
+        if nested {
+            f.write_str("(")?;
+        }
+        lhs.fmt_expr(f, true)?;
+        f.write_str(operator)?;
+        rhs.fmt_expr(f, true)?;
+        if nested {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_function<'a>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    args: impl IntoIterator<Item = &'a Calc>,
+) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    for (i, arg) in args.into_iter().enumerate() {
+        if i != 0 {
+            f.write_str(",")?;
+        }
+        arg.fmt_expr(f, false)?;
+    }
+    f.write_str(")")
+}
+
+impl Display for Calc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Calc::Add(..) | Calc::Sub(..) | Calc::Mul(..) | Calc::Div(..) => {
+                f.write_str("calc(")?;
+                self.fmt_expr(f, false)?;
+                f.write_str(")")
+            }
+            _ => self.fmt_expr(f, false),
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Color {
+    Rgba(Rgba),
+    CurrentColor,
+}
+
+fn clamp_unit(value: f64) -> f64 {
+    value.clamp(0., 1.)
+}
+
+fn to_channel(value: f64) -> u8 {
+    (clamp_unit(value) * 255.).round() as u8
+}
+
+fn hue_to_rgb(p: f64, q: f64, t: f64) -> f64 {
+    let t = t.rem_euclid(1.);
+    if t < 1. / 6. {
+        p + (q - p) * 6. * t
+    } else if t < 1. / 2. {
+        q
+    } else if t < 2. / 3. {
+        p + (q - p) * (2. / 3. - t) * 6.
+    } else {
+        p
+    }
+}
+
+impl Rgba {
+    /// `h` is in degrees, `s`, `l` and `a` are in `0.0..=1.0`.
+    pub fn from_hsla(h: f64, s: f64, l: f64, a: f64) -> Self {
+        let h = (h / 360.).rem_euclid(1.);
+        let s = clamp_unit(s);
+        let l = clamp_unit(l);
+        let (r, g, b) = if s == 0. {
+            (l, l, l)
+        } else {
+            let q = if l < 0.5 { l * (1. + s) } else { l + s - l * s };
+            let p = 2. * l - q;
+            (
+                hue_to_rgb(p, q, h + 1. / 3.),
+                hue_to_rgb(p, q, h),
+                hue_to_rgb(p, q, h - 1. / 3.),
+            )
+        };
+        Self {
+            r: to_channel(r),
+            g: to_channel(g),
+            b: to_channel(b),
+            a: clamp_unit(a),
+        }
+    }
+
+    /// Returns `(h, s, l, a)` in the same ranges as [`Rgba::from_hsla`].
+    pub fn to_hsla(&self) -> (f64, f64, f64, f64) {
+        let r = self.r as f64 / 255.;
+        let g = self.g as f64 / 255.;
+        let b = self.b as f64 / 255.;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.;
+        if max == min {
+            return (0., 0., l, self.a);
+        }
+        let d = max - min;
+        let s = if l > 0.5 {
+            d / (2. - max - min)
+        } else {
+            d / (max + min)
+        };
+        let h = if max == r {
+            (g - b) / d + if g < b { 6. } else { 0. }
+        } else if max == g {
+            (b - r) / d + 2.
+        } else {
+            (r - g) / d + 4.
+        };
+        (h * 60., s, l, self.a)
+    }
+}
+
+impl Display for Rgba {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let alpha = self.a * 255.;
+        if self.a >= 1. {
+            write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else if (alpha - alpha.round()).abs() < 1e-6 {
+            // An alpha of `#rrggbbaa` is kept in the hex form, which is exact.
+            write!(
+                f,
+                "#{:02x}{:02x}{:02x}{:02x}",
+                self.r,
+                self.g,
+                self.b,
+                alpha.round() as u8
+            )
+        } else {
+            let a = (self.a * 1000.).round() / 1000.;
+            write!(f, "rgba({},{},{},{})", self.r, self.g, self.b, a)
+        }
+    }
+}
+
+impl Color {
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 1.)
+    }
+
+    pub fn rgba(r: u8, g: u8, b: u8, a: f64) -> Self {
+        Color::Rgba(Rgba {
+            r,
+            g,
+            b,
+            a: clamp_unit(a),
+        })
+    }
+
+    pub fn hsl(h: f64, s: f64, l: f64) -> Self {
+        Self::hsla(h, s, l, 1.)
+    }
+
+    pub fn hsla(h: f64, s: f64, l: f64, a: f64) -> Self {
+        Color::Rgba(Rgba::from_hsla(h, s, l, a))
+    }
+
+    /// Parses `rgb`, `rgba`, `rrggbb` or `rrggbbaa` hex digits without the leading `#`.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|d| d * 17);
+        let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let (r, g, b, a) = match hex.len() {
+            3 => (digit(0)?, digit(1)?, digit(2)?, 255),
+            4 => (digit(0)?, digit(1)?, digit(2)?, digit(3)?),
+            6 => (pair(0)?, pair(2)?, pair(4)?, 255),
+            8 => (pair(0)?, pair(2)?, pair(4)?, pair(6)?),
+            _ => return None,
+        };
+        Some(Self::rgba(r, g, b, a as f64 / 255.))
+    }
+
+    /// Parses a named color, `transparent` or `currentColor`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        match name.as_str() {
+            "currentcolor" => Some(Color::CurrentColor),
+            "transparent" => Some(Self::rgba(0, 0, 0, 0.)),
+            _ => {
+                let index = NAMED_COLORS
+                    .binary_search_by(|(named, _)| named.cmp(&name.as_str()))
+                    .ok()?;
+                let rgb = NAMED_COLORS[index].1;
+                Some(Self::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+            }
+        }
+    }
+
+    fn map_hsla<F: FnOnce(f64, f64, f64, f64) -> (f64, f64, f64, f64)>(&self, f: F) -> Self {
+        match self {
+            Color::Rgba(rgba) => {
+                let (h, s, l, a) = rgba.to_hsla();
+                let (h, s, l, a) = f(h, s, l, a);
+                Color::Rgba(Rgba::from_hsla(h, s, l, a))
+            }
+            Color::CurrentColor => Color::CurrentColor,
+        }
+    }
+
+    /// Increases the lightness by `amount` (`0.0..=1.0`). `currentColor` is returned as is.
+    pub fn lighten(&self, amount: f64) -> Self {
+        self.map_hsla(|h, s, l, a| (h, s, l + amount, a))
+    }
+
+    /// Decreases the lightness by `amount` (`0.0..=1.0`). `currentColor` is returned as is.
+    pub fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Mixes two colors, `weight` being the proportion of `self`.
+    /// If either color is `currentColor`, `self` is returned as is.
+    pub fn mix(&self, other: &Color, weight: f64) -> Self {
+        match (self, other) {
+            (Color::Rgba(this), Color::Rgba(other)) => {
+                let weight = clamp_unit(weight);
+                let channel =
+                    |a: u8, b: u8| (a as f64 * weight + b as f64 * (1. - weight)).round() as u8;
+                Color::Rgba(Rgba {
+                    r: channel(this.r, other.r),
+                    g: channel(this.g, other.g),
+                    b: channel(this.b, other.b),
+                    a: this.a * weight + other.a * (1. - weight),
+                })
+            }
+            _ => *self,
+        }
+    }
+
+    /// Replaces the alpha channel. `currentColor` is returned as is.
+    pub fn with_alpha(&self, alpha: f64) -> Self {
+        match self {
+            Color::Rgba(rgba) => Color::Rgba(Rgba {
+                a: clamp_unit(alpha),
+                ..*rgba
+            }),
+            Color::CurrentColor => Color::CurrentColor,
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Rgba(rgba) => Display::fmt(rgba, f),
+            Color::CurrentColor => f.write_str("currentColor"),
+        }
+    }
+}
+
+// Sorted by name for binary search.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod test {
+    use super::{Color, NAMED_COLORS};
+
+    #[test]
+    fn named_colors_sorted() {
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(Color::from_hex("f00"), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(
+            Color::from_hex("00ff0080"),
+            Some(Color::rgba(0, 255, 0, 128. / 255.))
+        );
+        assert_eq!(Color::from_hex("ff00"), Some(Color::rgba(255, 255, 0, 0.)));
+        assert_eq!(Color::from_hex("12345"), None);
+        assert_eq!(Color::from_hex("ggg"), None);
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(
+            Color::from_name("RebeccaPurple"),
+            Some(Color::rgb(0x66, 0x33, 0x99))
+        );
+        assert_eq!(Color::from_name("currentColor"), Some(Color::CurrentColor));
+        assert_eq!(Color::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn hsl() {
+        assert_eq!(Color::hsl(0., 1., 0.5), Color::rgb(255, 0, 0));
+        assert_eq!(Color::hsl(120., 1., 0.25), Color::rgb(0, 128, 0));
+        assert_eq!(Color::hsl(240., 1., 0.5), Color::rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn manipulation() {
+        let red = Color::rgb(255, 0, 0);
+        assert_eq!(red.lighten(0.5), Color::rgb(255, 255, 255));
+        assert_eq!(red.darken(0.25), Color::rgb(128, 0, 0));
+        assert_eq!(
+            red.mix(&Color::rgb(0, 0, 255), 0.5),
+            Color::rgb(128, 0, 128)
+        );
+        assert_eq!(red.with_alpha(0.5), Color::rgba(255, 0, 0, 0.5));
+        assert_eq!(Color::CurrentColor.lighten(0.5), Color::CurrentColor);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Color::rgb(255, 0, 16).to_string(), "#ff0010");
+        assert_eq!(
+            Color::rgba(255, 0, 16, 0.5).to_string(),
+            "rgba(255,0,16,0.5)"
+        );
+        assert_eq!(
+            Color::from_hex("11223388").unwrap().to_string(),
+            "#11223388"
+        );
+        assert_eq!(
+            Color::rgb(17, 34, 51).with_alpha(0.53333).to_string(),
+            "rgba(17,34,51,0.533)"
+        );
+        assert_eq!(Color::CurrentColor.to_string(), "currentColor");
+    }
+}
@@ -0,0 +1,6 @@
+keyword! {
+    pub enum ColorScheme {
+        Light => "light",
+        Dark => "dark",
+    }
+}
@@ -0,0 +1,6 @@
+keyword! {
+    pub enum Direction {
+        Ltr => "ltr",
+        Rtl => "rtl",
+    }
+}
@@ -0,0 +1,14 @@
+keyword! {
+    pub enum DisplayType {
+        None => "none",
+        Block => "block",
+        Inline => "inline",
+        InlineBlock => "inline-block",
+        ListItem => "list-item",
+        Contents => "contents",
+        Flex => "flex",
+        InlineFlex => "inline-flex",
+        Grid => "grid",
+        InlineGrid => "inline-grid",
+    }
+}
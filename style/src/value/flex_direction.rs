@@ -0,0 +1,8 @@
+keyword! {
+    pub enum FlexDirection {
+        Row => "row",
+        RowReverse => "row-reverse",
+        Column => "column",
+        ColumnReverse => "column-reverse",
+    }
+}
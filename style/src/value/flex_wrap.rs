@@ -0,0 +1,7 @@
+keyword! {
+    pub enum FlexWrap {
+        Nowrap => "nowrap",
+        Wrap => "wrap",
+        WrapReverse => "wrap-reverse",
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use serde_derive::{Deserialize, Serialize};
+
+use super::quoted::write_quoted;
+
+keyword! {
+    pub enum GenericFamily {
+        Serif => "serif",
+        SansSerif => "sans-serif",
+        Monospace => "monospace",
+        Cursive => "cursive",
+        Fantasy => "fantasy",
+        SystemUi => "system-ui",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FamilyName {
+    Generic(GenericFamily),
+    Named(String),
+}
+
+impl Display for FamilyName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FamilyName::Generic(generic) => Display::fmt(generic, f),
+            FamilyName::Named(name) => write_quoted(f, name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FontFamily(pub Vec<FamilyName>);
+
+impl Display for FontFamily {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, name) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_char(',')?;
+            }
+            Display::fmt(name, f)?;
+        }
+        Ok(())
+    }
+}
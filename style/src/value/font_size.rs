@@ -0,0 +1,55 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Length, Parcentage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FontSize {
+    XxSmall,
+    XSmall,
+    Small,
+    Medium,
+    Large,
+    XLarge,
+    XxLarge,
+    Smaller,
+    Larger,
+    Length(Length),
+    Parcentage(Parcentage),
+}
+
+impl FontSize {
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword.to_ascii_lowercase().as_str() {
+            "xx-small" => FontSize::XxSmall,
+            "x-small" => FontSize::XSmall,
+            "small" => FontSize::Small,
+            "medium" => FontSize::Medium,
+            "large" => FontSize::Large,
+            "x-large" => FontSize::XLarge,
+            "xx-large" => FontSize::XxLarge,
+            "smaller" => FontSize::Smaller,
+            "larger" => FontSize::Larger,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for FontSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FontSize::XxSmall => f.write_str("xx-small"),
+            FontSize::XSmall => f.write_str("x-small"),
+            FontSize::Small => f.write_str("small"),
+            FontSize::Medium => f.write_str("medium"),
+            FontSize::Large => f.write_str("large"),
+            FontSize::XLarge => f.write_str("x-large"),
+            FontSize::XxLarge => f.write_str("xx-large"),
+            FontSize::Smaller => f.write_str("smaller"),
+            FontSize::Larger => f.write_str("larger"),
+            FontSize::Length(length) => Display::fmt(length, f),
+            FontSize::Parcentage(parcentage) => Display::fmt(parcentage, f),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FontWeight {
+    Normal,
+    Bold,
+    Bolder,
+    Lighter,
+    /// 1 to 1000.
+    Number(u16),
+}
+
+impl Display for FontWeight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FontWeight::Normal => f.write_str("normal"),
+            FontWeight::Bold => f.write_str("bold"),
+            FontWeight::Bolder => f.write_str("bolder"),
+            FontWeight::Lighter => f.write_str("lighter"),
+            FontWeight::Number(weight) => write!(f, "{}", weight),
+        }
+    }
+}
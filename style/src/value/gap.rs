@@ -0,0 +1,21 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::LengthOrPercentage;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Gap {
+    pub row: LengthOrPercentage,
+    pub column: LengthOrPercentage,
+}
+
+impl Display for Gap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.row == self.column {
+            Display::fmt(&self.row, f)
+        } else {
+            write!(f, "{} {}", self.row, self.column)
+        }
+    }
+}
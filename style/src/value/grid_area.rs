@@ -0,0 +1,38 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GridLine {
+    Auto,
+    Index(i32),
+    Span(u32),
+    Name(String),
+}
+
+impl Display for GridLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridLine::Auto => f.write_str("auto"),
+            GridLine::Index(index) => write!(f, "{}", index),
+            GridLine::Span(span) => write!(f, "span {}", span),
+            GridLine::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// `row-start / column-start / row-end / column-end`, trailing lines may be omitted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GridArea(pub Vec<GridLine>);
+
+impl Display for GridArea {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, line) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_str(" / ")?;
+            }
+            Display::fmt(line, f)?;
+        }
+        Ok(())
+    }
+}
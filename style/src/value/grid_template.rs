@@ -0,0 +1,104 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Length, Parcentage};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TrackBreadth {
+    Length(Length),
+    Parcentage(Parcentage),
+    Fr(f64),
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+impl Display for TrackBreadth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackBreadth::Length(length) => Display::fmt(length, f),
+            TrackBreadth::Parcentage(parcentage) => Display::fmt(parcentage, f),
+            TrackBreadth::Fr(fr) => write!(f, "{}fr", fr),
+            TrackBreadth::Auto => f.write_str("auto"),
+            TrackBreadth::MinContent => f.write_str("min-content"),
+            TrackBreadth::MaxContent => f.write_str("max-content"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TrackSize {
+    Breadth(TrackBreadth),
+    MinMax(TrackBreadth, TrackBreadth),
+}
+
+impl Display for TrackSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackSize::Breadth(breadth) => Display::fmt(breadth, f),
+            TrackSize::MinMax(min, max) => write!(f, "minmax({},{})", min, max),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrackRepeat {
+    Count(u32),
+    AutoFill,
+    AutoFit,
+}
+
+impl Display for TrackRepeat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackRepeat::Count(count) => write!(f, "{}", count),
+            TrackRepeat::AutoFill => f.write_str("auto-fill"),
+            TrackRepeat::AutoFit => f.write_str("auto-fit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TrackListItem {
+    Size(TrackSize),
+    Repeat(TrackRepeat, Vec<TrackSize>),
+}
+
+impl Display for TrackListItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackListItem::Size(size) => Display::fmt(size, f),
+            TrackListItem::Repeat(repeat, sizes) => {
+                write!(f, "repeat({},", repeat)?;
+                write_separated(f, sizes)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GridTemplate {
+    None,
+    Tracks(Vec<TrackListItem>),
+}
+
+impl Display for GridTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridTemplate::None => f.write_str("none"),
+            GridTemplate::Tracks(tracks) => write_separated(f, tracks),
+        }
+    }
+}
+
+fn write_separated<T: Display>(f: &mut Formatter<'_>, items: &[T]) -> fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index != 0 {
+            f.write_str(" ")?;
+        }
+        Display::fmt(item, f)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+keyword! {
+    pub enum JustifyContent {
+        Normal => "normal",
+        Start => "start",
+        End => "end",
+        FlexStart => "flex-start",
+        FlexEnd => "flex-end",
+        Center => "center",
+        Left => "left",
+        Right => "right",
+        SpaceBetween => "space-between",
+        SpaceAround => "space-around",
+        SpaceEvenly => "space-evenly",
+        Stretch => "stretch",
+    }
+}
@@ -0,0 +1,46 @@
+macro_rules! keyword {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident => $keyword:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            ::serde_derive::Serialize,
+            ::serde_derive::Deserialize,
+        )]
+        $vis enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            pub fn from_keyword(keyword: &str) -> Option<Self> {
+                match keyword.to_ascii_lowercase().as_str() {
+                    $($keyword => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+
+            pub fn keyword(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $keyword,)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.keyword())
+            }
+        }
+    };
+}
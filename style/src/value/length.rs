@@ -5,18 +5,59 @@ use serde_derive::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Length {
     Em(f64),
+    Rem(f64),
+    Ch(f64),
+    Ex(f64),
     Px(f64),
+    Pt(f64),
+    Cm(f64),
+    Mm(f64),
+    In(f64),
     Vh(f64),
     Vw(f64),
+    Vmin(f64),
+    Vmax(f64),
+}
+
+impl Length {
+    /// `value` with the unit `unit`, e.g. `(1.5, "rem")`. A bare `0` is `0px`.
+    pub fn from_unit(value: f64, unit: &str) -> Option<Self> {
+        Some(match unit.to_ascii_lowercase().as_str() {
+            "em" => Length::Em(value),
+            "rem" => Length::Rem(value),
+            "ch" => Length::Ch(value),
+            "ex" => Length::Ex(value),
+            "px" => Length::Px(value),
+            "pt" => Length::Pt(value),
+            "cm" => Length::Cm(value),
+            "mm" => Length::Mm(value),
+            "in" => Length::In(value),
+            "vh" => Length::Vh(value),
+            "vw" => Length::Vw(value),
+            "vmin" => Length::Vmin(value),
+            "vmax" => Length::Vmax(value),
+            "" if value == 0. => Length::Px(0.),
+            _ => return None,
+        })
+    }
 }
 
 impl Display for Length {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Length::Em(em) => write!(f, "{}em", em),
+            Length::Rem(rem) => write!(f, "{}rem", rem),
+            Length::Ch(ch) => write!(f, "{}ch", ch),
+            Length::Ex(ex) => write!(f, "{}ex", ex),
             Length::Px(px) => write!(f, "{}px", px),
+            Length::Pt(pt) => write!(f, "{}pt", pt),
+            Length::Cm(cm) => write!(f, "{}cm", cm),
+            Length::Mm(mm) => write!(f, "{}mm", mm),
+            Length::In(inch) => write!(f, "{}in", inch),
             Length::Vh(vh) => write!(f, "{}vh", vh),
             Length::Vw(vw) => write!(f, "{}vw", vw),
+            Length::Vmin(vmin) => write!(f, "{}vmin", vmin),
+            Length::Vmax(vmax) => write!(f, "{}vmax", vmax),
         }
     }
 }
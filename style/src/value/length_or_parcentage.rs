@@ -3,12 +3,13 @@ use std::fmt::{self, Display};
 use fmt::Formatter;
 use serde_derive::{Deserialize, Serialize};
 
-use crate::{Length, Parcentage};
+use crate::{Calc, Length, Parcentage};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LengthOrPercentage {
     Length(Length),
     Parcentage(Parcentage),
+    Calc(Calc),
 }
 
 impl Display for LengthOrPercentage {
@@ -16,6 +17,7 @@ impl Display for LengthOrPercentage {
         match self {
             LengthOrPercentage::Length(length) => Display::fmt(length, f),
             LengthOrPercentage::Parcentage(parcentage) => Display::fmt(parcentage, f),
+            LengthOrPercentage::Calc(calc) => Display::fmt(calc, f),
         }
     }
 }
@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Calc, Length, Parcentage};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LengthOrPercentageOrAuto {
+    Length(Length),
+    Parcentage(Parcentage),
+    Calc(Calc),
+    Auto,
+}
+
+impl Display for LengthOrPercentageOrAuto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthOrPercentageOrAuto::Length(length) => Display::fmt(length, f),
+            LengthOrPercentageOrAuto::Parcentage(parcentage) => Display::fmt(parcentage, f),
+            LengthOrPercentageOrAuto::Calc(calc) => Display::fmt(calc, f),
+            LengthOrPercentageOrAuto::Auto => f.write_str("auto"),
+        }
+    }
+}
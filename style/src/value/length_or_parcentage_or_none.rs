@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Calc, Length, Parcentage};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LengthOrPercentageOrNone {
+    Length(Length),
+    Parcentage(Parcentage),
+    Calc(Calc),
+    None,
+}
+
+impl Display for LengthOrPercentageOrNone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthOrPercentageOrNone::Length(length) => Display::fmt(length, f),
+            LengthOrPercentageOrNone::Parcentage(parcentage) => Display::fmt(parcentage, f),
+            LengthOrPercentageOrNone::Calc(calc) => Display::fmt(calc, f),
+            LengthOrPercentageOrNone::None => f.write_str("none"),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Length;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LetterSpacing {
+    Normal,
+    Length(Length),
+}
+
+impl Display for LetterSpacing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LetterSpacing::Normal => f.write_str("normal"),
+            LetterSpacing::Length(length) => Display::fmt(length, f),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Length, Parcentage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LineHeight {
+    Normal,
+    Number(f64),
+    Length(Length),
+    Parcentage(Parcentage),
+}
+
+impl Display for LineHeight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LineHeight::Normal => f.write_str("normal"),
+            LineHeight::Number(number) => write!(f, "{}", number),
+            LineHeight::Length(length) => Display::fmt(length, f),
+            LineHeight::Parcentage(parcentage) => Display::fmt(parcentage, f),
+        }
+    }
+}
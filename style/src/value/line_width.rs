@@ -0,0 +1,24 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Length;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LineWidth {
+    Thin,
+    Medium,
+    Thick,
+    Length(Length),
+}
+
+impl Display for LineWidth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LineWidth::Thin => f.write_str("thin"),
+            LineWidth::Medium => f.write_str("medium"),
+            LineWidth::Thick => f.write_str("thick"),
+            LineWidth::Length(length) => Display::fmt(length, f),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+/// Values for the start and end edges of one logical axis, as in `margin-inline`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogicalPair<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Clone> LogicalPair<T> {
+    pub fn both(value: T) -> Self {
+        Self {
+            start: value.clone(),
+            end: value,
+        }
+    }
+
+    /// Expands 1 or 2 values like the `margin-inline` shorthand does.
+    pub fn from_values(values: &[T]) -> Option<Self> {
+        match values {
+            [both] => Some(Self::both(both.clone())),
+            [start, end] => Some(Self {
+                start: start.clone(),
+                end: end.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Display + PartialEq> Display for LogicalPair<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.start != self.end {
+            write!(f, "{} {}", self.start, self.end)
+        } else {
+            Display::fmt(&self.start, f)
+        }
+    }
+}
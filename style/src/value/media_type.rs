@@ -0,0 +1,7 @@
+keyword! {
+    pub enum MediaType {
+        All => "all",
+        Screen => "screen",
+        Print => "print",
+    }
+}
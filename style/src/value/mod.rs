@@ -1,9 +1,98 @@
+#[macro_use]
+mod keyword;
+
+mod align_items;
+mod animation;
+mod border;
+mod border_style;
+mod box_sizing;
+mod calc;
+mod color;
+mod color_scheme;
+mod direction;
+mod display_type;
+mod flex_direction;
+mod flex_wrap;
+mod font_family;
+mod font_size;
+mod font_weight;
+mod gap;
+mod grid_area;
+mod grid_template;
+mod justify_content;
 mod length;
 mod length_or_parcentage;
+mod length_or_parcentage_or_auto;
+mod length_or_parcentage_or_none;
+mod letter_spacing;
+mod line_height;
+mod line_width;
+mod logical_pair;
+mod media_type;
+mod orientation;
+mod overflow;
 mod parcentage;
+mod position;
+mod quoted;
+mod reduced_motion;
+mod ruby_position;
+mod sides;
+mod text_align;
+mod text_combine_upright;
+mod text_emphasis;
+mod text_orientation;
+mod time;
+mod timing_function;
+mod transition;
+mod visibility;
 mod writing_mode;
+mod z_index;
 
+pub use align_items::AlignItems;
+pub use animation::{
+    Animation, AnimationDirection, AnimationFillMode, AnimationPlayState, IterationCount,
+    SingleAnimation,
+};
+pub use border::Border;
+pub use border_style::BorderStyle;
+pub use box_sizing::BoxSizing;
+pub use calc::Calc;
+pub use color::{Color, Rgba};
+pub use color_scheme::ColorScheme;
+pub use direction::Direction;
+pub use display_type::DisplayType;
+pub use flex_direction::FlexDirection;
+pub use flex_wrap::FlexWrap;
+pub use font_family::{FamilyName, FontFamily, GenericFamily};
+pub use font_size::FontSize;
+pub use font_weight::FontWeight;
+pub use gap::Gap;
+pub use grid_area::{GridArea, GridLine};
+pub use grid_template::{GridTemplate, TrackBreadth, TrackListItem, TrackRepeat, TrackSize};
+pub use justify_content::JustifyContent;
 pub use length::Length;
 pub use length_or_parcentage::LengthOrPercentage;
+pub use length_or_parcentage_or_auto::LengthOrPercentageOrAuto;
+pub use length_or_parcentage_or_none::LengthOrPercentageOrNone;
+pub use letter_spacing::LetterSpacing;
+pub use line_height::LineHeight;
+pub use line_width::LineWidth;
+pub use logical_pair::LogicalPair;
+pub use media_type::MediaType;
+pub use orientation::Orientation;
+pub use overflow::Overflow;
 pub use parcentage::Parcentage;
+pub use position::Position;
+pub use reduced_motion::ReducedMotion;
+pub use ruby_position::RubyPosition;
+pub use sides::Sides;
+pub use text_align::TextAlign;
+pub use text_combine_upright::TextCombineUpright;
+pub use text_emphasis::{TextEmphasis, TextEmphasisFill, TextEmphasisShape, TextEmphasisStyle};
+pub use text_orientation::TextOrientation;
+pub use time::Time;
+pub use timing_function::{StepPosition, TimingFunction};
+pub use transition::{SingleTransition, Transition, TransitionProperty};
+pub use visibility::Visibility;
 pub use writing_mode::WritingMode;
+pub use z_index::ZIndex;
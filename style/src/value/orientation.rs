@@ -0,0 +1,6 @@
+keyword! {
+    pub enum Orientation {
+        Portrait => "portrait",
+        Landscape => "landscape",
+    }
+}
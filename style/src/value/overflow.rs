@@ -0,0 +1,9 @@
+keyword! {
+    pub enum Overflow {
+        Visible => "visible",
+        Hidden => "hidden",
+        Clip => "clip",
+        Scroll => "scroll",
+        Auto => "auto",
+    }
+}
@@ -0,0 +1,9 @@
+keyword! {
+    pub enum Position {
+        Static => "static",
+        Relative => "relative",
+        Absolute => "absolute",
+        Fixed => "fixed",
+        Sticky => "sticky",
+    }
+}
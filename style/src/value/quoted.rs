@@ -0,0 +1,12 @@
+use std::fmt::{self, Formatter, Write};
+
+pub(crate) fn write_quoted(f: &mut Formatter<'_>, string: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in string.chars() {
+        if c == '"' || c == '\\' {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
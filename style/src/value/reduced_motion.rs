@@ -0,0 +1,6 @@
+keyword! {
+    pub enum ReducedMotion {
+        NoPreference => "no-preference",
+        Reduce => "reduce",
+    }
+}
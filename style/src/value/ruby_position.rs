@@ -0,0 +1,8 @@
+keyword! {
+    pub enum RubyPosition {
+        Over => "over",
+        Under => "under",
+        InterCharacter => "inter-character",
+        Alternate => "alternate",
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+/// Values for the four sides of a box, in the order used by CSS shorthands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sides<T> {
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+    pub left: T,
+}
+
+impl<T: Clone> Sides<T> {
+    pub fn all(value: T) -> Self {
+        Self {
+            top: value.clone(),
+            right: value.clone(),
+            bottom: value.clone(),
+            left: value,
+        }
+    }
+
+    /// Expands 1 to 4 values like the `margin` shorthand does.
+    pub fn from_values(values: &[T]) -> Option<Self> {
+        match values {
+            [all] => Some(Self::all(all.clone())),
+            [vertical, horizontal] => Some(Self {
+                top: vertical.clone(),
+                right: horizontal.clone(),
+                bottom: vertical.clone(),
+                left: horizontal.clone(),
+            }),
+            [top, horizontal, bottom] => Some(Self {
+                top: top.clone(),
+                right: horizontal.clone(),
+                bottom: bottom.clone(),
+                left: horizontal.clone(),
+            }),
+            [top, right, bottom, left] => Some(Self {
+                top: top.clone(),
+                right: right.clone(),
+                bottom: bottom.clone(),
+                left: left.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Display + PartialEq> Display for Sides<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.left != self.right {
+            write!(
+                f,
+                "{} {} {} {}",
+                self.top, self.right, self.bottom, self.left
+            )
+        } else if self.top != self.bottom {
+            write!(f, "{} {} {}", self.top, self.right, self.bottom)
+        } else if self.top != self.right {
+            write!(f, "{} {}", self.top, self.right)
+        } else {
+            Display::fmt(&self.top, f)
+        }
+    }
+}
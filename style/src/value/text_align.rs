@@ -0,0 +1,11 @@
+keyword! {
+    pub enum TextAlign {
+        Start => "start",
+        End => "end",
+        Left => "left",
+        Right => "right",
+        Center => "center",
+        Justify => "justify",
+        MatchParent => "match-parent",
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TextCombineUpright {
+    None,
+    All,
+    /// `digits 2` to `digits 4`, used for 縦中横 numbers.
+    Digits(u8),
+}
+
+impl Display for TextCombineUpright {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TextCombineUpright::None => f.write_str("none"),
+            TextCombineUpright::All => f.write_str("all"),
+            TextCombineUpright::Digits(digits) => write!(f, "digits {}", digits),
+        }
+    }
+}
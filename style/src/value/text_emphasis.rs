@@ -0,0 +1,64 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use super::quoted::write_quoted;
+use crate::Color;
+
+keyword! {
+    pub enum TextEmphasisFill {
+        Filled => "filled",
+        Open => "open",
+    }
+}
+
+keyword! {
+    pub enum TextEmphasisShape {
+        Dot => "dot",
+        Circle => "circle",
+        DoubleCircle => "double-circle",
+        Triangle => "triangle",
+        Sesame => "sesame",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TextEmphasisStyle {
+    None,
+    Shape {
+        fill: Option<TextEmphasisFill>,
+        shape: Option<TextEmphasisShape>,
+    },
+    String(String),
+}
+
+impl Display for TextEmphasisStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TextEmphasisStyle::None => f.write_str("none"),
+            TextEmphasisStyle::Shape { fill, shape } => match (fill, shape) {
+                (Some(fill), Some(shape)) => write!(f, "{} {}", fill, shape),
+                (Some(fill), None) => Display::fmt(fill, f),
+                (None, Some(shape)) => Display::fmt(shape, f),
+                (None, None) => f.write_str("none"),
+            },
+            TextEmphasisStyle::String(string) => write_quoted(f, string),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextEmphasis {
+    pub style: TextEmphasisStyle,
+    pub color: Option<Color>,
+}
+
+impl Display for TextEmphasis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.style, f)?;
+        if let Some(color) = &self.color {
+            write!(f, " {}", color)?;
+        }
+        Ok(())
+    }
+}
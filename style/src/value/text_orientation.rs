@@ -0,0 +1,7 @@
+keyword! {
+    pub enum TextOrientation {
+        Mixed => "mixed",
+        Upright => "upright",
+        Sideways => "sideways",
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Time {
+    Seconds(f64),
+    Milliseconds(f64),
+}
+
+impl Time {
+    /// `value` with a CSS time unit, e.g. `from_unit(0.3, "s")`.
+    pub fn from_unit(value: f64, unit: &str) -> Option<Self> {
+        match unit.to_ascii_lowercase().as_str() {
+            "s" => Some(Time::Seconds(value)),
+            "ms" => Some(Time::Milliseconds(value)),
+            _ => None,
+        }
+    }
+
+    pub fn as_secs_f64(&self) -> f64 {
+        match *self {
+            Time::Seconds(seconds) => seconds,
+            Time::Milliseconds(milliseconds) => milliseconds / 1000.,
+        }
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Time::Seconds(seconds) => write!(f, "{}s", seconds),
+            Time::Milliseconds(milliseconds) => write!(f, "{}ms", milliseconds),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+keyword! {
+    pub enum StepPosition {
+        JumpStart => "jump-start",
+        JumpEnd => "jump-end",
+        JumpNone => "jump-none",
+        JumpBoth => "jump-both",
+        Start => "start",
+        End => "end",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TimingFunction {
+    Ease,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    StepStart,
+    StepEnd,
+    CubicBezier(f64, f64, f64, f64),
+    Steps(u32, Option<StepPosition>),
+}
+
+impl TimingFunction {
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword.to_ascii_lowercase().as_str() {
+            "ease" => TimingFunction::Ease,
+            "linear" => TimingFunction::Linear,
+            "ease-in" => TimingFunction::EaseIn,
+            "ease-out" => TimingFunction::EaseOut,
+            "ease-in-out" => TimingFunction::EaseInOut,
+            "step-start" => TimingFunction::StepStart,
+            "step-end" => TimingFunction::StepEnd,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for TimingFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TimingFunction::Ease => f.write_str("ease"),
+            TimingFunction::Linear => f.write_str("linear"),
+            TimingFunction::EaseIn => f.write_str("ease-in"),
+            TimingFunction::EaseOut => f.write_str("ease-out"),
+            TimingFunction::EaseInOut => f.write_str("ease-in-out"),
+            TimingFunction::StepStart => f.write_str("step-start"),
+            TimingFunction::StepEnd => f.write_str("step-end"),
+            TimingFunction::CubicBezier(x1, y1, x2, y2) => {
+                write!(f, "cubic-bezier({},{},{},{})", x1, y1, x2, y2)
+            }
+            TimingFunction::Steps(steps, None) => write!(f, "steps({})", steps),
+            TimingFunction::Steps(steps, Some(position)) => {
+                write!(f, "steps({},{})", steps, position)
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Time, TimingFunction};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransitionProperty {
+    None,
+    All,
+    Property(String),
+}
+
+impl Display for TransitionProperty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitionProperty::None => f.write_str("none"),
+            TransitionProperty::All => f.write_str("all"),
+            TransitionProperty::Property(name) => f.write_str(name),
+        }
+    }
+}
+
+/// One comma separated item of `transition`. Omitted components are `None` and left to
+/// their initial values.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SingleTransition {
+    pub property: Option<TransitionProperty>,
+    pub duration: Option<Time>,
+    pub timing_function: Option<TimingFunction>,
+    pub delay: Option<Time>,
+}
+
+impl Display for SingleTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // The first time is the duration, so a delay needs one in front of it.
+        let duration = self
+            .duration
+            .or_else(|| self.delay.map(|_| Time::Seconds(0.)));
+        let components = [
+            self.property.as_ref().map(ToString::to_string),
+            duration.map(|value| value.to_string()),
+            self.timing_function.map(|value| value.to_string()),
+            self.delay.map(|value| value.to_string()),
+        ];
+        let components = components.iter().flatten().cloned().collect::<Vec<_>>();
+        f.write_str(&components.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transition(pub Vec<SingleTransition>);
+
+impl Display for Transition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, transition) in self.0.iter().enumerate() {
+            if index != 0 {
+                f.write_char(',')?;
+            }
+            Display::fmt(transition, f)?;
+        }
+        Ok(())
+    }
+}
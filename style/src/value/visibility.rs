@@ -0,0 +1,7 @@
+keyword! {
+    pub enum Visibility {
+        Visible => "visible",
+        Hidden => "hidden",
+        Collapse => "collapse",
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ZIndex {
+    Auto,
+    Integer(i32),
+}
+
+impl Display for ZIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ZIndex::Auto => f.write_str("auto"),
+            ZIndex::Integer(value) => write!(f, "{}", value),
+        }
+    }
+}
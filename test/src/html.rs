@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    use sulafat_macros::{html, StyleSet};
+    use sulafat_vdom::{id, style, Common, Div, Element, List, Node, Rp, Rt, Ruby, Single, Span};
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .html_item {
+            left: 10px;
+        }
+    }]
+    struct Item;
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Select(usize),
+    }
+
+    fn div(
+        key: Option<&str>,
+        attrs: Vec<sulafat_vdom::Attribute<Msg>>,
+        children: Vec<Node<Msg>>,
+    ) -> Node<Msg> {
+        Div::new(Common::new(
+            key.map(str::to_string),
+            attrs.into(),
+            children.into(),
+        ))
+        .into()
+    }
+
+    #[test]
+    fn element() {
+        let node: Node<Msg> = html! {
+            <div id="root" style={Item}>
+                "text"
+                <span/>
+            </div>
+        };
+        assert_eq!(
+            node,
+            div(
+                None,
+                vec![id("root".into()), style(Item)],
+                vec![
+                    "text".into(),
+                    Span::new(Common::new(None, vec![].into(), vec![].into())).into()
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn ruby() {
+        let node: Node<Msg> = html! {
+            <ruby>"漢"<rp>"("</rp><rt>"かん"</rt><rp>")"</rp></ruby>
+        };
+        let text = |s: &str| List::from(vec![Node::from(s)]);
+        assert_eq!(
+            node,
+            Ruby::new(Common::new(
+                None,
+                Default::default(),
+                vec![
+                    "漢".into(),
+                    Rp::new(Common::new(None, Default::default(), text("("))).into(),
+                    Rt::new(Common::new(None, Default::default(), text("かん"))).into(),
+                    Rp::new(Common::new(None, Default::default(), text(")"))).into(),
+                ]
+                .into()
+            ))
+            .into()
+        );
+    }
+
+    fn items(items: &[&str], selected: Option<usize>) -> Node<Msg> {
+        html! {
+            if let Some(index) = selected {
+                <div>{format!("{} is selected", items[index])}</div>
+            } else if items.is_empty() {
+                "empty"
+            }
+            for (index, item) in items.iter().enumerate() {
+                <div key={index} on_click={move |_| Msg::Select(index)}>{*item}</div>
+            }
+        }
+    }
+
+    #[test]
+    fn control_flow() {
+        let node = items(&["a", "b"], Some(1));
+        let list = match &node {
+            Node::List(list) => list,
+            node => panic!("{:?}", node),
+        };
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0], div(None, vec![], vec!["b is selected".into()]));
+        let children = match &list[1] {
+            Node::List(children) => children,
+            node => panic!("{:?}", node),
+        };
+        assert_eq!(children.len(), 2);
+        match &children[1] {
+            Node::Single(Single::Element(element @ Element::Div(_))) => {
+                assert_eq!(element.key(), &Some("1".to_string()));
+                assert_eq!(element.children()[0], Node::from("b"));
+            }
+            node => panic!("{:?}", node),
+        }
+        assert_eq!(
+            items(&[], None),
+            vec!["empty".into(), List::from(vec![]).into()].into()
+        );
+        assert_eq!(
+            items(&["a"], Some(0)),
+            items(&["a"], Some(0)),
+            "handlers created at the same place are equal"
+        );
+    }
+}
@@ -1 +1,2 @@
+mod html;
 mod style;
@@ -1,9 +1,19 @@
 #[cfg(test)]
 mod test {
-    use sulafat_macros::StyleSet;
+    use sulafat_macros::{include_tokens, StyleSet, Theme};
     use sulafat_style::{
-        CSSRenderer, Length, LengthOrPercentage, Parcentage, StyleRenderer, StyleRule, StyleSet,
-        WritingMode,
+        parse_declarations, AlignItems, Animation, AnimationFillMode, Border, BorderStyle,
+        BoxSizing, CSSRenderer, Calc, Color, ColorScheme, Condition, Direction, DisplayType,
+        FamilyName, FlexDirection, FlexWrap, FontFamily, FontSize, FontWeight, Gap, GenericFamily,
+        GridArea, GridLine, GridTemplate, IterationCount, JustifyContent, Keyframe, Keyframes,
+        Length, LengthOrPercentage, LengthOrPercentageOrAuto, LengthOrPercentageOrNone,
+        LetterSpacing, LineHeight, LineWidth, LogicalPair, LogicalResolver, MediaFeature,
+        MediaQuery, MediaType, Overflow, Parcentage, Position, Pseudo, RubyPosition, Sides,
+        SingleAnimation, SingleTransition, StepPosition, StyleBlock, StyleRenderer, StyleRule,
+        StyleSet, StyleSetVisitor, SupportsCondition, TextAlign, TextCombineUpright, TextEmphasis,
+        TextEmphasisFill, TextEmphasisShape, TextEmphasisStyle, TextOrientation, Theme, Time,
+        TimingFunction, TrackBreadth, TrackListItem, TrackRepeat, TrackSize, Transition,
+        TransitionProperty, Visibility, WritingMode, ZIndex,
     };
 
     #[derive(StyleSet)]
@@ -28,6 +38,16 @@ mod test {
         )
     }
 
+    #[test]
+    fn shared_rules() {
+        let rules = Style::rules().as_ptr() as usize;
+        let other = std::thread::spawn(|| Style::rules().as_ptr() as usize)
+            .join()
+            .unwrap();
+        assert_eq!(rules, other);
+        assert_eq!(Style::rules().as_ptr() as usize, rules);
+    }
+
     #[test]
     fn css_renderer() {
         let mut renderer = CSSRenderer::default();
@@ -38,4 +58,835 @@ mod test {
             ".test{left:100px;right:100%;writing-mode:vertical-rl;}"
         );
     }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .box_model {
+            position: absolute;
+            top: 0;
+            bottom: -10px;
+            z - index: -1;
+            display: inline - block;
+            visibility: hidden;
+            overflow - x: auto;
+            box - sizing: border - box;
+            width: 50 %;
+            min - height: auto;
+            max - width: none;
+            margin: 0 auto;
+            padding: 1px 2px 3px;
+            border: solid 1px;
+            border - width: thin medium thick 2px;
+        }
+    }]
+    struct BoxModel;
+
+    #[test]
+    fn box_model() {
+        assert_eq!(
+            BoxModel::rules(),
+            &[
+                StyleRule::Position(Position::Absolute),
+                StyleRule::Top(LengthOrPercentage::Length(Length::Px(0.))),
+                StyleRule::Bottom(LengthOrPercentage::Length(Length::Px(-10.))),
+                StyleRule::ZIndex(ZIndex::Integer(-1)),
+                StyleRule::Display(DisplayType::InlineBlock),
+                StyleRule::Visibility(Visibility::Hidden),
+                StyleRule::OverflowX(Overflow::Auto),
+                StyleRule::BoxSizing(BoxSizing::BorderBox),
+                StyleRule::Width(LengthOrPercentageOrAuto::Parcentage(Parcentage(50.))),
+                StyleRule::MinHeight(LengthOrPercentageOrAuto::Auto),
+                StyleRule::MaxWidth(LengthOrPercentageOrNone::None),
+                StyleRule::Margin(Sides {
+                    top: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    right: LengthOrPercentageOrAuto::Auto,
+                    bottom: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    left: LengthOrPercentageOrAuto::Auto,
+                }),
+                StyleRule::Padding(Sides {
+                    top: LengthOrPercentage::Length(Length::Px(1.)),
+                    right: LengthOrPercentage::Length(Length::Px(2.)),
+                    bottom: LengthOrPercentage::Length(Length::Px(3.)),
+                    left: LengthOrPercentage::Length(Length::Px(2.)),
+                }),
+                StyleRule::Border(Border {
+                    width: Some(LineWidth::Length(Length::Px(1.))),
+                    style: Some(BorderStyle::Solid),
+                    color: None,
+                }),
+                StyleRule::BorderWidth(Sides {
+                    top: LineWidth::Thin,
+                    right: LineWidth::Medium,
+                    bottom: LineWidth::Thick,
+                    left: LineWidth::Length(Length::Px(2.)),
+                }),
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&BoxModel::name());
+        BoxModel::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".box_model{position:absolute;top:0px;bottom:-10px;z-index:-1;",
+                "display:inline-block;visibility:hidden;overflow-x:auto;box-sizing:border-box;",
+                "width:50%;min-height:auto;max-width:none;margin:0px auto;padding:1px 2px 3px;",
+                "border:1px solid;border-width:thin medium thick 2px;}"
+            )
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .colors {
+            color: #f00;
+            background - color: rgba(0, 128, 255, 50 %);
+            border - color: red hsl(120deg, 100 %, 25 %) #00000080 currentColor;
+            border: 2px dashed #1a2b3c;
+        }
+    }]
+    struct Colors;
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            Colors::rules(),
+            &[
+                StyleRule::Color(Color::rgb(255, 0, 0)),
+                StyleRule::BackgroundColor(Color::rgba(0, 128, 255, 0.5)),
+                StyleRule::BorderColor(Sides {
+                    top: Color::rgb(255, 0, 0),
+                    right: Color::rgb(0, 128, 0),
+                    bottom: Color::rgba(0, 0, 0, 128. / 255.),
+                    left: Color::CurrentColor,
+                }),
+                StyleRule::Border(Border {
+                    width: Some(LineWidth::Length(Length::Px(2.))),
+                    style: Some(BorderStyle::Dashed),
+                    color: Some(Color::rgb(0x1a, 0x2b, 0x3c)),
+                }),
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Colors::name());
+        Colors::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".colors{color:#ff0000;background-color:rgba(0,128,255,0.5);",
+                "border-color:#ff0000 #008000 #00000080 currentColor;",
+                "border:2px dashed #1a2b3c;}"
+            )
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .layout {
+            display: grid;
+            grid - template - columns: repeat(auto - fill, minmax(100px, 1fr)) 20 % auto;
+            grid - template - rows: none;
+            grid - area: header / span 2 / 3;
+            gap: 8px 2vw;
+            flex - direction: row - reverse;
+            flex - wrap: wrap;
+            justify - content: space - between;
+            align - items: center;
+            flex - grow: 2;
+            flex - shrink: 0.5;
+            flex - basis: auto;
+        }
+    }]
+    struct Layout;
+
+    #[test]
+    fn layout() {
+        assert_eq!(
+            Layout::rules(),
+            &[
+                StyleRule::Display(DisplayType::Grid),
+                StyleRule::GridTemplateColumns(GridTemplate::Tracks(vec![
+                    TrackListItem::Repeat(
+                        TrackRepeat::AutoFill,
+                        vec![TrackSize::MinMax(
+                            TrackBreadth::Length(Length::Px(100.)),
+                            TrackBreadth::Fr(1.),
+                        )],
+                    ),
+                    TrackListItem::Size(TrackSize::Breadth(TrackBreadth::Parcentage(Parcentage(
+                        20.
+                    )))),
+                    TrackListItem::Size(TrackSize::Breadth(TrackBreadth::Auto)),
+                ])),
+                StyleRule::GridTemplateRows(GridTemplate::None),
+                StyleRule::GridArea(GridArea(vec![
+                    GridLine::Name("header".to_string()),
+                    GridLine::Span(2),
+                    GridLine::Index(3),
+                ])),
+                StyleRule::Gap(Gap {
+                    row: LengthOrPercentage::Length(Length::Px(8.)),
+                    column: LengthOrPercentage::Length(Length::Vw(2.)),
+                }),
+                StyleRule::FlexDirection(FlexDirection::RowReverse),
+                StyleRule::FlexWrap(FlexWrap::Wrap),
+                StyleRule::JustifyContent(JustifyContent::SpaceBetween),
+                StyleRule::AlignItems(AlignItems::Center),
+                StyleRule::FlexGrow(2.),
+                StyleRule::FlexShrink(0.5),
+                StyleRule::FlexBasis(LengthOrPercentageOrAuto::Auto),
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Layout::name());
+        Layout::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".layout{display:grid;",
+                "grid-template-columns:repeat(auto-fill,minmax(100px,1fr)) 20% auto;",
+                "grid-template-rows:none;grid-area:header / span 2 / 3;gap:8px 2vw;",
+                "flex-direction:row-reverse;flex-wrap:wrap;justify-content:space-between;",
+                "align-items:center;flex-grow:2;flex-shrink:0.5;flex-basis:auto;}"
+            )
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .typography {
+            writing - mode: horizontal - tb;
+            text - orientation: upright;
+            text - combine - upright: digits 2;
+            font - family: "Noto Serif JP", Yu Mincho, serif;
+            font - size: 120 %;
+            font - weight: 700;
+            line - height: 1.75;
+            letter - spacing: 2px;
+            text - align: justify;
+            text - emphasis: filled sesame red;
+            ruby - position: over;
+        }
+    }]
+    struct Typography;
+
+    #[test]
+    fn typography() {
+        assert_eq!(
+            Typography::rules(),
+            &[
+                StyleRule::WritingMode(WritingMode::HorizontalTb),
+                StyleRule::TextOrientation(TextOrientation::Upright),
+                StyleRule::TextCombineUpright(TextCombineUpright::Digits(2)),
+                StyleRule::FontFamily(FontFamily(vec![
+                    FamilyName::Named("Noto Serif JP".to_string()),
+                    FamilyName::Named("Yu Mincho".to_string()),
+                    FamilyName::Generic(GenericFamily::Serif),
+                ])),
+                StyleRule::FontSize(FontSize::Parcentage(Parcentage(120.))),
+                StyleRule::FontWeight(FontWeight::Number(700)),
+                StyleRule::LineHeight(LineHeight::Number(1.75)),
+                StyleRule::LetterSpacing(LetterSpacing::Length(Length::Px(2.))),
+                StyleRule::TextAlign(TextAlign::Justify),
+                StyleRule::TextEmphasis(TextEmphasis {
+                    style: TextEmphasisStyle::Shape {
+                        fill: Some(TextEmphasisFill::Filled),
+                        shape: Some(TextEmphasisShape::Sesame),
+                    },
+                    color: Some(Color::rgb(255, 0, 0)),
+                }),
+                StyleRule::RubyPosition(RubyPosition::Over),
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Typography::name());
+        Typography::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".typography{writing-mode:horizontal-tb;text-orientation:upright;",
+                "text-combine-upright:digits 2;",
+                r#"font-family:"Noto Serif JP","Yu Mincho",serif;"#,
+                "font-size:120%;font-weight:700;line-height:1.75;letter-spacing:2px;",
+                "text-align:justify;text-emphasis:filled sesame #ff0000;ruby-position:over;}"
+            )
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .logical {
+            writing - mode: vertical - rl;
+            direction: ltr;
+            inline - size: 100 %;
+            max - block - size: none;
+            margin - inline: 0 auto;
+            padding - block - end: 4px;
+            inset - inline - start: 0;
+        }
+    }]
+    struct Logical;
+
+    #[test]
+    fn logical() {
+        let px = |value| LengthOrPercentage::Length(Length::Px(value));
+        assert_eq!(
+            Logical::rules(),
+            &[
+                StyleRule::WritingMode(WritingMode::VerticalRl),
+                StyleRule::Direction(Direction::Ltr),
+                StyleRule::InlineSize(LengthOrPercentageOrAuto::Parcentage(Parcentage(100.))),
+                StyleRule::MaxBlockSize(LengthOrPercentageOrNone::None),
+                StyleRule::MarginInline(LogicalPair {
+                    start: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    end: LengthOrPercentageOrAuto::Auto,
+                }),
+                StyleRule::PaddingBlockEnd(px(4.)),
+                StyleRule::InsetInlineStart(px(0.)),
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Logical::name());
+        Logical::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".logical{writing-mode:vertical-rl;direction:ltr;inline-size:100%;",
+                "max-block-size:none;margin-inline:0px auto;padding-block-end:4px;",
+                "inset-inline-start:0px;}"
+            )
+        );
+        assert_eq!(
+            LogicalResolver::default().resolve_all(Logical::rules()),
+            vec![
+                StyleRule::WritingMode(WritingMode::VerticalRl),
+                StyleRule::Direction(Direction::Ltr),
+                StyleRule::Height(LengthOrPercentageOrAuto::Parcentage(Parcentage(100.))),
+                StyleRule::MaxWidth(LengthOrPercentageOrNone::None),
+                StyleRule::MarginTop(LengthOrPercentageOrAuto::Length(Length::Px(0.))),
+                StyleRule::MarginBottom(LengthOrPercentageOrAuto::Auto),
+                StyleRule::PaddingLeft(px(4.)),
+                StyleRule::Top(px(0.)),
+            ]
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .button {
+            color: black;
+            &:hover {
+                color: red;
+                &::after {
+                    display: block;
+                }
+            }
+            @media screen and (max - width: 600px), print {
+                width: 100 %;
+            }
+            @media (prefers - color - scheme: dark) {
+                color: white;
+                &:focus - visible {
+                    color: yellow;
+                }
+            }
+            @supports not (display: grid) {
+                display: flex;
+            }
+        }
+    }]
+    struct Button;
+
+    #[test]
+    fn blocks() {
+        assert_eq!(Button::rules(), &[StyleRule::Color(Color::rgb(0, 0, 0))]);
+        let dark = Condition::Media(vec![MediaQuery {
+            media_type: None,
+            features: vec![MediaFeature::PrefersColorScheme(ColorScheme::Dark)],
+        }]);
+        assert_eq!(
+            Button::blocks(),
+            &[
+                StyleBlock {
+                    conditions: vec![],
+                    selector: vec![Pseudo::Class("hover".to_string())],
+                    rules: vec![StyleRule::Color(Color::rgb(255, 0, 0))],
+                },
+                StyleBlock {
+                    conditions: vec![],
+                    selector: vec![
+                        Pseudo::Class("hover".to_string()),
+                        Pseudo::Element("after".to_string()),
+                    ],
+                    rules: vec![StyleRule::Display(DisplayType::Block)],
+                },
+                StyleBlock {
+                    conditions: vec![Condition::Media(vec![
+                        MediaQuery {
+                            media_type: Some(MediaType::Screen),
+                            features: vec![MediaFeature::MaxWidth(Length::Px(600.))],
+                        },
+                        MediaQuery {
+                            media_type: Some(MediaType::Print),
+                            features: vec![],
+                        },
+                    ])],
+                    selector: vec![],
+                    rules: vec![StyleRule::Width(LengthOrPercentageOrAuto::Parcentage(
+                        Parcentage(100.)
+                    ))],
+                },
+                StyleBlock {
+                    conditions: vec![dark.clone()],
+                    selector: vec![],
+                    rules: vec![StyleRule::Color(Color::rgb(255, 255, 255))],
+                },
+                StyleBlock {
+                    conditions: vec![dark],
+                    selector: vec![Pseudo::Class("focus-visible".to_string())],
+                    rules: vec![StyleRule::Color(Color::rgb(255, 255, 0))],
+                },
+                StyleBlock {
+                    conditions: vec![Condition::Supports(SupportsCondition::Not(Box::new(
+                        SupportsCondition::Declaration(StyleRule::Display(DisplayType::Grid))
+                    )))],
+                    selector: vec![],
+                    rules: vec![StyleRule::Display(DisplayType::Flex)],
+                },
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Button::name());
+        Button::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".button{color:#000000;}",
+                ".button:hover{color:#ff0000;}",
+                ".button:hover::after{display:block;}",
+                "@media screen and (max-width:600px),print{.button{width:100%;}}",
+                "@media (prefers-color-scheme:dark){.button{color:#ffffff;}}",
+                "@media (prefers-color-scheme:dark){.button:focus-visible{color:#ffff00;}}",
+                "@supports not (display:grid){.button{display:flex;}}"
+            )
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .base {
+            padding: 4px;
+        }
+    }]
+    struct Base;
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .large {
+            font-size: 20px;
+        }
+    }]
+    struct Large;
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .primary extends Base, Large {
+            color: #0000ff;
+        }
+    }]
+    struct Primary;
+
+    #[test]
+    fn extends() {
+        struct Names(Vec<String>);
+        impl StyleSetVisitor for Names {
+            fn visit<S: StyleSet>(&mut self) {
+                self.0.push(S::name());
+            }
+        }
+        let mut names = Names(vec![]);
+        Primary::extends(&mut names);
+        assert_eq!(names.0, vec!["base", "large"]);
+        Base::extends(&mut names);
+        assert_eq!(names.0.len(), 2);
+        assert_eq!(Primary::rules(), &[StyleRule::Color(Color::rgb(0, 0, 255))]);
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .moving {
+            position: absolute;
+            left: {x}px;
+            top: {y}%;
+            border: {width}px solid rgb({red}, 0, 0);
+            &:hover {
+                left: -{x}px;
+                width: calc(100% - {width}px * 2);
+            }
+        }
+    }]
+    struct Moving {
+        x: f64,
+        y: u32,
+        width: f64,
+        red: u8,
+    }
+
+    #[test]
+    fn interpolation() {
+        let moving = Moving {
+            x: 1.5,
+            y: 50,
+            width: 2.,
+            red: 255,
+        };
+        assert_eq!(
+            moving.variables(),
+            vec![
+                ("moving-0".to_string(), "1.5px".to_string()),
+                ("moving-1".to_string(), "50%".to_string()),
+                ("moving-2".to_string(), "2px solid rgb(255,0,0)".to_string()),
+                ("moving-3".to_string(), "-1.5px".to_string()),
+                ("moving-4".to_string(), "calc(100% - 2px * 2)".to_string()),
+            ]
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Moving::name());
+        Moving::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".moving{position:absolute;left:var(--moving-0);top:var(--moving-1);",
+                "border:var(--moving-2);}",
+                ".moving:hover{left:var(--moving-3);width:var(--moving-4);}"
+            )
+        );
+    }
+
+    #[derive(Theme)]
+    struct Tokens {
+        primary: Color,
+        gap: Length,
+        font_body: FontFamily,
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .card {
+            color: var(--primary);
+            gap: var(--gap);
+            font-family: var(--font-body);
+            padding: 1px;
+        }
+    }]
+    struct Card;
+
+    #[test]
+    fn theme() {
+        let tokens = Tokens {
+            primary: Color::rgb(255, 0, 0),
+            gap: Length::Px(8.),
+            font_body: FontFamily(vec![
+                FamilyName::Named("Noto Sans".to_string()),
+                FamilyName::Generic(GenericFamily::SansSerif),
+            ]),
+        };
+        assert_eq!(
+            tokens.css(":root"),
+            r#":root{--primary:#ff0000;--gap:8px;--font-body:"Noto Sans",sans-serif;}"#
+        );
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Card::name());
+        Card::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".card{color:var(--primary);gap:var(--gap);",
+                "font-family:var(--font-body);padding:1px;}"
+            )
+        );
+    }
+
+    include_tokens! {
+        struct DesignTokens = "tokens.json";
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .link {
+            color: var(--color-primary);
+            background-color: var(--color-surface);
+            padding: var(--space-medium);
+            &:hover {
+                font-weight: var(--font-weight-bold);
+            }
+        }
+    }]
+    #[theme(DesignTokens)]
+    struct Link;
+
+    #[test]
+    fn design_tokens() {
+        assert_eq!(DesignTokens::COLOR_PRIMARY, Color::rgb(0x33, 0x66, 0xff));
+        assert_eq!(DesignTokens::SPACE_NONE, Length::Px(0.));
+        assert_eq!(DesignTokens::MOTION_DURATION, Time::Milliseconds(200.));
+        assert_eq!(
+            DesignTokens::MOTION_EASING,
+            TimingFunction::CubicBezier(0.4, 0., 0.2, 1.)
+        );
+        assert_eq!(DesignTokens::OPACITY_DISABLED, 0.5);
+        let tokens = DesignTokens {
+            color_primary: Color::rgb(0, 0, 0),
+            ..DesignTokens::default()
+        };
+        assert_eq!(tokens.font_weight_bold, FontWeight::Number(700));
+        assert_eq!(
+            tokens.css(":root"),
+            concat!(
+                ":root{--color-primary:#000000;--color-surface:#ffffff;--space-none:0px;",
+                "--space-medium:1rem;--motion-duration:200ms;",
+                "--motion-easing:cubic-bezier(0.4,0,0.2,1);--font-weight-bold:700;",
+                "--opacity-disabled:0.5;}"
+            )
+        );
+        assert_eq!(Link::rules()[0].to_string(), "color:var(--color-primary);");
+    }
+
+    fn round_trip<S: StyleSet>() {
+        let css = S::rules()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<String>();
+        assert_eq!(
+            parse_declarations(&css).as_deref(),
+            Ok(S::rules()),
+            "{}",
+            css
+        );
+    }
+
+    #[test]
+    fn runtime_parser() {
+        round_trip::<Style>();
+        round_trip::<BoxModel>();
+        round_trip::<Colors>();
+        round_trip::<Layout>();
+        round_trip::<Typography>();
+        round_trip::<Logical>();
+        round_trip::<Card>();
+        let error = parse_declarations("left: 1px; top: 1").unwrap_err();
+        assert_eq!(
+            (error.offset(), error.message()),
+            (16, "Suffix is required.")
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .button_css {
+            r#"
+            color: black;
+            &:hover {
+                color: red;
+                &::after { display: block }
+            }
+            @media screen and (max-width: 600px), print {
+                width: 100%;
+            }
+            @media (prefers-color-scheme: dark) {
+                color: white;
+                &:focus-visible { color: yellow; }
+            }
+            @supports not (display: grid) {
+                display: flex;
+            }
+            "#
+        }
+    }]
+    struct ButtonCss;
+
+    #[derive(StyleSet)]
+    #[style_set("writing-mode: vertical-rl; color: #fff; margin: 0 1.5em")]
+    struct Anonymous;
+
+    #[test]
+    fn compile_errors() {
+        trybuild::TestCases::new().compile_fail("ui/*.rs");
+    }
+
+    #[test]
+    fn css_text() {
+        assert_eq!(ButtonCss::name(), "button_css");
+        assert_eq!(ButtonCss::rules(), Button::rules());
+        assert_eq!(ButtonCss::blocks(), Button::blocks());
+        assert_eq!(
+            Anonymous::rules(),
+            &[
+                StyleRule::WritingMode(WritingMode::VerticalRl),
+                StyleRule::Color(Color::rgb(255, 255, 255)),
+                StyleRule::Margin(Sides {
+                    top: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    right: LengthOrPercentageOrAuto::Length(Length::Em(1.5)),
+                    bottom: LengthOrPercentageOrAuto::Length(Length::Px(0.)),
+                    left: LengthOrPercentageOrAuto::Length(Length::Em(1.5)),
+                }),
+            ]
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .sizes {
+            width: calc(100% - (1rem + 2px) * 2);
+            max-width: min(90vw, clamp(10pt, 50%, 3in));
+            min-height: max(2ch, 1vmin - 1mm);
+            left: calc(1cm / 4 + -1ch);
+            top: 1vmax;
+        }
+    }]
+    struct Sizes;
+
+    #[derive(StyleSet)]
+    #[style_set(
+        "width: calc(100% - (1rem + 2px) * 2); max-width: min(90vw, clamp(10pt, 50%, 3in));
+        min-height: max(2ch, 1vmin - 1mm); left: calc(1cm / 4 + -1ch); top: 1vmax"
+    )]
+    struct SizesCss;
+
+    #[test]
+    fn calc() {
+        assert_eq!(Sizes::rules(), SizesCss::rules());
+        assert_eq!(
+            Sizes::rules()[0],
+            StyleRule::Width(LengthOrPercentageOrAuto::Calc(Calc::Sub(
+                Box::new(Calc::Parcentage(Parcentage(100.))),
+                Box::new(Calc::Mul(
+                    Box::new(Calc::Add(
+                        Box::new(Calc::Length(Length::Rem(1.))),
+                        Box::new(Calc::Length(Length::Px(2.)))
+                    )),
+                    Box::new(Calc::Number(2.))
+                ))
+            )))
+        );
+        assert_eq!(
+            Sizes::rules()[4],
+            StyleRule::Top(LengthOrPercentage::Length(Length::Vmax(1.)))
+        );
+        round_trip::<Sizes>();
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Sizes::name());
+        Sizes::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".sizes{width:calc(100% - ((1rem + 2px) * 2));",
+                "max-width:min(90vw,clamp(10pt,50%,3in));",
+                "min-height:max(2ch,1vmin - 1mm);",
+                "left:calc((1cm / 4) + -1ch);top:1vmax;}"
+            )
+        );
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .spinner {
+            opacity: 0.8;
+            transition: opacity 0.3s ease - in - out, width 200ms steps(4, jump - end) 1s;
+            animation: spin 1s linear infinite, fade 2s cubic - bezier(0.1, 0.7, 1, 0.1) both;
+            @keyframes spin {
+                from { opacity: 1; }
+                50%, 75% { opacity: 0.5; }
+                to { opacity: 1; }
+            }
+            @keyframes fade {
+                to { opacity: 0; }
+            }
+        }
+    }]
+    struct Spinner;
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .spinner_css {
+            r#"
+            opacity: .8;
+            transition: opacity .3s ease-in-out, width 200ms steps(4, jump-end) 1s;
+            animation: spin 1s linear infinite, fade 2s cubic-bezier(.1, .7, 1, .1) both;
+            @keyframes spin {
+                from { opacity: 1 }
+                50%, 75% { opacity: .5 }
+                to { opacity: 1 }
+            }
+            @keyframes fade { to { opacity: 0 } }
+            "#
+        }
+    }]
+    struct SpinnerCss;
+
+    #[test]
+    fn animations() {
+        assert_eq!(Spinner::rules(), SpinnerCss::rules());
+        assert_eq!(Spinner::keyframes(), SpinnerCss::keyframes());
+        assert_eq!(
+            Spinner::rules()[1],
+            StyleRule::Transition(Transition(vec![
+                SingleTransition {
+                    property: Some(TransitionProperty::Property("opacity".into())),
+                    duration: Some(Time::Seconds(0.3)),
+                    timing_function: Some(TimingFunction::EaseInOut),
+                    delay: None,
+                },
+                SingleTransition {
+                    property: Some(TransitionProperty::Property("width".into())),
+                    duration: Some(Time::Milliseconds(200.)),
+                    timing_function: Some(TimingFunction::Steps(4, Some(StepPosition::JumpEnd))),
+                    delay: Some(Time::Seconds(1.)),
+                },
+            ]))
+        );
+        assert_eq!(
+            Spinner::rules()[2],
+            StyleRule::Animation(Animation(vec![
+                SingleAnimation {
+                    duration: Some(Time::Seconds(1.)),
+                    timing_function: Some(TimingFunction::Linear),
+                    iteration_count: Some(IterationCount::Infinite),
+                    name: Some("spin".into()),
+                    ..Default::default()
+                },
+                SingleAnimation {
+                    duration: Some(Time::Seconds(2.)),
+                    timing_function: Some(TimingFunction::CubicBezier(0.1, 0.7, 1., 0.1)),
+                    fill_mode: Some(AnimationFillMode::Both),
+                    name: Some("fade".into()),
+                    ..Default::default()
+                },
+            ]))
+        );
+        assert_eq!(
+            Spinner::keyframes()[1],
+            Keyframes {
+                name: "fade".into(),
+                frames: vec![Keyframe {
+                    selectors: vec![Parcentage(100.)],
+                    rules: vec![StyleRule::Opacity(0.)],
+                }],
+            }
+        );
+        round_trip::<Spinner>();
+        let mut renderer = CSSRenderer::default();
+        renderer.name(&Spinner::name());
+        Spinner::render(&mut renderer);
+        assert_eq!(
+            renderer.finish(),
+            concat!(
+                ".spinner{opacity:0.8;",
+                "transition:opacity 0.3s ease-in-out,width 200ms steps(4,jump-end) 1s;",
+                "animation:1s linear infinite spin,2s cubic-bezier(0.1,0.7,1,0.1) both fade;}",
+                "@keyframes spin{0%{opacity:1;}50%,75%{opacity:0.5;}100%{opacity:1;}}",
+                "@keyframes fade{100%{opacity:0;}}"
+            )
+        );
+    }
 }
@@ -0,0 +1,7 @@
+use sulafat_macros::StyleSet;
+
+#[derive(StyleSet)]
+#[style_set("font-family: \"Noto\u{20}Sans\"; lft: 1px;")]
+struct Escaped;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sulafat_macros::StyleSet;
+
+#[derive(StyleSet)]
+#[style_set("left: {x}px;")]
+struct Interpolated {
+    x: f64,
+}
+
+fn main() {}
@@ -1,22 +1,21 @@
-use crate::{ClosureId, Handler};
+use crate::{ClosureId, Handler, MsgMap, StyleRegistry};
 use serde::{ser::SerializeTupleVariant, Serialize, Serializer};
 use std::{
     any::Any,
     collections::HashMap,
     fmt::Debug,
-    hash::Hash,
     rc::{Rc, Weak},
 };
 use sulafat_macros::{Clone, PartialEq, VariantIdent};
 
-use super::Style;
+use super::{RenderedStyleList, Style, StyleList};
 
 #[derive(Debug, Clone, PartialEq, VariantIdent)]
 pub enum Attribute<Msg> {
     Id(String),
     OnClick(Rc<Handler<(), Msg>>),
     OnPointerMove(Rc<Handler<(f64, f64), Msg>>),
-    Style(Style),
+    Style(StyleList),
 }
 
 impl<Msg> Attribute<Msg> {
@@ -35,6 +34,31 @@ impl<Msg> Attribute<Msg> {
             Attribute::Style(_) => {}
         }
     }
+
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> Attribute<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        match self {
+            Attribute::Id(id) => Attribute::Id(id),
+            Attribute::OnClick(handler) => Attribute::OnClick(Rc::new(handler.map(map))),
+            Attribute::OnPointerMove(handler) => {
+                Attribute::OnPointerMove(Rc::new(handler.map(map)))
+            }
+            Attribute::Style(list) => Attribute::Style(list),
+        }
+    }
+
+    pub(crate) fn pick_style(&self, registry: &mut StyleRegistry) {
+        if let Attribute::Style(list) = self {
+            for style in list.iter() {
+                if let Style::Dynamic(style) = style {
+                    registry.retain(style)
+                }
+            }
+        }
+    }
 }
 
 impl<Msg> Eq for Attribute<Msg> {}
@@ -64,7 +88,7 @@ impl<Msg> Serialize for Attribute<Msg> {
             }
             Attribute::Style(style) => {
                 let mut variant = serializer.serialize_tuple_variant("Attribute", 3, "Style", 1)?;
-                variant.serialize_field(style)?;
+                variant.serialize_field(&RenderedStyleList::from(style))?;
                 variant.end()
             }
         }
@@ -1,6 +1,8 @@
 use std::{cmp::Ordering, iter::FromIterator, ops::Deref};
 
-use crate::{Attribute, Diff, PatchAttributeList, PatchAttributeListOp, VariantIdent};
+use crate::{
+    Attribute, Diff, MsgMap, PatchAttributeList, PatchAttributeListOp, StyleList, VariantIdent,
+};
 use sulafat_macros::{Clone, PartialEq, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -9,11 +11,36 @@ pub struct AttributeList<Msg> {
 }
 
 impl<Msg> AttributeList<Msg> {
-    pub(crate) fn new(mut list: Vec<Attribute<Msg>>) -> Self {
-        list.sort_by(|a, b| a.variant_ident().cmp(&b.variant_ident()));
+    pub(crate) fn new(list: Vec<Attribute<Msg>>) -> Self {
+        let mut styles: Option<StyleList> = None;
+        let mut list = list
+            .into_iter()
+            .filter_map(|a| match a {
+                Attribute::Style(list) => {
+                    styles.get_or_insert_with(Default::default).append(list);
+                    None
+                }
+                a => Some(a),
+            })
+            .collect::<Vec<_>>();
+        if let Some(styles) = styles {
+            list.push(Attribute::Style(styles));
+        }
+        list.sort_by_key(|a| a.variant_ident());
         list.dedup_by(|a, b| a.variant_ident() == b.variant_ident());
         Self { list }
     }
+
+    /// Mapping keeps the variants, so the list stays sorted.
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> AttributeList<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        AttributeList {
+            list: self.list.into_iter().map(|a| a.map(map)).collect(),
+        }
+    }
 }
 
 impl<Msg> Deref for AttributeList<Msg> {
@@ -51,7 +78,7 @@ impl<Msg> Diff for AttributeList<Msg> {
         let mut list = vec![];
         while i1 < self.len() && i2 < other.len() {
             let this = &self[i1];
-            let other = &other[i2];
+            let other = &mut other.list[i2];
             match this.variant_ident().cmp(&other.variant_ident()) {
                 Ordering::Less => {
                     i1 += 1;
@@ -59,13 +86,17 @@ impl<Msg> Diff for AttributeList<Msg> {
                 }
                 Ordering::Greater => {
                     i2 += 1;
-                    list.push(PatchAttributeListOp::Insert(other.into()))
+                    list.push(PatchAttributeListOp::Insert((&*other).into()))
                 }
                 Ordering::Equal => {
                     i1 += 1;
                     i2 += 1;
-                    if this != other {
-                        list.push(PatchAttributeListOp::Insert(other.into()))
+                    if let (Attribute::Style(this), Attribute::Style(other)) = (this, &mut *other) {
+                        if let Some(patch) = this.diff(other) {
+                            list.push(PatchAttributeListOp::Style(patch))
+                        }
+                    } else if this != other {
+                        list.push(PatchAttributeListOp::Insert((&*other).into()))
                     }
                 }
             }
@@ -0,0 +1,136 @@
+use std::{
+    any::TypeId,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+use sulafat_style::{CSSRenderer, StyleRenderer, StyleRule, StyleSet};
+
+/// A style that is not covered by the exported stylesheet. The runtime inserts `css`
+/// into a shared `<style>` sheet while any element uses the class `name`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynamicStyle {
+    name: Rc<str>,
+    css: Rc<str>,
+}
+
+impl DynamicStyle {
+    pub fn from_style_set<S: StyleSet>() -> Self {
+        thread_local! {
+            static CACHE: RefCell<HashMap<TypeId, DynamicStyle>> = Default::default();
+        }
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entry(TypeId::of::<S>())
+                .or_insert_with(|| {
+                    let name = S::name();
+                    let mut renderer = CSSRenderer::default();
+                    renderer.name(&name);
+                    S::render(&mut renderer);
+                    Self {
+                        name: name.into(),
+                        css: renderer.finish().into(),
+                    }
+                })
+                .clone()
+        })
+    }
+
+    /// The class name is derived from the rules, so equal rules share one class.
+    pub fn from_rules(rules: &[StyleRule]) -> Self {
+        thread_local! {
+            static INTERNED: RefCell<Interned> = Default::default();
+        }
+        let body = rules
+            .iter()
+            .map(|rule| rule.to_string())
+            .collect::<String>();
+        let hash = fnv1a(&body);
+        INTERNED.with(|interned| {
+            interned.borrow_mut().get(body, hash, |name| {
+                let mut renderer = CSSRenderer::default();
+                renderer.name(name);
+                for rule in rules {
+                    renderer.render(rule);
+                }
+                renderer.finish()
+            })
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn css(&self) -> &str {
+        &self.css
+    }
+}
+
+/// FNV-1a, of which the result is stable across Rust releases unlike `DefaultHasher`.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The styles made from rules, keyed by the rule text. Rules of which the hashes collide
+/// get the name with a suffix, so that a class never stands for two different rules.
+#[derive(Default)]
+struct Interned {
+    styles: HashMap<String, DynamicStyle>,
+    names: HashSet<Rc<str>>,
+}
+
+impl Interned {
+    fn get(&mut self, body: String, hash: u64, css: impl FnOnce(&str) -> String) -> DynamicStyle {
+        if let Some(style) = self.styles.get(&body) {
+            return style.clone();
+        }
+        let mut name = format!("sulafat-d{:016x}", hash);
+        let mut suffix = 0;
+        while self.names.contains(name.as_str()) {
+            suffix += 1;
+            name = format!("sulafat-d{:016x}-{}", hash, suffix);
+        }
+        let style = DynamicStyle {
+            css: css(&name).into(),
+            name: name.into(),
+        };
+        self.names.insert(style.name.clone());
+        self.styles.insert(body, style.clone());
+        style
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fnv1a, DynamicStyle, Interned};
+    use sulafat_style::{Length, LengthOrPercentage, StyleRule};
+
+    #[test]
+    fn stable_name() {
+        assert_eq!(fnv1a(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a("a"), 0xaf63_dc4c_8601_ec8c);
+        let style = DynamicStyle::from_rules(&[StyleRule::Left(LengthOrPercentage::Length(
+            Length::Px(1.),
+        ))]);
+        assert_eq!(
+            style.name(),
+            format!("sulafat-d{:016x}", fnv1a("left:1px;"))
+        );
+        assert_eq!(style.css(), format!(".{}{{left:1px;}}", style.name()));
+    }
+
+    #[test]
+    fn collision() {
+        let mut interned = Interned::default();
+        let css = |name: &str| name.to_string();
+        let first = interned.get("left:1px;".into(), 0, css);
+        let second = interned.get("left:2px;".into(), 0, css);
+        assert_eq!(first.name(), "sulafat-d0000000000000000");
+        assert_eq!(second.name(), "sulafat-d0000000000000000-1");
+        assert_eq!(interned.get("left:1px;".into(), 0, css), first);
+    }
+}
@@ -1,8 +1,7 @@
 use std::rc::Rc;
 
-use crate::{Attribute, Handler};
-
-use super::style::ToStyle;
+use crate::{Attribute, Handler, StyleList, ToStyle};
+use sulafat_style::Theme;
 
 pub fn id<Msg>(s: String) -> Attribute<Msg> {
     Attribute::Id(s)
@@ -24,9 +23,20 @@ where
     Attribute::OnPointerMove(Rc::new(Handler::new(f)))
 }
 
+/// Several `style` attributes on one element are merged into a single class list.
 pub fn style<Msg, S>(s: S) -> Attribute<Msg>
 where
     S: ToStyle,
 {
     Attribute::Style(s.to_style())
 }
+
+/// Sets the tokens of `t` as custom properties of the element, so that `var(--token)`
+/// in the styles of its subtree resolves to them. Switching themes patches only this
+/// element's variables.
+pub fn theme<Msg, T>(t: T) -> Attribute<Msg>
+where
+    T: Theme,
+{
+    Attribute::Style(StyleList::default().with_variables(t.variables()))
+}
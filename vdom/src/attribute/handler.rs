@@ -20,6 +20,11 @@ impl<Args, Msg> Handler<Args, Msg> {
         self.handle.invoke(args)
     }
 
+    /// `None` if the message was handled by a local state on the way.
+    pub(crate) fn dispatch(&self, args: Args) -> Option<Msg> {
+        self.handle.dispatch(args)
+    }
+
     pub(crate) fn new<F: 'static + Fn(Args) -> Msg>(f: F) -> Self
     where
         Args: 'static,
@@ -70,6 +75,81 @@ impl<Args, Msg> Handler<Args, Msg> {
     pub fn closure_id(&self) -> &ClosureId {
         &self.closure_id
     }
+
+    /// The mapped handler is dispatched by an id derived from both `self` and `map`, so
+    /// that one handler mapped into several places, e.g. the same view of two
+    /// components, is told apart. It compares equal to one mapped from an equal handler
+    /// with the same function.
+    pub(crate) fn map<Msg2>(self: &Rc<Self>, map: &MsgMap<Msg, Msg2>) -> Handler<Args, Msg2>
+    where
+        Args: 'static,
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        Handler {
+            closure_id: ClosureId::mapped(self.closure_id, &map.id),
+            handle: Box::new(MappedHandle {
+                handler: self.clone(),
+                map: map.clone(),
+            }),
+        }
+    }
+}
+
+/// A conversion of messages applied to every handler of a subtree.
+pub(crate) struct MsgMap<Msg, Msg2> {
+    id: ClosureId,
+    f: Rc<dyn Fn(Msg) -> Option<Msg2>>,
+}
+
+impl<Msg, Msg2> MsgMap<Msg, Msg2> {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: 'static + Fn(Msg) -> Msg2,
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        Self {
+            id: ClosureId::new::<F, Msg, Msg2>(&f),
+            f: Rc::new(move |msg| Some(f(msg))),
+        }
+    }
+
+    /// A conversion identified by `id` instead of `f`.
+    pub(crate) fn with_id<F>(id: ClosureId, f: F) -> Self
+    where
+        F: 'static + Fn(Msg) -> Msg2,
+    {
+        Self {
+            id,
+            f: Rc::new(move |msg| Some(f(msg))),
+        }
+    }
+
+    /// A conversion which may consume the message, identified by `id` instead of `f`.
+    pub(crate) fn filter<F>(id: ClosureId, f: F) -> Self
+    where
+        F: 'static + Fn(Msg) -> Option<Msg2>,
+    {
+        Self { id, f: Rc::new(f) }
+    }
+
+    pub(crate) fn map(&self, msg: Msg) -> Option<Msg2> {
+        (self.f)(msg)
+    }
+
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<Msg, Msg2> Clone for MsgMap<Msg, Msg2> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            f: self.f.clone(),
+        }
+    }
 }
 
 impl<Args, Msg> Debug for Handler<Args, Msg> {
@@ -88,6 +168,9 @@ impl<Args, Msg> Eq for Handler<Args, Msg> {}
 
 pub trait Handle<Args, Msg> {
     fn invoke(&self, args: Args) -> Msg;
+    fn dispatch(&self, args: Args) -> Option<Msg> {
+        Some(self.invoke(args))
+    }
     fn is_same(&self, other: &dyn Handle<Args, Msg>) -> bool;
     fn as_any(&self) -> &dyn Any;
 }
@@ -109,6 +192,37 @@ where
     }
 }
 
+struct MappedHandle<Args, Msg, Msg2> {
+    handler: Rc<Handler<Args, Msg>>,
+    map: MsgMap<Msg, Msg2>,
+}
+
+impl<Args, Msg, Msg2> Handle<Args, Msg2> for MappedHandle<Args, Msg, Msg2>
+where
+    Self: 'static,
+{
+    fn invoke(&self, args: Args) -> Msg2 {
+        self.dispatch(args)
+            .expect("ローカルな状態へのメッセージは返せません")
+    }
+
+    fn dispatch(&self, args: Args) -> Option<Msg2> {
+        self.map.map(self.handler.dispatch(args)?)
+    }
+
+    fn is_same(&self, other: &dyn Handle<Args, Msg2>) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() {
+            self.map.is_same(&other.map) && self.handler.handle.is_same(&*other.handler.handle)
+        } else {
+            false
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 struct CachedHandle<F, Data, Args, Msg> {
     data: Data,
     f: F,
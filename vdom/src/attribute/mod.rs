@@ -1,10 +1,14 @@
 mod attribute;
 mod attribute_list;
+mod dynamic_style;
 mod functions;
 mod handler;
 mod rendered_attribute;
 mod rendered_attribute_list;
+mod rendered_style;
+mod rendered_style_list;
 mod style;
+mod style_list;
 pub use rendered_attribute::RenderedAttribute;
 pub use rendered_attribute_list::{
     PatchAttributeList, PatchAttributeListOp, RenderedAttributeList,
@@ -12,6 +16,11 @@ pub use rendered_attribute_list::{
 
 pub use attribute::Attribute;
 pub use attribute_list::AttributeList;
-pub use functions::{id, on_click, on_pointer_move, style};
+pub use dynamic_style::DynamicStyle;
+pub use functions::{id, on_click, on_pointer_move, style, theme};
 pub use handler::Handler;
-pub use style::Style;
+pub(crate) use handler::MsgMap;
+pub use rendered_style::RenderedStyle;
+pub use rendered_style_list::{PatchStyleList, PatchStyleListOp, RenderedStyleList};
+pub use style::{Style, ToStyle};
+pub use style_list::StyleList;
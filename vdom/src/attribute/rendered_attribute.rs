@@ -1,4 +1,4 @@
-use crate::{Attribute, ClosureId, Style, VariantIdent};
+use crate::{Attribute, ClosureId, RenderedStyleList, VariantIdent};
 use serde_derive::{Deserialize, Serialize};
 use sulafat_macros::VariantIdent;
 
@@ -9,18 +9,18 @@ pub enum RenderedAttribute {
     Id(String),
     OnClick(ClosureId),
     OnPointerMove(ClosureId),
-    Style(Style),
+    Style(RenderedStyleList),
 }
 
 impl<Msg> From<&Attribute<Msg>> for RenderedAttribute {
     fn from(attr: &Attribute<Msg>) -> Self {
         match attr {
             Attribute::Id(id) => RenderedAttribute::Id(id.clone()),
-            Attribute::OnClick(handler) => RenderedAttribute::OnClick(handler.closure_id().clone()),
+            Attribute::OnClick(handler) => RenderedAttribute::OnClick(*handler.closure_id()),
             Attribute::OnPointerMove(handler) => {
-                RenderedAttribute::OnPointerMove(handler.closure_id().clone())
+                RenderedAttribute::OnPointerMove(*handler.closure_id())
             }
-            Attribute::Style(style) => RenderedAttribute::Style(style.clone()),
+            Attribute::Style(style) => RenderedAttribute::Style(style.into()),
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use crate::{Apply, ApplyResult, AttributeList, VariantIdent};
+use crate::{Apply, ApplyResult, AttributeList, PatchStyleList, VariantIdent};
 use serde_derive::{Deserialize, Serialize};
 
 use super::RenderedAttribute;
@@ -13,7 +13,7 @@ pub struct RenderedAttributeList {
 
 impl From<Vec<RenderedAttribute>> for RenderedAttributeList {
     fn from(mut list: Vec<RenderedAttribute>) -> Self {
-        list.sort_by(|a, b| a.variant_ident().cmp(&b.variant_ident()));
+        list.sort_by_key(|a| a.variant_ident());
         list.dedup_by(|a, b| a.variant_ident() == b.variant_ident());
         Self { list }
     }
@@ -67,6 +67,16 @@ impl Apply for RenderedAttributeList {
                     }
                     i += 1;
                 }
+                PatchAttributeListOp::Style(patch) => {
+                    while i < self.len() && !matches!(self[i], RenderedAttribute::Style(_)) {
+                        i += 1;
+                    }
+                    if let Some(RenderedAttribute::Style(list)) = self.list.get_mut(i) {
+                        list.apply(patch)?;
+                    } else {
+                        return Err("変更する属性がありません".into());
+                    }
+                }
             }
         }
         Ok(())
@@ -96,4 +106,6 @@ impl IntoIterator for PatchAttributeList {
 pub enum PatchAttributeListOp {
     Remove(<RenderedAttribute as VariantIdent>::Type),
     Insert(RenderedAttribute),
+    /// Adds and removes classes of the `Style` attribute one by one.
+    Style(PatchStyleList),
 }
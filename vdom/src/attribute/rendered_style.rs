@@ -0,0 +1,14 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Style;
+
+/// The class name an element is rendered with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "Style")]
+pub struct RenderedStyle(pub String);
+
+impl From<&Style> for RenderedStyle {
+    fn from(style: &Style) -> Self {
+        RenderedStyle(style.name().to_string())
+    }
+}
@@ -0,0 +1,103 @@
+use std::{collections::BTreeMap, ops::Deref};
+
+use crate::{Apply, ApplyResult, RenderedStyle, StyleList};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "StyleList")]
+pub struct RenderedStyleList {
+    list: Vec<RenderedStyle>,
+    variables: BTreeMap<String, String>,
+}
+
+impl RenderedStyleList {
+    pub fn variables(&self) -> &BTreeMap<String, String> {
+        &self.variables
+    }
+}
+
+impl From<Vec<RenderedStyle>> for RenderedStyleList {
+    fn from(list: Vec<RenderedStyle>) -> Self {
+        Self {
+            list,
+            variables: Default::default(),
+        }
+    }
+}
+
+impl Deref for RenderedStyleList {
+    type Target = Vec<RenderedStyle>;
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl From<&StyleList> for RenderedStyleList {
+    fn from(list: &StyleList) -> Self {
+        Self {
+            list: list.iter().map(|style| style.into()).collect(),
+            variables: list.variables().clone(),
+        }
+    }
+}
+
+impl Apply for RenderedStyleList {
+    type Patch = PatchStyleList;
+    fn apply(&mut self, patch: Self::Patch) -> ApplyResult {
+        for patch in patch {
+            match patch {
+                PatchStyleListOp::Remove(name) => {
+                    if let Some(index) = self.iter().position(|style| style.0 == name) {
+                        self.list.remove(index);
+                    } else {
+                        return Err("削除するクラスがありません".into());
+                    }
+                }
+                PatchStyleListOp::Insert(index, style) => {
+                    if index > self.len() {
+                        return Err("挿入位置が範囲外です".into());
+                    }
+                    self.list.insert(index, style);
+                }
+                PatchStyleListOp::RemoveVariable(name) => {
+                    if self.variables.remove(&name).is_none() {
+                        return Err("削除する変数がありません".into());
+                    }
+                }
+                PatchStyleListOp::SetVariable(name, value) => {
+                    self.variables.insert(name, value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Class removals come first, then class insertions in ascending index order, then
+/// custom property changes.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchStyleList {
+    list: Vec<PatchStyleListOp>,
+}
+
+impl From<Vec<PatchStyleListOp>> for PatchStyleList {
+    fn from(list: Vec<PatchStyleListOp>) -> Self {
+        Self { list }
+    }
+}
+
+impl IntoIterator for PatchStyleList {
+    type Item = PatchStyleListOp;
+    type IntoIter = <Vec<PatchStyleListOp> as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PatchStyleListOp {
+    Remove(String),
+    Insert(usize, RenderedStyle),
+    RemoveVariable(String),
+    SetVariable(String, String),
+}
@@ -1,34 +1,77 @@
-use serde_derive::{Deserialize, Serialize};
-use sulafat_style::{StyleRule, StyleSet};
+use sulafat_style::{StyleRule, StyleSet, StyleSetVisitor};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+use super::{DynamicStyle, StyleList};
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Style {
+    /// A class defined by the exported stylesheet.
     Static(String),
-    Dynamic(Vec<StyleRule>),
+    /// A class registered at runtime, see [`StyleRegistry`](crate::StyleRegistry).
+    Dynamic(DynamicStyle),
+}
+
+impl Style {
+    pub fn of<S: StyleSet>() -> Self {
+        #[cfg(feature = "export-css")]
+        {
+            Style::Static(S::name())
+        }
+        #[cfg(not(feature = "export-css"))]
+        {
+            Style::Dynamic(DynamicStyle::from_style_set::<S>())
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Style::Static(name) => name,
+            Style::Dynamic(style) => style.name(),
+        }
+    }
 }
 
 pub trait ToStyle {
-    fn to_style(&self) -> Style;
+    fn to_style(&self) -> StyleList;
 }
 
+/// With `export-css` the class comes from the exported stylesheet. Otherwise the same
+/// CSS, including `S::blocks()`, is registered at runtime, so both modes render the
+/// same class.
+///
+/// The classes of extended style sets come first. Only `self` supplies variables, so
+/// interpolated declarations of an extended set are left unset.
 impl<S> ToStyle for S
 where
     S: StyleSet,
 {
-    fn to_style(&self) -> Style {
-        #[cfg(feature = "export-css")]
-        {
-            Style::Static(S::name())
-        }
-        #[cfg(not(feature = "export-css"))]
-        {
-            Style::Dynamic(Vec::from(S::rules()))
+    fn to_style(&self) -> StyleList {
+        struct Collect(Vec<Style>);
+        impl StyleSetVisitor for Collect {
+            fn visit<S: StyleSet>(&mut self) {
+                S::extends(self);
+                self.0.push(Style::of::<S>());
+            }
         }
+        let mut collect = Collect(vec![]);
+        collect.visit::<S>();
+        StyleList::from(collect.0).with_variables(self.variables())
     }
 }
 
 impl ToStyle for [StyleRule] {
-    fn to_style(&self) -> Style {
-        Style::Dynamic(self.to_owned())
+    fn to_style(&self) -> StyleList {
+        vec![Style::Dynamic(DynamicStyle::from_rules(self))].into()
+    }
+}
+
+impl ToStyle for Style {
+    fn to_style(&self) -> StyleList {
+        vec![self.clone()].into()
+    }
+}
+
+impl ToStyle for StyleList {
+    fn to_style(&self) -> StyleList {
+        self.clone()
     }
 }
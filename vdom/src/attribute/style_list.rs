@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, iter::FromIterator, ops::Deref};
+
+use crate::{Diff, PatchStyleList, PatchStyleListOp, Style};
+
+/// The classes of an element, in the order they were given, and the custom properties
+/// set on it. A class appears at most once; later duplicates are dropped.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyleList {
+    list: Vec<Style>,
+    variables: BTreeMap<String, String>,
+}
+
+impl StyleList {
+    pub(crate) fn new(list: Vec<Style>) -> Self {
+        let mut this = Self::default();
+        for style in list {
+            this.push(style);
+        }
+        this
+    }
+
+    /// Custom property names without `--` and their values, see
+    /// [`StyleSet::variables`](sulafat_style::StyleSet::variables).
+    pub fn with_variables<I>(mut self, variables: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        self.variables.extend(variables);
+        self
+    }
+
+    pub fn variables(&self) -> &BTreeMap<String, String> {
+        &self.variables
+    }
+
+    /// Appends the classes of `other` that are not in `self` yet. Variables of `other`
+    /// override those of `self`.
+    pub(crate) fn append(&mut self, other: StyleList) {
+        for style in other.list {
+            self.push(style);
+        }
+        self.variables.extend(other.variables);
+    }
+
+    fn push(&mut self, style: Style) {
+        if self.position(style.name()).is_none() {
+            self.list.push(style);
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.list.iter().position(|style| style.name() == name)
+    }
+}
+
+impl Deref for StyleList {
+    type Target = Vec<Style>;
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl From<Vec<Style>> for StyleList {
+    fn from(list: Vec<Style>) -> Self {
+        Self::new(list)
+    }
+}
+
+impl FromIterator<Style> for StyleList {
+    fn from_iter<T: IntoIterator<Item = Style>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for StyleList {
+    type Item = Style;
+    type IntoIter = <Vec<Style> as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl Diff for StyleList {
+    type Patch = PatchStyleList;
+    fn diff(&self, other: &mut Self) -> Option<Self::Patch> {
+        let kept = self
+            .iter()
+            .filter(|style| other.position(style.name()).is_some())
+            .map(|style| style.name());
+        let moved = other
+            .iter()
+            .filter(|style| self.position(style.name()).is_some())
+            .map(|style| style.name());
+        let reordered = !kept.eq(moved);
+        let mut list = vec![];
+        for style in self.iter() {
+            if reordered || other.position(style.name()).is_none() {
+                list.push(PatchStyleListOp::Remove(style.name().into()));
+            }
+        }
+        for (index, style) in other.iter().enumerate() {
+            if reordered || self.position(style.name()).is_none() {
+                list.push(PatchStyleListOp::Insert(index, style.into()));
+            }
+        }
+        for name in self.variables.keys() {
+            if !other.variables.contains_key(name) {
+                list.push(PatchStyleListOp::RemoveVariable(name.clone()));
+            }
+        }
+        for (name, value) in &other.variables {
+            if self.variables.get(name) != Some(value) {
+                list.push(PatchStyleListOp::SetVariable(name.clone(), value.clone()));
+            }
+        }
+        if list.is_empty() {
+            None
+        } else {
+            Some(list.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::StyleList;
+    use crate::{
+        id, style, Apply, Attribute, AttributeList, Diff, PatchStyleList, PatchStyleListOp,
+        RenderedStyle, RenderedStyleList, Style,
+    };
+
+    fn list(names: &[&str]) -> StyleList {
+        names
+            .iter()
+            .map(|name| Style::Static(name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn dedup() {
+        assert_eq!(list(&["a", "b", "a"]), list(&["a", "b"]));
+    }
+
+    #[test]
+    fn merge() {
+        let attributes: AttributeList<()> = vec![
+            style(Style::Static("button".into())),
+            id("ok".into()),
+            style(list(&["primary", "button"])),
+        ]
+        .into();
+        assert_eq!(
+            *attributes,
+            vec![
+                id("ok".into()),
+                Attribute::Style(list(&["button", "primary"]))
+            ]
+        );
+    }
+
+    #[test]
+    fn per_class() {
+        let old = list(&["button", "primary"]);
+        let mut new = list(&["button", "large", "danger"]);
+        let patch = old.diff(&mut new);
+        assert_eq!(
+            patch,
+            Some(PatchStyleList::from(vec![
+                PatchStyleListOp::Remove("primary".into()),
+                PatchStyleListOp::Insert(1, RenderedStyle("large".into())),
+                PatchStyleListOp::Insert(2, RenderedStyle("danger".into())),
+            ]))
+        );
+        let mut rendered = RenderedStyleList::from(&old);
+        rendered.apply(patch.unwrap()).unwrap();
+        assert_eq!(rendered, RenderedStyleList::from(&new));
+    }
+
+    #[test]
+    fn variables() {
+        let old = list(&["a"]).with_variables(vec![
+            ("a-0".to_string(), "1px".to_string()),
+            ("a-1".to_string(), "red".to_string()),
+        ]);
+        let mut new = list(&["a"]).with_variables(vec![
+            ("a-0".to_string(), "2px".to_string()),
+            ("a-2".to_string(), "0".to_string()),
+        ]);
+        let patch = old.diff(&mut new).unwrap();
+        assert_eq!(
+            patch,
+            PatchStyleList::from(vec![
+                PatchStyleListOp::RemoveVariable("a-1".into()),
+                PatchStyleListOp::SetVariable("a-0".into(), "2px".into()),
+                PatchStyleListOp::SetVariable("a-2".into(), "0".into()),
+            ])
+        );
+        let mut rendered = RenderedStyleList::from(&old);
+        rendered.apply(patch).unwrap();
+        assert_eq!(rendered, RenderedStyleList::from(&new));
+    }
+
+    #[test]
+    fn reorder() {
+        let old = list(&["a", "b", "c"]);
+        let mut new = list(&["c", "a"]);
+        let mut rendered = RenderedStyleList::from(&old);
+        rendered.apply(old.diff(&mut new).unwrap()).unwrap();
+        assert_eq!(rendered, RenderedStyleList::from(&new));
+        assert_eq!(new.diff(&mut list(&["c", "a"])), None);
+    }
+}
@@ -1,12 +1,20 @@
 use crate::util::safe_cast;
 use serde_derive::{Deserialize, Serialize};
-use std::hash::Hash;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
 use sulafat_util::TypeId;
 
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum ClosureId {
     TypeId(TypeId),
     FnPtr(usize),
+    /// A handler of which messages are converted by a [`MsgMap`](crate::MsgMap), keyed
+    /// by both the handler and the conversion.
+    Mapped(u64),
+    /// A handler converting messages for a local state, by the id of the state.
+    Local(u64),
 }
 
 impl ClosureId {
@@ -16,7 +24,7 @@ impl ClosureId {
         Output: 'static,
     {
         let id = TypeId::of::<F>();
-        if let Some(&fn_ptr) = safe_cast::<F, fn(Args) -> Output>(&f) {
+        if let Some(&fn_ptr) = safe_cast::<F, fn(Args) -> Output>(f) {
             ClosureId::FnPtr(fn_ptr as usize)
         } else {
             ClosureId::TypeId(id)
@@ -31,10 +39,32 @@ impl ClosureId {
         Output: 'static,
     {
         let id = TypeId::of::<F>();
-        if let Some(&fn_ptr) = safe_cast::<F, fn(Data, Args) -> Output>(&f) {
+        if let Some(&fn_ptr) = safe_cast::<F, fn(Data, Args) -> Output>(f) {
             ClosureId::FnPtr(fn_ptr as usize)
         } else {
             ClosureId::TypeId(id)
         }
     }
+
+    pub(crate) fn new3<F: 'static + Fn(A, B, C) -> Output, A, B, C, Output>(f: &F) -> Self
+    where
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        Output: 'static,
+    {
+        let id = TypeId::of::<F>();
+        if let Some(&fn_ptr) = safe_cast::<F, fn(A, B, C) -> Output>(f) {
+            ClosureId::FnPtr(fn_ptr as usize)
+        } else {
+            ClosureId::TypeId(id)
+        }
+    }
+
+    /// `inner` told apart by `key`, such as the conversion it is mapped with.
+    pub(crate) fn mapped<K: Hash>(inner: ClosureId, key: &K) -> Self {
+        let mut hasher = DefaultHasher::new();
+        (inner, key).hash(&mut hasher);
+        ClosureId::Mapped(hasher.finish())
+    }
 }
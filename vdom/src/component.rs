@@ -0,0 +1,351 @@
+use crate::{cmd::Cmd, view::View, CachedView, ClosureId, MsgMap, Node, Program};
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+    rc::Rc,
+};
+
+/// The state of a [`Program`] embedded in the model of another program.
+///
+/// The messages of the component are lifted into the parent's by `lift`, which is a
+/// function pointer such as an enum variant, e.g. `Msg::Counter`. Components of a list
+/// are lifted with a key by the `_keyed` methods, e.g. `Msg::Item` of
+/// `Item(usize, CounterMsg)` with the index. The handlers of each instance, which is
+/// kept through updates, are dispatched to it alone.
+///
+/// ```ignore
+/// fn update(model: &Model, msg: &Msg) -> (Model, Cmd<Msg>) {
+///     match msg {
+///         Msg::Counter(msg) => {
+///             let (counter, cmd) = model.counter.update(msg, Msg::Counter);
+///             (Model { counter, ..model.clone() }, cmd)
+///         }
+///     }
+/// }
+///
+/// fn view(model: &Model) -> Node<Msg> {
+///     div().child(model.counter.view(Msg::Counter)).into()
+/// }
+/// ```
+pub struct Component<P: Program> {
+    id: u64,
+    model: Rc<P::Model>,
+}
+
+impl<P: Program> Component<P> {
+    fn new(model: P::Model) -> Self {
+        thread_local! {
+            static NEXT: Cell<u64> = const { Cell::new(0) };
+        }
+        Self {
+            id: NEXT.with(|next| next.replace(next.get() + 1)),
+            model: Rc::new(model),
+        }
+    }
+
+    pub fn init<Msg: 'static>(lift: fn(P::Msg) -> Msg) -> (Self, Cmd<Msg>) {
+        let (model, cmd) = P::init_cmd();
+        (Self::new(model), cmd.map(lift))
+    }
+
+    pub fn init_keyed<K, Msg>(key: K, lift: fn(K, P::Msg) -> Msg) -> (Self, Cmd<Msg>)
+    where
+        K: 'static + Clone,
+        Msg: 'static,
+    {
+        let (model, cmd) = P::init_cmd();
+        (Self::new(model), cmd.map(move |msg| lift(key.clone(), msg)))
+    }
+
+    pub fn update<Msg: 'static>(&self, msg: &P::Msg, lift: fn(P::Msg) -> Msg) -> (Self, Cmd<Msg>) {
+        let (model, cmd) = P::update_cmd(&self.model, msg);
+        (self.with_model(model), cmd.map(lift))
+    }
+
+    pub fn update_keyed<K, Msg>(
+        &self,
+        msg: &P::Msg,
+        key: K,
+        lift: fn(K, P::Msg) -> Msg,
+    ) -> (Self, Cmd<Msg>)
+    where
+        K: 'static + Clone,
+        Msg: 'static,
+    {
+        let (model, cmd) = P::update_cmd(&self.model, msg);
+        (
+            self.with_model(model),
+            cmd.map(move |msg| lift(key.clone(), msg)),
+        )
+    }
+
+    fn with_model(&self, model: P::Model) -> Self {
+        Self {
+            id: self.id,
+            model: Rc::new(model),
+        }
+    }
+
+    /// The subtree is rendered and diffed again only when the model has changed since
+    /// the last view.
+    pub fn view<Msg: 'static>(&self, lift: fn(P::Msg) -> Msg) -> Node<Msg> {
+        let id = ClosureId::mapped(ClosureId::FnPtr(lift as usize), &self.id);
+        self.view_with(MsgMap::with_id(id, lift))
+    }
+
+    pub fn view_keyed<K, Msg>(&self, key: K, lift: fn(K, P::Msg) -> Msg) -> Node<Msg>
+    where
+        K: 'static + Clone + Hash,
+        Msg: 'static,
+    {
+        let id = ClosureId::mapped(ClosureId::FnPtr(lift as usize), &(self.id, &key));
+        self.view_with(MsgMap::with_id(id, move |msg| lift(key.clone(), msg)))
+    }
+
+    fn view_with<Msg: 'static>(&self, lift: MsgMap<P::Msg, Msg>) -> Node<Msg> {
+        Node::CachedView(CachedView::new(
+            None,
+            ComponentView::<P, Msg> {
+                model: self.model.clone(),
+                lift,
+            },
+        ))
+    }
+
+    pub fn model(&self) -> &P::Model {
+        &self.model
+    }
+}
+
+impl<P: Program> Clone for Component<P> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            model: self.model.clone(),
+        }
+    }
+}
+
+impl<P: Program> PartialEq for Component<P> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.model, &other.model) || self.model == other.model
+    }
+}
+
+impl<P: Program> Debug for Component<P>
+where
+    P::Model: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Component").field(&self.model).finish()
+    }
+}
+
+struct ComponentView<P: Program, Msg> {
+    model: Rc<P::Model>,
+    lift: MsgMap<P::Msg, Msg>,
+}
+
+impl<P: Program, Msg: 'static> View<Msg> for ComponentView<P, Msg> {
+    fn render(&self) -> Node<Msg> {
+        P::view(&self.model).map_with(&self.lift)
+    }
+
+    fn is_same(&self, other: &dyn View<Msg>) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() {
+            self.lift.is_same(&other.lift)
+                && (Rc::ptr_eq(&self.model, &other.model) || self.model == other.model)
+        } else {
+            false
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Component;
+    use crate::{on_click, Apply, Common, Diff, Div, Handler, Node, Program, RenderedNode};
+    use std::{cell::Cell, collections::HashMap};
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn calls() -> usize {
+        CALLS.with(|calls| calls.replace(0))
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum CounterMsg {
+        Increment,
+    }
+
+    struct Counter;
+
+    impl Program for Counter {
+        type Model = u32;
+        type Msg = CounterMsg;
+        fn init() -> Self::Model {
+            0
+        }
+        fn update(model: &Self::Model, msg: &Self::Msg) -> Self::Model {
+            match msg {
+                CounterMsg::Increment => model + 1,
+            }
+        }
+        fn view(model: &Self::Model) -> Node<Self::Msg> {
+            CALLS.with(|calls| calls.set(calls.get() + 1));
+            Div::new(Common::new(
+                None,
+                vec![on_click(|_| CounterMsg::Increment)].into(),
+                vec![model.to_string().into()].into(),
+            ))
+            .into()
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Left(CounterMsg),
+        Right(CounterMsg),
+        Item(usize, CounterMsg),
+    }
+
+    fn invoke_all(node: &mut Node<Msg>) -> Vec<Msg> {
+        node.full_render();
+        let mut handlers = HashMap::new();
+        node.pick_handler(&mut handlers);
+        handlers
+            .values()
+            .map(|handler| {
+                let handler = handler.upgrade().unwrap();
+                handler.downcast::<Handler<(), Msg>>().unwrap().invoke(())
+            })
+            .collect()
+    }
+
+    fn view(left: &Component<Counter>, right: &Component<Counter>) -> Node<Msg> {
+        Div::new(Common::new(
+            None,
+            vec![].into(),
+            vec![left.view(Msg::Left), right.view(Msg::Right)].into(),
+        ))
+        .into()
+    }
+
+    #[test]
+    fn lift_messages() {
+        let (left, _) = Component::<Counter>::init(Msg::Left);
+        let mut node = left.view(Msg::Left);
+        node.full_render();
+        let mut handlers = HashMap::new();
+        node.pick_handler(&mut handlers);
+        let handler = handlers.values().next().unwrap().upgrade().unwrap();
+        let handler = handler.downcast::<Handler<(), Msg>>().unwrap();
+        assert_eq!(handler.invoke(()), Msg::Left(CounterMsg::Increment));
+    }
+
+    #[test]
+    fn dispatch_to_each_instance() {
+        let (left, _) = Component::<Counter>::init(Msg::Left);
+        let (right, _) = Component::<Counter>::init(Msg::Right);
+        let mut node = view(&left, &right);
+        node.full_render();
+        let mut handlers = HashMap::new();
+        node.pick_handler(&mut handlers);
+        assert_eq!(handlers.len(), 2);
+        let mut msgs = handlers
+            .values()
+            .map(|handler| {
+                let handler = handler.upgrade().unwrap();
+                handler.downcast::<Handler<(), Msg>>().unwrap().invoke(())
+            })
+            .collect::<Vec<_>>();
+        msgs.sort_by_key(|msg| matches!(msg, Msg::Right(_)));
+        assert_eq!(
+            msgs,
+            vec![
+                Msg::Left(CounterMsg::Increment),
+                Msg::Right(CounterMsg::Increment)
+            ]
+        );
+    }
+
+    #[test]
+    fn dispatch_to_each_instance_lifted_alike() {
+        let (first, _) = Component::<Counter>::init(Msg::Left);
+        let (second, _) = Component::<Counter>::init(Msg::Left);
+        let mut node = Div::new(Common::new(
+            None,
+            vec![].into(),
+            vec![first.view(Msg::Left), second.view(Msg::Left)].into(),
+        ))
+        .into();
+        assert_eq!(invoke_all(&mut node).len(), 2);
+
+        let (second, _) = second.update(&CounterMsg::Increment, Msg::Left);
+        let mut updated = Div::new(Common::new(
+            None,
+            vec![].into(),
+            vec![first.view(Msg::Left), second.view(Msg::Left)].into(),
+        ))
+        .into();
+        assert_eq!(invoke_all(&mut updated).len(), 2);
+    }
+
+    #[test]
+    fn dispatch_keyed() {
+        let items = (0..2)
+            .map(|i| Component::<Counter>::init_keyed(i, Msg::Item).0)
+            .collect::<Vec<_>>();
+        let mut node = Div::new(Common::new(
+            None,
+            vec![].into(),
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| item.view_keyed(i, Msg::Item))
+                .collect::<Vec<_>>()
+                .into(),
+        ))
+        .into();
+        let mut msgs = invoke_all(&mut node);
+        msgs.sort_by_key(|msg| matches!(msg, Msg::Item(1, _)));
+        assert_eq!(
+            msgs,
+            vec![
+                Msg::Item(0, CounterMsg::Increment),
+                Msg::Item(1, CounterMsg::Increment)
+            ]
+        );
+        let (item, _) = items[1].update_keyed(&CounterMsg::Increment, 1, Msg::Item);
+        assert_eq!(*item.model(), 1);
+    }
+
+    #[test]
+    fn diff_changed_component_only() {
+        let (left, _) = Component::<Counter>::init(Msg::Left);
+        let (right, _) = Component::<Counter>::init(Msg::Right);
+        let mut old = view(&left, &right);
+        old.full_render();
+        assert_eq!(calls(), 2);
+        let mut same = view(&left, &right);
+        assert_eq!(old.diff(&mut same), None);
+        assert_eq!(calls(), 0);
+
+        let (left, _) = left.update(&CounterMsg::Increment, Msg::Left);
+        assert_eq!(*left.model(), 1);
+        let mut new = view(&left, &right);
+        let patch = same.diff(&mut new).unwrap();
+        assert_eq!(calls(), 1);
+        let mut rendered = RenderedNode::from(&same);
+        rendered.apply(patch).unwrap();
+        assert_eq!(rendered, RenderedNode::from(&new));
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{lazy, view::View, CachedView, Node};
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    rc::Rc,
+};
+
+/// A value provided to the descendants of a node.
+pub trait ContextValue: Any {
+    fn as_any(&self) -> &dyn Any;
+    fn into_any(self: Rc<Self>) -> Rc<dyn Any>;
+    fn is_same(&self, other: &dyn ContextValue) -> bool;
+}
+
+impl<T: 'static + PartialEq> ContextValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Rc<Self>) -> Rc<dyn Any> {
+        self
+    }
+
+    fn is_same(&self, other: &dyn ContextValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+struct Provided {
+    value: Rc<dyn ContextValue>,
+    changed: bool,
+}
+
+/// A value read by a view while it was rendered.
+#[derive(Clone)]
+pub(crate) struct Read {
+    type_id: TypeId,
+    value: Option<Rc<dyn ContextValue>>,
+}
+
+thread_local! {
+    static PROVIDED: RefCell<Vec<Provided>> = const { RefCell::new(vec![]) };
+    static READS: RefCell<Vec<Vec<Read>>> = const { RefCell::new(vec![]) };
+}
+
+fn find(type_id: TypeId) -> Option<Rc<dyn ContextValue>> {
+    PROVIDED.with(|provided| {
+        provided
+            .borrow()
+            .iter()
+            .rev()
+            .find(|provided| provided.value.as_any().type_id() == type_id)
+            .map(|provided| provided.value.clone())
+    })
+}
+
+pub(crate) struct Guard(bool);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if self.0 {
+            PROVIDED.with(|provided| provided.borrow_mut().pop());
+        }
+    }
+}
+
+/// Provides `value` until the guard is dropped. `old` is the value provided at the same
+/// place in the previous tree.
+pub(crate) fn enter(
+    value: Option<Rc<dyn ContextValue>>,
+    old: Option<Rc<dyn ContextValue>>,
+) -> Guard {
+    if let Some(value) = value {
+        let changed = !old.is_some_and(|old| Rc::ptr_eq(&old, &value) || old.is_same(&*value));
+        PROVIDED.with(|provided| provided.borrow_mut().push(Provided { value, changed }));
+        Guard(true)
+    } else {
+        Guard(false)
+    }
+}
+
+/// Whether a value provided to the current node differs from the previous tree.
+pub(crate) fn is_changed() -> bool {
+    PROVIDED.with(|provided| provided.borrow().iter().any(|provided| provided.changed))
+}
+
+/// Calls `f`, collecting the values read in it.
+pub(crate) fn record<R>(f: impl FnOnce() -> R) -> (R, Vec<Read>) {
+    READS.with(|reads| reads.borrow_mut().push(vec![]));
+    let result = f();
+    let reads = READS.with(|reads| reads.borrow_mut().pop().unwrap());
+    (result, reads)
+}
+
+/// Whether one of the values in `reads` is not provided now.
+pub(crate) fn is_changed_read(reads: &[Read]) -> bool {
+    reads
+        .iter()
+        .any(|read| match (find(read.type_id), &read.value) {
+            (Some(value), Some(read)) => !(Rc::ptr_eq(&value, read) || value.is_same(&**read)),
+            (None, None) => false,
+            _ => true,
+        })
+}
+
+/// The value of type `T` provided by the nearest ancestor, if any.
+///
+/// It is found only while a view below the provider is rendered: the view function given
+/// to [`provide`], the functions it calls, and [`CachedView`]s such as [`lazy`] further
+/// below. The view is rendered again when the value it read changes.
+pub fn context<T: 'static>() -> Option<Rc<T>> {
+    let type_id = TypeId::of::<T>();
+    let value = find(type_id);
+    READS.with(|reads| {
+        if let Some(reads) = reads.borrow_mut().last_mut() {
+            reads.push(Read {
+                type_id,
+                value: value.clone(),
+            });
+        }
+    });
+    Some(value?.into_any().downcast().unwrap())
+}
+
+struct ProvideView<Msg> {
+    value: Rc<dyn ContextValue>,
+    child: Node<Msg>,
+}
+
+impl<Msg: 'static> View<Msg> for ProvideView<Msg> {
+    fn render(&self) -> Node<Msg> {
+        self.child.clone()
+    }
+
+    fn is_same(&self, other: &dyn View<Msg>) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() {
+            self.value.is_same(&*other.value) && self.child == other.child
+        } else {
+            false
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn provided(&self) -> Option<Rc<dyn ContextValue>> {
+        Some(self.value.clone())
+    }
+}
+
+/// Provides `value` to the view rendered by `f` from `a`, which reads it by [`context`].
+///
+/// `f` is called below the provider as a [`lazy`] view, so that plain view functions
+/// building the child can read the value too.
+///
+/// ```ignore
+/// provide(Locale("ja"), page, model.page.clone())
+/// ```
+pub fn provide<T, A, Msg, F>(value: T, f: F, a: A) -> Node<Msg>
+where
+    T: 'static + PartialEq,
+    F: 'static + Fn(&A) -> Node<Msg>,
+    A: 'static + PartialEq,
+    Msg: 'static,
+{
+    Node::CachedView(CachedView::new(
+        None,
+        ProvideView {
+            value: Rc::new(value),
+            child: lazy(f, a).into(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{context, provide};
+    use crate::{lazy, Apply, Common, Diff, Div, Node, RenderedNode};
+    use std::cell::Cell;
+
+    thread_local! {
+        static READER: Cell<usize> = const { Cell::new(0) };
+        static WRAPPER: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn calls() -> (usize, usize) {
+        (
+            READER.with(|calls| calls.replace(0)),
+            WRAPPER.with(|calls| calls.replace(0)),
+        )
+    }
+
+    #[derive(PartialEq)]
+    struct Locale(&'static str);
+
+    fn reader(n: &u32) -> Node<()> {
+        READER.with(|calls| calls.set(calls.get() + 1));
+        let locale = context::<Locale>().map_or("none", |locale| locale.0);
+        format!("{}{}", locale, n).into()
+    }
+
+    fn wrapper(n: &u32) -> Node<()> {
+        WRAPPER.with(|calls| calls.set(calls.get() + 1));
+        Div::new(Common::new(
+            None,
+            vec![].into(),
+            vec![lazy(reader, *n).into()].into(),
+        ))
+        .into()
+    }
+
+    fn children(_: &()) -> Node<()> {
+        vec![lazy(wrapper, 1).into(), lazy(reader, 2).into()].into()
+    }
+
+    fn view(locale: &'static str) -> Node<()> {
+        provide(Locale(locale), children, ())
+    }
+
+    fn page(n: &u32) -> Node<()> {
+        Div::new(Common::new(None, vec![].into(), vec![reader(n)].into())).into()
+    }
+
+    fn check(old: &Node<()>, new: &mut Node<()>) -> bool {
+        let patch = old.diff(new);
+        let mut rendered = RenderedNode::from(old);
+        if let Some(patch) = patch.clone() {
+            rendered.apply(patch).unwrap();
+        }
+        assert_eq!(rendered, RenderedNode::from(&*new));
+        patch.is_some()
+    }
+
+    #[test]
+    fn read_provided() {
+        let mut node = view("en");
+        node.full_render();
+        assert_eq!(
+            RenderedNode::from(&node),
+            RenderedNode::from(&Node::<()>::from(vec![
+                Div::new(Common::new(None, vec![].into(), vec!["en1".into()].into())).into(),
+                "en2".into(),
+            ]))
+        );
+        let mut outside: Node<()> = lazy(reader, 3).into();
+        outside.full_render();
+        assert_eq!(
+            RenderedNode::from(&outside),
+            RenderedNode::from(&Node::<()>::from("none3"))
+        );
+    }
+
+    #[test]
+    fn read_in_plain_view() {
+        let mut old = provide(Locale("en"), page, 1);
+        old.full_render();
+        assert_eq!(
+            RenderedNode::from(&old),
+            RenderedNode::from(&Node::<()>::from(Div::new(Common::new(
+                None,
+                vec![].into(),
+                vec!["en1".into()].into()
+            ))))
+        );
+        assert_eq!(calls(), (1, 0));
+        let mut same = provide(Locale("en"), page, 1);
+        assert!(!check(&old, &mut same));
+        assert_eq!(calls(), (0, 0));
+        let mut changed = provide(Locale("ja"), page, 1);
+        assert!(check(&same, &mut changed));
+        assert_eq!(calls(), (1, 0));
+    }
+
+    #[test]
+    fn invalidate_readers_only() {
+        let mut old = view("en");
+        old.full_render();
+        assert_eq!(calls(), (2, 1));
+        let mut same = view("en");
+        assert!(!check(&old, &mut same));
+        assert_eq!(calls(), (0, 0));
+        let mut changed = view("ja");
+        assert!(check(&same, &mut changed));
+        assert_eq!(calls(), (2, 0));
+        let mut back = view("en");
+        assert!(check(&changed, &mut back));
+        assert_eq!(calls(), (2, 0));
+    }
+}
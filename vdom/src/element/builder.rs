@@ -0,0 +1,207 @@
+use std::marker::PhantomData;
+
+use crate::{
+    id, on_click, on_pointer_move, style, theme, Attribute, Common, Div, List, Node, Rp, Rt, Ruby,
+    Span, ToStyle,
+};
+use sulafat_style::Theme;
+
+/// Builds an element in method chains, e.g.
+/// `div().id("x").on_click(|_| Msg::A).child("text").into()`.
+///
+/// The attributes and children are collected as they would be for [`Common::new`], so
+/// the result is the same as building `Common` directly.
+#[must_use]
+pub struct ElementBuilder<Msg, E> {
+    key: Option<String>,
+    attributes: Vec<Attribute<Msg>>,
+    children: List<Msg>,
+    element: PhantomData<fn(Common<Msg>) -> E>,
+}
+
+impl<Msg, E> ElementBuilder<Msg, E>
+where
+    E: From<Common<Msg>>,
+{
+    pub fn new() -> Self {
+        Self {
+            key: None,
+            attributes: vec![],
+            children: vec![].into(),
+            element: PhantomData,
+        }
+    }
+
+    pub fn key<K: Into<String>>(mut self, key: K) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn attribute(mut self, attribute: Attribute<Msg>) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    pub fn id<I: Into<String>>(self, i: I) -> Self {
+        self.attribute(id(i.into()))
+    }
+
+    pub fn on_click<F>(self, f: F) -> Self
+    where
+        F: 'static + Fn(()) -> Msg,
+        Msg: 'static,
+    {
+        self.attribute(on_click(f))
+    }
+
+    pub fn on_pointer_move<F>(self, f: F) -> Self
+    where
+        F: 'static + Fn((f64, f64)) -> Msg,
+        Msg: 'static,
+    {
+        self.attribute(on_pointer_move(f))
+    }
+
+    /// Can be called more than once; the classes are merged.
+    pub fn style<S: ToStyle>(self, s: S) -> Self {
+        self.attribute(style(s))
+    }
+
+    pub fn theme<T: Theme>(self, t: T) -> Self {
+        self.attribute(theme(t))
+    }
+
+    /// `None` leaves an empty placeholder, so that the following children keep their
+    /// positions when it becomes `Some`.
+    pub fn child<C: Into<Node<Msg>>>(mut self, child: C) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Appends each item as a child of its own, not as a nested list.
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Node<Msg>>,
+    {
+        for child in children {
+            self.children.push(child.into());
+        }
+        self
+    }
+
+    pub fn build(self) -> E {
+        E::from(Common::new(self.key, self.attributes.into(), self.children))
+    }
+}
+
+impl<Msg, E> Default for ElementBuilder<Msg, E>
+where
+    E: From<Common<Msg>>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Msg, E> From<ElementBuilder<Msg, E>> for Node<Msg>
+where
+    E: From<Common<Msg>> + Into<Node<Msg>>,
+{
+    fn from(builder: ElementBuilder<Msg, E>) -> Self {
+        builder.build().into()
+    }
+}
+
+pub fn div<Msg>() -> ElementBuilder<Msg, Div<Msg>> {
+    ElementBuilder::new()
+}
+
+pub fn span<Msg>() -> ElementBuilder<Msg, Span<Msg>> {
+    ElementBuilder::new()
+}
+
+pub fn ruby<Msg>() -> ElementBuilder<Msg, Ruby<Msg>> {
+    ElementBuilder::new()
+}
+
+pub fn rt<Msg>() -> ElementBuilder<Msg, Rt<Msg>> {
+    ElementBuilder::new()
+}
+
+pub fn rp<Msg>() -> ElementBuilder<Msg, Rp<Msg>> {
+    ElementBuilder::new()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{div, rt, ruby, span};
+    use crate::{id, on_click, style, Common, Div, List, Node, Rt, Ruby, Span};
+    use sulafat_macros::StyleSet;
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .builder_item {
+            left: 10px;
+        }
+    }]
+    struct Item;
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        A,
+    }
+
+    fn handler(_: ()) -> Msg {
+        Msg::A
+    }
+
+    #[test]
+    fn same_as_common() {
+        let built: Node<Msg> = div()
+            .id("x")
+            .key("k")
+            .on_click(handler)
+            .style(Item)
+            .child("text")
+            .child(span().child(String::from("inner")))
+            .into();
+        let direct: Node<Msg> = Div::new(Common::new(
+            Some("k".into()),
+            vec![style(Item), id("x".into()), on_click(handler)].into(),
+            vec![
+                "text".into(),
+                Span::new(Common::new(
+                    None,
+                    vec![].into(),
+                    vec![String::from("inner").into()].into(),
+                ))
+                .into(),
+            ]
+            .into(),
+        ))
+        .into();
+        assert_eq!(built, direct);
+    }
+
+    #[test]
+    fn optional_and_iterator_children() {
+        let built: Ruby<()> = ruby()
+            .child(Some("漢"))
+            .child(None::<Node<()>>)
+            .children((0..2).map(|i| rt().child(i.to_string())))
+            .build();
+        let direct = Ruby::new(Common::new(
+            None,
+            vec![].into(),
+            vec![
+                "漢".into(),
+                List::from(vec![]).into(),
+                Rt::new(Common::new(None, vec![].into(), vec!["0".into()].into())).into(),
+                Rt::new(Common::new(None, vec![].into(), vec!["1".into()].into())).into(),
+            ]
+            .into(),
+        ));
+        assert_eq!(built, direct);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{AttributeList, Diff, List, PatchAttributeList, PatchCommon};
+use crate::{AttributeList, Diff, List, MsgMap, PatchCommon};
 use sulafat_macros::{Clone, PartialEq, Serialize};
 
 #[derive(Default, Clone, Debug, PartialEq, Serialize)]
@@ -21,6 +21,18 @@ impl<Msg> Common<Msg> {
             children,
         }
     }
+
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> Common<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        Common {
+            key: self.key,
+            attribute_list: self.attribute_list.map(map),
+            children: self.children.map(map),
+        }
+    }
 }
 
 impl<Msg> Diff for Common<Msg> {
@@ -32,7 +44,7 @@ impl<Msg> Diff for Common<Msg> {
         ) {
             (None, None) => None,
             (attribute_list, children) => Some(PatchCommon {
-                attribute_list: attribute_list.unwrap_or_else(|| PatchAttributeList::default()),
+                attribute_list: attribute_list.unwrap_or_default(),
                 children,
             }),
         }
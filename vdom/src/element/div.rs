@@ -20,6 +20,12 @@ impl<Msg> Div<Msg> {
     }
 }
 
+impl<Msg> From<Common<Msg>> for Div<Msg> {
+    fn from(common: Common<Msg>) -> Self {
+        Self::new(common)
+    }
+}
+
 impl<Msg> From<Div<Msg>> for Element<Msg> {
     fn from(div: Div<Msg>) -> Self {
         Element::Div(div)
@@ -52,9 +58,11 @@ impl<Msg> Eq for Div<Msg> {}
 #[cfg(test)]
 mod test {
     use crate::{
-        element::rendered::RenderedDiv, id, Apply, Common, Diff, Div, PatchAttributeListOp,
-        PatchCommon, PatchDiv, RenderedAttribute,
+        element::rendered::RenderedDiv, id, style, theme, Apply, Common, Diff, Div,
+        PatchAttributeListOp, PatchCommon, PatchDiv, PatchStyleListOp, RenderedAttribute,
     };
+    use sulafat_macros::{StyleSet, Theme};
+    use sulafat_style::Color;
     #[test]
     fn same() {
         let div1 = Div::<()>::default();
@@ -93,4 +101,64 @@ mod test {
         rendered_div1.apply(patch.unwrap()).unwrap();
         assert_eq!(rendered_div1, rendered_div2);
     }
+
+    #[derive(Theme)]
+    struct Palette {
+        primary: Color,
+        on_primary: Color,
+    }
+
+    #[derive(StyleSet)]
+    #[style_set{
+        .themed_button {
+            color: var(--on-primary);
+            background-color: var(--primary);
+        }
+    }]
+    struct Button;
+
+    fn themed(palette: Palette) -> Div<()> {
+        let button = Div::new(Common::new(
+            None,
+            vec![style(Button)].into(),
+            Default::default(),
+        ));
+        Div::new(Common::new(
+            None,
+            vec![theme(palette)].into(),
+            vec![button.into()].into(),
+        ))
+    }
+
+    #[test]
+    fn switch_theme() {
+        let light = themed(Palette {
+            primary: Color::rgb(0, 0, 255),
+            on_primary: Color::rgb(255, 255, 255),
+        });
+        let mut dark = themed(Palette {
+            primary: Color::rgb(128, 128, 255),
+            on_primary: Color::rgb(255, 255, 255),
+        });
+        let patch = light.diff(&mut dark);
+        assert_eq!(
+            patch,
+            Some(PatchDiv {
+                common: PatchCommon {
+                    attribute_list: vec![PatchAttributeListOp::Style(
+                        vec![PatchStyleListOp::SetVariable(
+                            "primary".into(),
+                            "#8080ff".into()
+                        )]
+                        .into()
+                    )]
+                    .into(),
+                    children: Default::default()
+                }
+            })
+        );
+        let mut rendered_light = RenderedDiv::from(&light);
+        rendered_light.apply(patch.unwrap()).unwrap();
+        assert_eq!(rendered_light, RenderedDiv::from(&dark));
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    Attribute, ClosureId, Common, Diff, Div, List, Node, PatchElement, Single, Span, VariantIdent,
+    Attribute, ClosureId, Common, Diff, Div, List, MsgMap, Node, PatchElement, Rp, Rt, Ruby,
+    Single, Span, StyleRegistry, VariantIdent,
 };
 
 use std::{any::Any, collections::HashMap, rc::Weak};
@@ -9,6 +10,9 @@ use sulafat_macros::{Clone, PartialEq, Serialize, VariantIdent};
 pub enum Element<Msg> {
     Div(Div<Msg>),
     Span(Span<Msg>),
+    Ruby(Ruby<Msg>),
+    Rt(Rt<Msg>),
+    Rp(Rp<Msg>),
 }
 
 impl<Msg> Element<Msg> {
@@ -16,12 +20,18 @@ impl<Msg> Element<Msg> {
         match self {
             Element::Div(div) => div.common(),
             Element::Span(span) => span.common(),
+            Element::Ruby(ruby) => ruby.common(),
+            Element::Rt(rt) => rt.common(),
+            Element::Rp(rp) => rp.common(),
         }
     }
     fn common_mut(&mut self) -> &mut Common<Msg> {
         match self {
             Element::Div(div) => div.common_mut(),
             Element::Span(span) => span.common_mut(),
+            Element::Ruby(ruby) => ruby.common_mut(),
+            Element::Rt(rt) => rt.common_mut(),
+            Element::Rp(rp) => rp.common_mut(),
         }
     }
 
@@ -61,6 +71,20 @@ impl<Msg> Element<Msg> {
         self.common_mut().children.full_render()
     }
 
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> Element<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        match self {
+            Element::Div(div) => Div::new(div.common.map(map)).into(),
+            Element::Span(span) => Span::new(span.common.map(map)).into(),
+            Element::Ruby(ruby) => Ruby::new(ruby.common.map(map)).into(),
+            Element::Rt(rt) => Rt::new(rt.common.map(map)).into(),
+            Element::Rp(rp) => Rp::new(rp.common.map(map)).into(),
+        }
+    }
+
     pub(crate) fn pick_handler(&self, handlers: &mut HashMap<ClosureId, Weak<dyn Any>>)
     where
         Msg: 'static,
@@ -70,6 +94,13 @@ impl<Msg> Element<Msg> {
         }
         self.common().children.pick_handler(handlers)
     }
+
+    pub(crate) fn pick_style(&self, registry: &mut StyleRegistry) {
+        for attr in self.common().attribute_list.iter() {
+            attr.pick_style(registry)
+        }
+        self.common().children.pick_style(registry)
+    }
 }
 
 impl<Msg> From<Element<Msg>> for Single<Msg> {
@@ -88,15 +119,20 @@ impl<Msg> Diff for Element<Msg> {
     type Patch = PatchElement;
     fn diff(&self, other: &mut Self) -> Option<Self::Patch> {
         if self.key() != other.key() {
+            other.full_render();
             return Some(PatchElement::Replace((&*other).into()));
         }
         Some(match (self, other) {
             (Element::Div(div1), Element::Div(div2)) => div1.diff(div2)?.into(),
             (Element::Span(div1), Element::Span(div2)) => div1.diff(div2)?.into(),
+            (Element::Ruby(ruby1), Element::Ruby(ruby2)) => ruby1.diff(ruby2)?.into(),
+            (Element::Rt(rt1), Element::Rt(rt2)) => rt1.diff(rt2)?.into(),
+            (Element::Rp(rp1), Element::Rp(rp2)) => rp1.diff(rp2)?.into(),
             (_, other) => {
                 if self.variant_ident() == other.variant_ident() {
                     unreachable!()
                 }
+                other.full_render();
                 PatchElement::Replace((&*other).into())
             }
         })
@@ -1,13 +1,21 @@
+mod builder;
 mod common;
 mod div;
 mod element;
 mod rendered;
+mod rp;
+mod rt;
+mod ruby;
 mod span;
+pub use builder::{div, rp, rt, ruby, span, ElementBuilder};
 pub use common::Common;
 pub use div::Div;
 pub use element::Element;
 pub use rendered::{
-    PatchCommon, PatchDiv, PatchElement, PatchSpan, RenderedCommon, RenderedDiv, RenderedElement,
-    RenderedSpan,
+    PatchCommon, PatchDiv, PatchElement, PatchRp, PatchRt, PatchRuby, PatchSpan, RenderedCommon,
+    RenderedDiv, RenderedElement, RenderedRp, RenderedRt, RenderedRuby, RenderedSpan,
 };
+pub use rp::Rp;
+pub use rt::Rt;
+pub use ruby::Ruby;
 pub use span::Span;
@@ -1,17 +1,20 @@
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{
-    single::RenderedSingle, Apply, ApplyResult, Element, PatchDiv, PatchNode, PatchSingle,
-    PatchSpan,
+    single::RenderedSingle, Apply, ApplyResult, Element, PatchDiv, PatchNode, PatchRp, PatchRt,
+    PatchRuby, PatchSingle, PatchSpan,
 };
 
-use super::{RenderedDiv, RenderedSpan};
+use super::{RenderedDiv, RenderedRp, RenderedRt, RenderedRuby, RenderedSpan};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename = "Element")]
 pub enum RenderedElement {
     Div(RenderedDiv),
     Span(RenderedSpan),
+    Ruby(RenderedRuby),
+    Rt(RenderedRt),
+    Rp(RenderedRp),
 }
 
 impl From<RenderedElement> for RenderedSingle {
@@ -31,7 +34,7 @@ impl<Msg> From<&Element<Msg>> for RenderedElement {
                 }
             };
         }
-        from_element!(Div, Span)
+        from_element!(Div, Span, Ruby, Rt, Rp)
     }
 }
 
@@ -53,7 +56,7 @@ impl Apply for RenderedElement {
                 }
             };
         }
-        apply_element!(Div, Span);
+        apply_element!(Div, Span, Ruby, Rt, Rp);
         Err("異なる要素です".into())
     }
 }
@@ -63,6 +66,9 @@ pub enum PatchElement {
     Replace(RenderedElement),
     Div(PatchDiv),
     Span(PatchSpan),
+    Ruby(PatchRuby),
+    Rt(PatchRt),
+    Rp(PatchRp),
 }
 
 impl From<PatchElement> for PatchSingle {
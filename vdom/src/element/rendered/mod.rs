@@ -1,9 +1,15 @@
 mod common;
 mod div;
 mod element;
+mod rp;
+mod rt;
+mod ruby;
 mod span;
 
 pub use common::{PatchCommon, RenderedCommon};
 pub use div::{PatchDiv, RenderedDiv};
 pub use element::{PatchElement, RenderedElement};
+pub use rp::{PatchRp, RenderedRp};
+pub use rt::{PatchRt, RenderedRt};
+pub use ruby::{PatchRuby, RenderedRuby};
 pub use span::{PatchSpan, RenderedSpan};
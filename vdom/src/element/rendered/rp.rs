@@ -0,0 +1,76 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    single::RenderedSingle, Apply, ApplyResult, PatchCommon, PatchElement, PatchNode, PatchSingle,
+    RenderedElement, RenderedNode, Rp,
+};
+
+use super::RenderedCommon;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "Rp")]
+pub struct RenderedRp {
+    pub(crate) common: RenderedCommon,
+}
+
+impl RenderedRp {
+    pub fn new(common: RenderedCommon) -> Self {
+        Self { common }
+    }
+}
+
+impl From<RenderedRp> for RenderedElement {
+    fn from(rp: RenderedRp) -> Self {
+        RenderedElement::Rp(rp)
+    }
+}
+
+impl From<RenderedRp> for RenderedSingle {
+    fn from(rp: RenderedRp) -> Self {
+        RenderedSingle::Element(rp.into())
+    }
+}
+
+impl From<RenderedRp> for RenderedNode {
+    fn from(rp: RenderedRp) -> Self {
+        RenderedNode::Single(rp.into())
+    }
+}
+
+impl<Msg> From<&Rp<Msg>> for RenderedRp {
+    fn from(rp: &Rp<Msg>) -> Self {
+        Self {
+            common: (&rp.common).into(),
+        }
+    }
+}
+
+impl Apply for RenderedRp {
+    type Patch = PatchRp;
+    fn apply(&mut self, patch: Self::Patch) -> ApplyResult {
+        self.common.apply(patch.common)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchRp {
+    pub(crate) common: PatchCommon,
+}
+
+impl From<PatchRp> for PatchElement {
+    fn from(patch: PatchRp) -> Self {
+        PatchElement::Rp(patch)
+    }
+}
+
+impl From<PatchRp> for PatchSingle {
+    fn from(patch: PatchRp) -> Self {
+        PatchElement::from(patch).into()
+    }
+}
+
+impl From<PatchRp> for PatchNode {
+    fn from(patch: PatchRp) -> Self {
+        PatchSingle::from(patch).into()
+    }
+}
@@ -0,0 +1,76 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    single::RenderedSingle, Apply, ApplyResult, PatchCommon, PatchElement, PatchNode, PatchSingle,
+    RenderedElement, RenderedNode, Rt,
+};
+
+use super::RenderedCommon;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "Rt")]
+pub struct RenderedRt {
+    pub(crate) common: RenderedCommon,
+}
+
+impl RenderedRt {
+    pub fn new(common: RenderedCommon) -> Self {
+        Self { common }
+    }
+}
+
+impl From<RenderedRt> for RenderedElement {
+    fn from(rt: RenderedRt) -> Self {
+        RenderedElement::Rt(rt)
+    }
+}
+
+impl From<RenderedRt> for RenderedSingle {
+    fn from(rt: RenderedRt) -> Self {
+        RenderedSingle::Element(rt.into())
+    }
+}
+
+impl From<RenderedRt> for RenderedNode {
+    fn from(rt: RenderedRt) -> Self {
+        RenderedNode::Single(rt.into())
+    }
+}
+
+impl<Msg> From<&Rt<Msg>> for RenderedRt {
+    fn from(rt: &Rt<Msg>) -> Self {
+        Self {
+            common: (&rt.common).into(),
+        }
+    }
+}
+
+impl Apply for RenderedRt {
+    type Patch = PatchRt;
+    fn apply(&mut self, patch: Self::Patch) -> ApplyResult {
+        self.common.apply(patch.common)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchRt {
+    pub(crate) common: PatchCommon,
+}
+
+impl From<PatchRt> for PatchElement {
+    fn from(patch: PatchRt) -> Self {
+        PatchElement::Rt(patch)
+    }
+}
+
+impl From<PatchRt> for PatchSingle {
+    fn from(patch: PatchRt) -> Self {
+        PatchElement::from(patch).into()
+    }
+}
+
+impl From<PatchRt> for PatchNode {
+    fn from(patch: PatchRt) -> Self {
+        PatchSingle::from(patch).into()
+    }
+}
@@ -0,0 +1,76 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    single::RenderedSingle, Apply, ApplyResult, PatchCommon, PatchElement, PatchNode, PatchSingle,
+    RenderedElement, RenderedNode, Ruby,
+};
+
+use super::RenderedCommon;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "Ruby")]
+pub struct RenderedRuby {
+    pub(crate) common: RenderedCommon,
+}
+
+impl RenderedRuby {
+    pub fn new(common: RenderedCommon) -> Self {
+        Self { common }
+    }
+}
+
+impl From<RenderedRuby> for RenderedElement {
+    fn from(ruby: RenderedRuby) -> Self {
+        RenderedElement::Ruby(ruby)
+    }
+}
+
+impl From<RenderedRuby> for RenderedSingle {
+    fn from(ruby: RenderedRuby) -> Self {
+        RenderedSingle::Element(ruby.into())
+    }
+}
+
+impl From<RenderedRuby> for RenderedNode {
+    fn from(ruby: RenderedRuby) -> Self {
+        RenderedNode::Single(ruby.into())
+    }
+}
+
+impl<Msg> From<&Ruby<Msg>> for RenderedRuby {
+    fn from(ruby: &Ruby<Msg>) -> Self {
+        Self {
+            common: (&ruby.common).into(),
+        }
+    }
+}
+
+impl Apply for RenderedRuby {
+    type Patch = PatchRuby;
+    fn apply(&mut self, patch: Self::Patch) -> ApplyResult {
+        self.common.apply(patch.common)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchRuby {
+    pub(crate) common: PatchCommon,
+}
+
+impl From<PatchRuby> for PatchElement {
+    fn from(patch: PatchRuby) -> Self {
+        PatchElement::Ruby(patch)
+    }
+}
+
+impl From<PatchRuby> for PatchSingle {
+    fn from(patch: PatchRuby) -> Self {
+        PatchElement::from(patch).into()
+    }
+}
+
+impl From<PatchRuby> for PatchNode {
+    fn from(patch: PatchRuby) -> Self {
+        PatchSingle::from(patch).into()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Common, Diff, Element, Node, PatchRp, Single};
+use sulafat_macros::{Clone, PartialEq, Serialize};
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize)]
+pub struct Rp<Msg> {
+    pub(crate) common: Common<Msg>,
+}
+
+impl<Msg> Rp<Msg> {
+    pub fn new(common: Common<Msg>) -> Self {
+        Self { common }
+    }
+
+    pub fn common(&self) -> &Common<Msg> {
+        &self.common
+    }
+
+    pub fn common_mut(&mut self) -> &mut Common<Msg> {
+        &mut self.common
+    }
+}
+
+impl<Msg> From<Common<Msg>> for Rp<Msg> {
+    fn from(common: Common<Msg>) -> Self {
+        Self::new(common)
+    }
+}
+
+impl<Msg> From<Rp<Msg>> for Element<Msg> {
+    fn from(rp: Rp<Msg>) -> Self {
+        Element::Rp(rp)
+    }
+}
+
+impl<Msg> From<Rp<Msg>> for Single<Msg> {
+    fn from(rp: Rp<Msg>) -> Self {
+        Element::from(rp).into()
+    }
+}
+
+impl<Msg> From<Rp<Msg>> for Node<Msg> {
+    fn from(rp: Rp<Msg>) -> Self {
+        Single::from(rp).into()
+    }
+}
+
+impl<Msg> Diff for Rp<Msg> {
+    type Patch = PatchRp;
+    fn diff(&self, other: &mut Self) -> Option<Self::Patch> {
+        Some(PatchRp {
+            common: self.common.diff(&mut other.common)?,
+        })
+    }
+}
+
+impl<Msg> Eq for Rp<Msg> {}
@@ -0,0 +1,56 @@
+use crate::{Common, Diff, Element, Node, PatchRt, Single};
+use sulafat_macros::{Clone, PartialEq, Serialize};
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize)]
+pub struct Rt<Msg> {
+    pub(crate) common: Common<Msg>,
+}
+
+impl<Msg> Rt<Msg> {
+    pub fn new(common: Common<Msg>) -> Self {
+        Self { common }
+    }
+
+    pub fn common(&self) -> &Common<Msg> {
+        &self.common
+    }
+
+    pub fn common_mut(&mut self) -> &mut Common<Msg> {
+        &mut self.common
+    }
+}
+
+impl<Msg> From<Common<Msg>> for Rt<Msg> {
+    fn from(common: Common<Msg>) -> Self {
+        Self::new(common)
+    }
+}
+
+impl<Msg> From<Rt<Msg>> for Element<Msg> {
+    fn from(rt: Rt<Msg>) -> Self {
+        Element::Rt(rt)
+    }
+}
+
+impl<Msg> From<Rt<Msg>> for Single<Msg> {
+    fn from(rt: Rt<Msg>) -> Self {
+        Element::from(rt).into()
+    }
+}
+
+impl<Msg> From<Rt<Msg>> for Node<Msg> {
+    fn from(rt: Rt<Msg>) -> Self {
+        Single::from(rt).into()
+    }
+}
+
+impl<Msg> Diff for Rt<Msg> {
+    type Patch = PatchRt;
+    fn diff(&self, other: &mut Self) -> Option<Self::Patch> {
+        Some(PatchRt {
+            common: self.common.diff(&mut other.common)?,
+        })
+    }
+}
+
+impl<Msg> Eq for Rt<Msg> {}
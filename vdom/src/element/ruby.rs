@@ -0,0 +1,102 @@
+use crate::{Common, Diff, Element, Node, PatchRuby, Single};
+use sulafat_macros::{Clone, PartialEq, Serialize};
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize)]
+pub struct Ruby<Msg> {
+    pub(crate) common: Common<Msg>,
+}
+
+impl<Msg> Ruby<Msg> {
+    pub fn new(common: Common<Msg>) -> Self {
+        Self { common }
+    }
+
+    pub fn common(&self) -> &Common<Msg> {
+        &self.common
+    }
+
+    pub fn common_mut(&mut self) -> &mut Common<Msg> {
+        &mut self.common
+    }
+}
+
+impl<Msg> From<Common<Msg>> for Ruby<Msg> {
+    fn from(common: Common<Msg>) -> Self {
+        Self::new(common)
+    }
+}
+
+impl<Msg> From<Ruby<Msg>> for Element<Msg> {
+    fn from(ruby: Ruby<Msg>) -> Self {
+        Element::Ruby(ruby)
+    }
+}
+
+impl<Msg> From<Ruby<Msg>> for Single<Msg> {
+    fn from(ruby: Ruby<Msg>) -> Self {
+        Element::from(ruby).into()
+    }
+}
+
+impl<Msg> From<Ruby<Msg>> for Node<Msg> {
+    fn from(ruby: Ruby<Msg>) -> Self {
+        Single::from(ruby).into()
+    }
+}
+
+impl<Msg> Diff for Ruby<Msg> {
+    type Patch = PatchRuby;
+    fn diff(&self, other: &mut Self) -> Option<Self::Patch> {
+        Some(PatchRuby {
+            common: self.common.diff(&mut other.common)?,
+        })
+    }
+}
+
+impl<Msg> Eq for Ruby<Msg> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        element::rendered::RenderedRuby, id, Apply, Common, Diff, PatchAttributeListOp,
+        PatchCommon, PatchRuby, RenderedAttribute, Ruby,
+    };
+    #[test]
+    fn same() {
+        let ruby1 = Ruby::<()>::default();
+        let mut ruby2 = Ruby::default();
+        assert_eq!(ruby1.diff(&mut ruby2), None)
+    }
+
+    #[test]
+    fn different_id() {
+        let ruby1 = Ruby::<()>::new(Common::new(
+            None,
+            vec![id("a".into())].into(),
+            Default::default(),
+        ));
+        let mut ruby2 = Ruby::new(Common::new(
+            None,
+            vec![id("b".into())].into(),
+            Default::default(),
+        ));
+        assert_ne!(ruby1, ruby2);
+        let patch = ruby1.diff(&mut ruby2);
+        assert_eq!(
+            patch,
+            Some(PatchRuby {
+                common: PatchCommon {
+                    attribute_list: vec![PatchAttributeListOp::Insert(RenderedAttribute::Id(
+                        "b".into()
+                    ))]
+                    .into(),
+                    children: Default::default()
+                }
+            })
+        );
+        let mut rendered_ruby1 = RenderedRuby::from(&ruby1);
+        let rendered_ruby2 = RenderedRuby::from(&ruby2);
+        rendered_ruby1.apply(patch.unwrap()).unwrap();
+        assert_eq!(rendered_ruby1, rendered_ruby2);
+    }
+}
@@ -20,6 +20,12 @@ impl<Msg> Span<Msg> {
     }
 }
 
+impl<Msg> From<Common<Msg>> for Span<Msg> {
+    fn from(common: Common<Msg>) -> Self {
+        Self::new(common)
+    }
+}
+
 impl<Msg> From<Span<Msg>> for Element<Msg> {
     fn from(span: Span<Msg>) -> Self {
         Element::Span(span)
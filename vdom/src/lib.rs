@@ -1,36 +1,52 @@
 #![cfg_attr(feature = "nightly-features", feature(unsafe_block_in_unsafe_fn))]
 #![cfg_attr(feature = "nightly-features", deny(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "nightly-features"), allow(unused_unsafe))]
+#![allow(clippy::module_inception)]
 
 pub mod attribute;
 pub mod closure_id;
 pub mod cmd;
+pub mod component;
+pub mod context;
 pub mod diff;
 pub mod element;
 pub mod list;
+pub mod local;
 pub mod node;
 pub mod program;
 pub mod random;
+pub mod root;
+pub mod scheduler;
 pub mod single;
+pub mod style_sheet;
 pub mod timer;
 pub(crate) mod util;
 pub mod variant_ident;
 pub mod view;
 
+pub(crate) use attribute::MsgMap;
 pub use attribute::{
-    id, on_click, on_pointer_move, style, Attribute, AttributeList, Handler, PatchAttributeList,
-    PatchAttributeListOp, RenderedAttribute, RenderedAttributeList, Style,
+    id, on_click, on_pointer_move, style, theme, Attribute, AttributeList, DynamicStyle, Handler,
+    PatchAttributeList, PatchAttributeListOp, PatchStyleList, PatchStyleListOp, RenderedAttribute,
+    RenderedAttributeList, RenderedStyle, RenderedStyleList, Style, StyleList, ToStyle,
 };
 pub use closure_id::ClosureId;
+pub use component::Component;
+pub use context::{context, provide};
 pub use diff::{Apply, ApplyResult, Diff};
 pub use element::{
-    Common, Div, Element, PatchCommon, PatchDiv, PatchElement, PatchSpan, RenderedElement, Span,
+    div, rp, rt, ruby, span, Common, Div, Element, ElementBuilder, PatchCommon, PatchDiv,
+    PatchElement, PatchRp, PatchRt, PatchRuby, PatchSpan, RenderedElement, Rp, Rt, Ruby, Span,
 };
 pub use list::{List, PatchList, PatchListOp, RenderedList};
+pub use local::{local, Local, LocalMsg};
 pub use node::{Node, PatchNode, RenderedNode};
 pub use program::{EventHandler, Manager, Program};
+pub use root::{PatchRoot, RenderedRoot};
+pub use scheduler::{NativeScheduler, Scheduler};
 pub use single::{PatchSingle, Single};
+pub use style_sheet::{PatchStyleSheet, PatchStyleSheetOp, RenderedStyleSheet, StyleRegistry};
 pub use variant_ident::VariantIdent;
-pub use view::CachedView;
+pub use view::{lazy, lazy2, lazy3, CachedView, Lazy};
 
 extern crate self as sulafat_vdom;
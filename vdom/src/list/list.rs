@@ -1,4 +1,6 @@
-use crate::{CachedView, ClosureId, Diff, Node, PatchList, PatchListOp, PatchSingle};
+use crate::{
+    CachedView, ClosureId, Diff, MsgMap, Node, PatchList, PatchListOp, PatchSingle, StyleRegistry,
+};
 use serde::{ser::SerializeSeq, Serialize, Serializer};
 use std::{
     any::Any, cmp::min, collections::HashMap, iter::FromIterator, mem::replace, ops::Deref,
@@ -64,8 +66,8 @@ impl<Msg> List<Msg> {
         let node = self.list.remove(index);
         if node.is_full_rendered() {
             self.full_rendered_count -= 1;
+            self.flat_len -= node.flat_len().unwrap();
         }
-        self.flat_len -= node.flat_len().unwrap();
         node
     }
 
@@ -73,13 +75,31 @@ impl<Msg> List<Msg> {
         self.full_rendered_count == self.len()
     }
 
+    /// Recounts the children, since a cached view among them may have been rendered by a
+    /// diff after it was added.
     pub(crate) fn full_render(&mut self) {
+        if self.is_full_rendered() {
+            return;
+        }
+        let mut flat_len = 0;
         for node in &mut self.list {
-            if !node.is_full_rendered() {
-                node.full_render();
-                self.full_rendered_count += 1;
-            }
+            node.full_render();
+            flat_len += node.flat_len().unwrap();
         }
+        self.flat_len = flat_len;
+        self.full_rendered_count = self.len();
+    }
+
+    /// Mapped cached views are not rendered yet, so the counts are taken again.
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> List<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        self.list
+            .into_iter()
+            .map(|node| node.map_with(map))
+            .collect()
     }
 
     pub(crate) fn pick_handler(&self, handlers: &mut HashMap<ClosureId, Weak<dyn Any>>)
@@ -90,6 +110,12 @@ impl<Msg> List<Msg> {
             node.pick_handler(handlers)
         }
     }
+
+    pub(crate) fn pick_style(&self, registry: &mut StyleRegistry) {
+        for node in &self.list {
+            node.pick_style(registry)
+        }
+    }
 }
 
 impl<Msg> Deref for List<Msg> {
@@ -148,6 +174,7 @@ impl FlatDiffContext {
                 self.cached_view_flat_diff(this, other, this_flat_index)
             }
             (_, node) => {
+                node.full_render();
                 node.add_patch(&mut self.patches);
                 self.flat_index += node.flat_len().unwrap();
             }
@@ -177,6 +204,7 @@ impl FlatDiffContext {
                     this_flat_index + indexes[this_index],
                 )
             } else {
+                node.full_render();
                 node.add_patch(&mut self.patches);
                 self.flat_index += node.flat_len().unwrap();
             }
@@ -190,15 +218,26 @@ impl FlatDiffContext {
         this_flat_index: usize,
     ) {
         if this.is_different(other) {
-            other.render().add_patch(&mut self.patches);
-        } else if !this.share_cache_if_same(other) {
-            self.node_diff(
-                unsafe { this.rendered() }.unwrap(),
-                other.render(),
-                this_flat_index,
-            )
+            other.full_render().add_patch(&mut self.patches);
+            self.flat_index += other.flat_len().unwrap();
+        } else if this.share_cache_if_same(other) {
+            let flat_len = other.flat_len().unwrap();
+            for i in 0..flat_len {
+                self.patches.push(if this_flat_index == self.flat_index {
+                    self.nop_count += 1;
+                    PatchListOp::Nop
+                } else {
+                    self.is_move = true;
+                    PatchListOp::From(this_flat_index + i)
+                });
+            }
+            self.flat_index += flat_len;
+        } else {
+            this.diff_rendered(other, |this, other| {
+                self.node_diff(this, other, this_flat_index)
+            });
+            other.full_render();
         }
-        self.flat_index += other.flat_len().unwrap();
     }
 }
 
@@ -212,8 +251,9 @@ impl<Msg> Diff for List<Msg> {
             patches: Vec::with_capacity(other.len()),
         };
         context.flat_diff(self, other, 0);
+        other.full_render();
         Some(
-            if !context.is_move && context.nop_count >= (other.flat_len().unwrap() + 1) / 2 {
+            if !context.is_move && context.nop_count >= other.flat_len().unwrap().div_ceil(2) {
                 let len = other.len();
                 let entries = context
                     .patches
@@ -246,15 +286,16 @@ impl<Msg> Diff for List<Msg> {
 impl<Msg> From<Vec<Node<Msg>>> for List<Msg> {
     fn from(list: Vec<Node<Msg>>) -> Self {
         let (flat_len, full_rendered_count) = list.iter().fold((0, 0), |(a, b), node| {
-            (
-                a + node.flat_len().unwrap(),
-                b + node.is_full_rendered() as usize,
-            )
+            if node.is_full_rendered() {
+                (a + node.flat_len().unwrap(), b + 1)
+            } else {
+                (a, b)
+            }
         });
         Self {
             flat_len,
             list,
-            full_rendered_count: full_rendered_count.into(),
+            full_rendered_count,
         }
     }
 }
@@ -277,7 +318,7 @@ impl<Msg> FromIterator<Node<Msg>> for List<Msg> {
         Self {
             flat_len,
             list,
-            full_rendered_count: full_rendered_count,
+            full_rendered_count,
         }
     }
 }
@@ -64,37 +64,35 @@ impl Apply for RenderedList {
     fn apply(&mut self, patch: Self::Patch) -> ApplyResult {
         match patch {
             PatchList::All(patches) => {
-                let mut prev = self.list.drain(..).map(|v| Some(v)).collect::<Vec<_>>();
+                let mut prev = self.list.drain(..).map(Some).collect::<Vec<_>>();
                 self.list.reserve(patches.len());
                 for (index, patch) in patches.into_iter().enumerate() {
                     match patch {
                         PatchListOp::Nop => self.list.push(
                             prev[index]
                                 .take()
-                                .ok_or_else(|| String::from("元ノードの取得に失敗しました"))?
-                                .into(),
+                                .ok_or_else(|| String::from("元ノードの取得に失敗しました"))?,
                         ),
                         PatchListOp::From(index) => self.list.push(
                             prev[index]
                                 .take()
-                                .ok_or_else(|| String::from("元ノードの取得に失敗しました"))?
-                                .into(),
+                                .ok_or_else(|| String::from("元ノードの取得に失敗しました"))?,
                         ),
                         PatchListOp::Modify(patch) => {
                             let mut single = prev[index]
                                 .take()
                                 .ok_or_else(|| String::from("元ノードの取得に失敗しました"))?;
                             single.apply(patch)?;
-                            self.list.push(single.into());
+                            self.list.push(single);
                         }
                         PatchListOp::FromModify(index, patch) => {
                             let mut single = prev[index]
                                 .take()
                                 .ok_or_else(|| String::from("元ノードの取得に失敗しました"))?;
                             single.apply(patch)?;
-                            self.list.push(single.into());
+                            self.list.push(single);
                         }
-                        PatchListOp::New(single) => self.list.push(single.into()),
+                        PatchListOp::New(single) => self.list.push(single),
                     }
                 }
             }
@@ -106,7 +104,7 @@ impl Apply for RenderedList {
                 for (index, patch) in entries {
                     if index >= self.len() {
                         if let PatchSingle::Replace(single) = patch {
-                            self.list.push(single.into())
+                            self.list.push(single)
                         } else {
                             return Err("不正なパッチです".into());
                         }
@@ -0,0 +1,383 @@
+use crate::{view::View, CachedView, ClosureId, MsgMap, Node};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+thread_local! {
+    static UPDATED: Cell<bool> = const { Cell::new(false) };
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Whether a local state of the manager being diffed has been updated.
+pub(crate) fn is_updated() -> bool {
+    UPDATED.with(|updated| updated.get())
+}
+
+/// Runs `f`, dispatching an event of one manager, and returns whether it has updated a
+/// local state, so that the update is kept by that manager only.
+pub(crate) fn dispatch<R>(f: impl FnOnce() -> R) -> (R, bool) {
+    let outer = UPDATED.with(|updated| updated.replace(false));
+    let result = f();
+    (result, UPDATED.with(|updated| updated.replace(outer)))
+}
+
+/// Runs `f`, diffing a manager of which local states have been updated if `updated`.
+pub(crate) fn diff<R>(updated: bool, f: impl FnOnce() -> R) -> R {
+    let outer = UPDATED.with(|cell| cell.replace(updated));
+    let result = f();
+    UPDATED.with(|cell| cell.set(outer));
+    result
+}
+
+/// A message of a view with a local state, either for the state itself or passed on to
+/// the rest of the program.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocalMsg<L, Msg> {
+    Local(L),
+    Parent(Msg),
+}
+
+struct State<S> {
+    id: u64,
+    value: RefCell<Rc<S>>,
+}
+
+impl<S> State<S> {
+    fn new(value: S) -> Self {
+        let id = NEXT_ID.with(|next| next.replace(next.get() + 1));
+        Self {
+            id,
+            value: RefCell::new(Rc::new(value)),
+        }
+    }
+}
+
+type StateCell<S> = Rc<State<S>>;
+
+struct LocalView<Props, S, L, Msg> {
+    props: Props,
+    init: fn(&Props) -> S,
+    update: fn(&S, &L) -> S,
+    view: fn(&S, &Props) -> Node<LocalMsg<L, Msg>>,
+    state: RefCell<Option<StateCell<S>>>,
+    rendered: RefCell<Option<Rc<S>>>,
+}
+
+impl<Props, S, L, Msg> LocalView<Props, S, L, Msg> {
+    fn current(&self) -> Option<Rc<S>> {
+        Some(self.state.borrow().as_ref()?.value.borrow().clone())
+    }
+
+    fn is_same_fn(&self, other: &Self) -> bool {
+        self.init as usize == other.init as usize
+            && self.update as usize == other.update as usize
+            && self.view as usize == other.view as usize
+    }
+}
+
+impl<Props, S, L, Msg> View<Msg> for LocalView<Props, S, L, Msg>
+where
+    Props: 'static + PartialEq,
+    S: 'static,
+    L: 'static,
+    Msg: 'static,
+{
+    fn render(&self) -> Node<Msg> {
+        let cell = self
+            .state
+            .borrow_mut()
+            .get_or_insert_with(|| Rc::new(State::new((self.init)(&self.props))))
+            .clone();
+        let state = cell.value.borrow().clone();
+        *self.rendered.borrow_mut() = Some(state.clone());
+        let update = self.update;
+        // The state is kept across diffs and has an id of its own, so that the handlers of
+        // two instances of the same view are dispatched to their own states.
+        let id = ClosureId::Local(cell.id);
+        let map = MsgMap::filter(id, move |msg| match msg {
+            LocalMsg::Local(msg) => {
+                let state = update(&cell.value.borrow(), &msg);
+                *cell.value.borrow_mut() = Rc::new(state);
+                UPDATED.with(|updated| updated.set(true));
+                None
+            }
+            LocalMsg::Parent(msg) => Some(msg),
+        });
+        (self.view)(&state, &self.props).map_with(&map)
+    }
+
+    fn is_same(&self, other: &dyn View<Msg>) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() {
+            self.is_same_fn(other)
+                && self.props == other.props
+                && self.rendered.borrow().as_ref().map(Rc::as_ptr)
+                    == other.current().as_ref().map(Rc::as_ptr)
+        } else {
+            false
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn adopt(&self, old: &dyn View<Msg>) {
+        if let Some(old) = old.as_any().downcast_ref::<Self>() {
+            if !std::ptr::eq(self, old) && self.is_same_fn(old) {
+                let state = old.state.borrow().clone();
+                *self.state.borrow_mut() = state;
+            }
+        }
+    }
+
+    fn is_updated(&self) -> bool {
+        match (&*self.rendered.borrow(), self.current()) {
+            (Some(rendered), Some(current)) => !Rc::ptr_eq(rendered, &current),
+            _ => false,
+        }
+    }
+}
+
+#[must_use]
+pub struct Local<Props, S, L, Msg> {
+    key: Option<String>,
+    view: LocalView<Props, S, L, Msg>,
+}
+
+impl<Props, S, L, Msg> Local<Props, S, L, Msg> {
+    pub fn key<K: Into<String>>(mut self, key: K) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+}
+
+impl<Props, S, L, Msg> From<Local<Props, S, L, Msg>> for Node<Msg>
+where
+    Props: 'static + PartialEq,
+    S: 'static,
+    L: 'static,
+    Msg: 'static,
+{
+    fn from(local: Local<Props, S, L, Msg>) -> Self {
+        Node::CachedView(CachedView::new(local.key, local.view))
+    }
+}
+
+/// A view owning a state of its own, e.g. whether a dropdown is open.
+///
+/// The state is created by `init` when the view is rendered first, and kept while the
+/// view stays at the same place, or with the same key, across diffs. It is dropped with
+/// the view. `LocalMsg::Local` messages update it by `update` and re-render the view
+/// without reaching the program, while `LocalMsg::Parent` messages are passed on.
+///
+/// ```ignore
+/// fn dropdown(open: &bool, items: &Rc<[String]>) -> Node<LocalMsg<Toggle, Msg>> { .. }
+///
+/// local(items, |_| false, |open, Toggle| !open, dropdown).key("menu")
+/// ```
+pub fn local<Props, S, L, Msg>(
+    props: Props,
+    init: fn(&Props) -> S,
+    update: fn(&S, &L) -> S,
+    view: fn(&S, &Props) -> Node<LocalMsg<L, Msg>>,
+) -> Local<Props, S, L, Msg> {
+    Local {
+        key: None,
+        view: LocalView {
+            props,
+            init,
+            update,
+            view,
+            state: RefCell::new(None),
+            rendered: RefCell::new(None),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{local, LocalMsg};
+    use crate::{lazy, on_click, Apply, Common, Diff, Div, Handler, Node, RenderedNode};
+    use std::{cell::Cell, collections::HashMap};
+
+    thread_local! {
+        static LIVE: Cell<isize> = const { Cell::new(0) };
+    }
+
+    struct Open(bool);
+
+    impl Open {
+        fn new(open: bool) -> Self {
+            LIVE.with(|live| live.set(live.get() + 1));
+            Self(open)
+        }
+    }
+
+    impl Drop for Open {
+        fn drop(&mut self) {
+            LIVE.with(|live| live.set(live.get() - 1));
+        }
+    }
+
+    struct Toggle;
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Select(u32),
+    }
+
+    fn dropdown(open: &Open, count: &u32) -> Node<LocalMsg<Toggle, Msg>> {
+        let items = if open.0 {
+            (1..=*count)
+                .map(|item| {
+                    Div::new(Common::new(
+                        None,
+                        vec![on_click(move |_| LocalMsg::Parent(Msg::Select(item)))].into(),
+                        vec![item.to_string().into()].into(),
+                    ))
+                    .into()
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        Div::new(Common::new(
+            None,
+            vec![on_click(|_| LocalMsg::Local(Toggle))].into(),
+            items.into(),
+        ))
+        .into()
+    }
+
+    fn view(count: u32) -> Node<Msg> {
+        Div::new(Common::new(
+            None,
+            vec![].into(),
+            vec![local(
+                count,
+                |_| Open::new(false),
+                |open, _| Open::new(!open.0),
+                dropdown,
+            )
+            .key("menu")
+            .into()]
+            .into(),
+        ))
+        .into()
+    }
+
+    fn pair() -> Node<Msg> {
+        let dropdown = |key| {
+            local(
+                1,
+                |_| Open::new(false),
+                |open, _| Open::new(!open.0),
+                dropdown,
+            )
+            .key(key)
+            .into()
+        };
+        Div::new(Common::new(
+            None,
+            vec![].into(),
+            vec![dropdown("first"), dropdown("second")].into(),
+        ))
+        .into()
+    }
+
+    fn dispatch(node: &Node<Msg>) -> Vec<Option<Msg>> {
+        let mut handlers = HashMap::new();
+        node.pick_handler(&mut handlers);
+        handlers
+            .values()
+            .map(|handler| {
+                let handler = handler.upgrade().unwrap();
+                handler.downcast::<Handler<(), Msg>>().unwrap().dispatch(())
+            })
+            .collect()
+    }
+
+    fn check(old: &Node<Msg>, new: &mut Node<Msg>) -> bool {
+        let patch = old.diff(new);
+        let mut rendered = RenderedNode::from(old);
+        if let Some(patch) = patch.clone() {
+            rendered.apply(patch).unwrap();
+        }
+        assert_eq!(rendered, RenderedNode::from(&*new));
+        patch.is_some()
+    }
+
+    #[test]
+    fn keep_and_update() {
+        let mut old = view(1);
+        old.full_render();
+        let mut new = view(1);
+        assert!(!check(&old, &mut new));
+        assert_eq!(dispatch(&new), vec![None]);
+        let mut opened = view(1);
+        assert!(check(&new, &mut opened));
+        let mut same = view(1);
+        assert!(!check(&opened, &mut same));
+        let mut messages = dispatch(&same);
+        messages.sort_by_key(Option::is_some);
+        assert_eq!(messages, vec![None, Some(Msg::Select(1))]);
+    }
+
+    #[test]
+    fn separate_states() {
+        let mut old = pair();
+        old.full_render();
+        assert_eq!(dispatch(&old), vec![None, None]);
+        let mut new = pair();
+        assert!(check(&old, &mut new));
+        let mut messages = dispatch(&new);
+        messages.sort_by_key(Option::is_some);
+        assert_eq!(
+            messages,
+            vec![None, None, Some(Msg::Select(1)), Some(Msg::Select(1))]
+        );
+    }
+
+    #[test]
+    fn keep_updates_in_scope() {
+        let mut node = view(1);
+        node.full_render();
+        let ((), outer) = super::dispatch(|| {
+            let (_, inner) = super::dispatch(|| dispatch(&node));
+            assert!(inner);
+        });
+        assert!(!outer);
+        assert!(!super::diff(false, super::is_updated));
+    }
+
+    #[test]
+    fn drop_with_view() {
+        let mut old = view(1);
+        old.full_render();
+        dispatch(&old);
+        let mut new = view(1);
+        assert!(check(&old, &mut new));
+        drop(old);
+        assert_eq!(LIVE.with(|live| live.get()), 1);
+        let mut removed: Node<Msg> = "removed".into();
+        assert!(check(&new, &mut removed));
+        drop(new);
+        assert_eq!(LIVE.with(|live| live.get()), 0);
+    }
+
+    fn cached(count: &u32) -> Node<Msg> {
+        view(*count)
+    }
+
+    #[test]
+    fn inside_cached_view() {
+        let mut old: Node<Msg> = lazy(cached, 1).into();
+        old.full_render();
+        assert_eq!(dispatch(&old), vec![None]);
+        let mut new = lazy(cached, 1).into();
+        assert!(check(&old, &mut new));
+        assert_eq!(dispatch(&new).len(), 2);
+    }
+}
@@ -1,6 +1,8 @@
 use std::{any::Any, collections::HashMap, rc::Weak};
 
-use crate::{list::PatchListOp, CachedView, ClosureId, Diff, List, PatchNode, Single};
+use crate::{
+    list::PatchListOp, CachedView, ClosureId, Diff, List, MsgMap, PatchNode, Single, StyleRegistry,
+};
 use serde::{ser::SerializeTupleVariant, Serialize, Serializer};
 use sulafat_macros::{Clone, PartialEq};
 
@@ -57,6 +59,41 @@ impl<Msg> Node<Msg> {
         }
     }
 
+    /// Converts the messages of every handler in the subtree with `f`, e.g. to reuse a
+    /// `fn view(..) -> Node<ChildMsg>` as `view(..).map(Msg::Child)`.
+    ///
+    /// The mapped handlers compare equal as long as the original ones do and `f` is the
+    /// same function, so mapping on each render does not change them in a diff. Closures
+    /// are told apart by their types only, as for handlers.
+    pub fn map<F, Msg2>(self, f: F) -> Node<Msg2>
+    where
+        F: 'static + Fn(Msg) -> Msg2,
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        self.map_with(&MsgMap::new(f))
+    }
+
+    pub(crate) fn map_with<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> Node<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        match self {
+            Node::Single(single) => Node::Single(single.map(map)),
+            Node::List(list) => Node::List(list.map(map)),
+            Node::CachedView(view) => Node::CachedView(view.map(map)),
+        }
+    }
+
+    pub(crate) fn is_outdated(&self) -> bool {
+        match self {
+            Node::Single(single) => single.is_outdated(),
+            Node::List(list) => list.iter().any(Node::is_outdated),
+            Node::CachedView(view) => view.is_outdated(),
+        }
+    }
+
     pub(crate) fn pick_handler(&self, handlers: &mut HashMap<ClosureId, Weak<dyn Any>>)
     where
         Msg: 'static,
@@ -67,6 +104,14 @@ impl<Msg> Node<Msg> {
             Node::CachedView(view) => unsafe { view.rendered() }.unwrap().pick_handler(handlers),
         }
     }
+
+    pub(crate) fn pick_style(&self, registry: &mut StyleRegistry) {
+        match self {
+            Node::Single(single) => single.pick_style(registry),
+            Node::List(list) => list.pick_style(registry),
+            Node::CachedView(view) => view.pick_style(registry),
+        }
+    }
 }
 
 impl<Msg> Diff for Node<Msg> {
@@ -76,7 +121,10 @@ impl<Msg> Diff for Node<Msg> {
             (Node::Single(s), Node::Single(o)) => Some(s.diff(o)?.into()),
             (Node::List(s), Node::List(o)) => Some(s.diff(o)?.into()),
             (Node::CachedView(s), Node::CachedView(o)) => s.diff(o),
-            (_, other) => Some(PatchNode::Replace((&*other).into())),
+            (_, other) => {
+                other.full_render();
+                Some(PatchNode::Replace((&*other).into()))
+            }
         }
     }
 }
@@ -102,11 +150,23 @@ impl<Msg> Serialize for Node<Msg> {
     }
 }
 
+/// `None` is an empty list, which renders nothing but keeps the place of the node among
+/// its siblings.
+impl<Msg, T> From<Option<T>> for Node<Msg>
+where
+    T: Into<Node<Msg>>,
+{
+    fn from(node: Option<T>) -> Self {
+        node.map_or_else(|| List::from(vec![]).into(), Into::into)
+    }
+}
+
 impl<Msg> Eq for Node<Msg> {}
 
 #[cfg(test)]
 mod test {
-    use crate::{Apply, Diff, Node, PatchNode, RenderedNode};
+    use crate::{lazy, on_click, Apply, Common, Diff, Div, Handler, Node, PatchNode, RenderedNode};
+    use std::collections::HashMap;
 
     #[test]
     fn same_single() {
@@ -143,4 +203,53 @@ mod test {
         let rendered_node2 = bincode::deserialize(&ser).unwrap();
         assert_eq!(rendered_node1, rendered_node2);
     }
+
+    #[derive(Debug, PartialEq)]
+    enum ChildMsg {
+        Click(u32),
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Child(ChildMsg),
+        Other(ChildMsg),
+    }
+
+    fn child(n: &u32) -> Node<ChildMsg> {
+        let n = *n;
+        Div::new(Common::new(
+            None,
+            vec![on_click(move |_| ChildMsg::Click(n))].into(),
+            vec![n.to_string().into()].into(),
+        ))
+        .into()
+    }
+
+    fn parent(f: fn(ChildMsg) -> Msg) -> Node<Msg> {
+        vec![child(&1).map(f), Node::from(lazy(child, 2)).map(f)].into()
+    }
+
+    #[test]
+    fn map() {
+        let mut node = child(&1).map(Msg::Child);
+        node.full_render();
+        let mut handlers = HashMap::new();
+        node.pick_handler(&mut handlers);
+        let handler = handlers.values().next().unwrap().upgrade().unwrap();
+        let handler = handler.downcast::<Handler<(), Msg>>().unwrap();
+        assert_eq!(handler.invoke(()), Msg::Child(ChildMsg::Click(1)));
+    }
+
+    #[test]
+    fn map_keeps_identity() {
+        let mut old = parent(Msg::Child);
+        old.full_render();
+        let mut new = parent(Msg::Child);
+        assert_eq!(old.diff(&mut new), None);
+        let mut other = parent(Msg::Other);
+        let patch = new.diff(&mut other).unwrap();
+        let mut rendered = RenderedNode::from(&new);
+        rendered.apply(patch).unwrap();
+        assert_eq!(rendered, RenderedNode::from(&other));
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{cmd::Cmd, view::Memo, CachedView, ClosureId, Diff, Handler, Node, PatchNode};
+use crate::{
+    cmd::Cmd, local, scheduler::Scheduler, view::Memo, CachedView, ClosureId, Diff, Handler, Node,
+    PatchRoot, RenderedRoot, StyleRegistry,
+};
 use serde_derive::{Deserialize, Serialize};
 use std::{
     any::Any,
@@ -40,11 +43,19 @@ pub trait Program: 'static {
     fn subscriptions() {}
 }
 
+fn view<P: Program>(model: &Rc<P::Model>) -> Node<P::Msg> {
+    P::view(model)
+}
+
 pub struct Manager<P: Program> {
     view: CachedView<P::Msg>,
     model: Rc<P::Model>,
     cmd: Cmd<P::Msg>,
     handlers: HashMap<ClosureId, Weak<dyn Any>>,
+    styles: StyleRegistry,
+    dirty: bool,
+    local_updated: bool,
+    scheduler: Option<Rc<dyn Scheduler>>,
     #[cfg(target_arch = "wasm32")]
     weak: WeakManager<P>,
 }
@@ -60,12 +71,16 @@ impl<P: Program> Manager<P> {
         let (model, cmd) = P::init_cmd();
         unregister_trigger();
         let model = Rc::new(model);
-        let view = CachedView::new(None, Memo::new(P::view, model.clone()));
+        let view = CachedView::new(None, Memo::new(view::<P>, (model.clone(),)));
         let this = Self {
             view,
             model,
             cmd,
             handlers: Default::default(),
+            styles: Default::default(),
+            dirty: false,
+            local_updated: false,
+            scheduler: None,
             weak,
         };
         unsafe { ptr.write(this) };
@@ -76,19 +91,47 @@ impl<P: Program> Manager<P> {
     pub fn new() -> Box<Self> {
         let (model, cmd) = P::init_cmd();
         let model = Rc::new(model);
-        let view = CachedView::new(None, Memo::new(P::view, model.clone()));
+        let view = CachedView::new(None, Memo::new(view::<P>, (model.clone(),)));
         Box::new(Self {
             view,
             model,
             cmd,
             handlers: Default::default(),
+            styles: Default::default(),
+            dirty: false,
+            local_updated: false,
+            scheduler: None,
         })
     }
 
-    pub fn full_render(&mut self) -> &mut Node<P::Msg> {
+    /// Sets the scheduler requested to render when the manager becomes dirty.
+    pub fn set_scheduler(&mut self, scheduler: Rc<dyn Scheduler>) {
+        self.scheduler = Some(scheduler);
+    }
+
+    /// Whether a message has been processed since the last render.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_dirty(&mut self) {
+        if !replace(&mut self.dirty, true) {
+            if let Some(scheduler) = &self.scheduler {
+                scheduler.request_render();
+            }
+        }
+    }
+
+    pub fn full_render(&mut self) -> RenderedRoot<'_, P::Msg> {
+        self.dirty = false;
         let node = self.view.full_render();
         node.pick_handler(&mut self.handlers);
-        node
+        self.styles = Default::default();
+        node.pick_style(&mut self.styles);
+        RenderedRoot {
+            style_sheet: (&self.styles).into(),
+            node,
+        }
     }
 
     pub fn on_msg(&mut self, msg: &P::Msg) {
@@ -105,6 +148,7 @@ impl<P: Program> Manager<P> {
             }
         }
         self.model = Rc::new(model);
+        self.mark_dirty();
     }
 
     pub fn on_event(&mut self, event_handler: &EventHandler) {
@@ -114,17 +158,23 @@ impl<P: Program> Manager<P> {
             .unwrap()
             .upgrade()
             .unwrap();
-        let msg = match &event_handler.event {
+        let (msg, local_updated) = local::dispatch(|| match &event_handler.event {
             Event::OnClick => handler
                 .downcast::<Handler<(), P::Msg>>()
                 .unwrap()
-                .invoke(()),
+                .dispatch(()),
             &Event::OnPointerMove(x, y) => handler
                 .downcast::<Handler<(f64, f64), P::Msg>>()
                 .unwrap()
-                .invoke((x, y)),
-        };
-        self.on_msg(&msg)
+                .dispatch((x, y)),
+        });
+        if local_updated {
+            self.local_updated = true;
+            self.mark_dirty();
+        }
+        if let Some(msg) = msg {
+            self.on_msg(&msg)
+        }
     }
 
     pub fn resolve(&mut self, context: &mut Context) {
@@ -133,15 +183,35 @@ impl<P: Program> Manager<P> {
         }
     }
 
-    pub fn diff(&mut self) -> Option<PatchNode> {
-        let mut view = CachedView::new(None, Memo::new(P::view, self.model.clone()));
-        let diff = self.view.diff(&mut view);
+    /// Diffs the messages since the last render into one patch, if the manager is dirty.
+    pub fn render(&mut self) -> Option<PatchRoot> {
+        if self.dirty {
+            self.diff()
+        } else {
+            None
+        }
+    }
+
+    pub fn diff(&mut self) -> Option<PatchRoot> {
+        self.dirty = false;
+        let mut view = CachedView::new(None, Memo::new(view::<P>, (self.model.clone(),)));
+        let node = local::diff(replace(&mut self.local_updated, false), || {
+            self.view.diff(&mut view)
+        });
+        let rendered = unsafe { view.rendered() }.unwrap();
         self.handlers.clear();
-        unsafe { view.rendered() }
-            .unwrap()
-            .pick_handler(&mut self.handlers);
+        rendered.pick_handler(&mut self.handlers);
+        let mut styles = StyleRegistry::default();
+        rendered.pick_style(&mut styles);
+        let style_sheet = self.styles.diff(&mut styles).unwrap_or_default();
+        self.styles = styles;
         self.view = view;
-        diff
+        let patch = PatchRoot { style_sheet, node };
+        if patch.is_empty() {
+            None
+        } else {
+            Some(patch)
+        }
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,25 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Node, PatchNode, PatchStyleSheet, RenderedStyleSheet};
+
+/// The first render of a program: the dynamic styles it uses and its node.
+#[derive(Serialize)]
+#[serde(bound = "", rename = "Root")]
+pub struct RenderedRoot<'a, Msg> {
+    pub style_sheet: RenderedStyleSheet,
+    pub node: &'a Node<Msg>,
+}
+
+/// Changes between two renders. Style sheet changes travel with the node patch so
+/// that the runtime sees classes inserted before they are used.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchRoot {
+    pub style_sheet: PatchStyleSheet,
+    pub node: Option<PatchNode>,
+}
+
+impl PatchRoot {
+    pub fn is_empty(&self) -> bool {
+        self.style_sheet.is_empty() && self.node.is_none()
+    }
+}
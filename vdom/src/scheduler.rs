@@ -0,0 +1,194 @@
+use std::cell::Cell;
+
+/// Schedules the renders of [`Manager`](crate::Manager)s.
+///
+/// A manager requests a render when it becomes dirty, i.e. when it has processed a
+/// message from an event, a [`Cmd`](crate::cmd::Cmd) or a subscription, and not again
+/// until it is rendered. The requests until the next frame are to be coalesced, so that
+/// each dirty manager is rendered once per frame by [`Manager::render`](crate::Manager::render).
+pub trait Scheduler {
+    fn request_render(&self);
+}
+
+/// A [`Scheduler`] of which frames are run by hand, e.g. in tests off the browser.
+#[derive(Default)]
+pub struct NativeScheduler {
+    requested: Cell<bool>,
+    frames: Cell<usize>,
+}
+
+impl NativeScheduler {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether a render has been requested since the last frame.
+    pub fn is_requested(&self) -> bool {
+        self.requested.get()
+    }
+
+    /// Starts a frame if a render has been requested, in which the caller is to render
+    /// the managers.
+    pub fn begin_frame(&self) -> bool {
+        if self.requested.replace(false) {
+            self.frames.set(self.frames.get() + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The number of frames started so far.
+    pub fn frames(&self) -> usize {
+        self.frames.get()
+    }
+}
+
+impl Scheduler for NativeScheduler {
+    fn request_render(&self) {
+        self.requested.set(true);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NativeScheduler;
+    use crate::{
+        cmd::Cmd, local, on_click, program::Event, Common, Div, EventHandler, Manager, Node,
+        Program,
+    };
+    use bincode::{deserialize, serialize};
+    use std::{
+        collections::HashMap,
+        rc::Rc,
+        task::{Context, Waker},
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Increment,
+        Load,
+    }
+
+    struct Counter;
+
+    impl Program for Counter {
+        type Model = u32;
+        type Msg = Msg;
+        fn init() -> Self::Model {
+            0
+        }
+        fn update_cmd(model: &Self::Model, msg: &Self::Msg) -> (Self::Model, Cmd<Self::Msg>) {
+            match msg {
+                Msg::Increment => (model + 1, Cmd::none()),
+                Msg::Load => (*model, Cmd::with(|| Msg::Increment)),
+            }
+        }
+        fn view(model: &Self::Model) -> Node<Self::Msg> {
+            Div::new(Common::new(
+                None,
+                vec![on_click(|_| Msg::Increment)].into(),
+                vec![model.to_string().into()].into(),
+            ))
+            .into()
+        }
+    }
+
+    fn manager<P: Program>() -> (Box<Manager<P>>, Rc<NativeScheduler>) {
+        let scheduler = Rc::new(NativeScheduler::new());
+        let mut manager = Manager::<P>::new();
+        manager.set_scheduler(scheduler.clone());
+        manager.full_render();
+        (manager, scheduler)
+    }
+
+    fn click<P: Program>(manager: &mut Manager<P>) {
+        let mut handlers = HashMap::new();
+        manager.full_render().node.pick_handler(&mut handlers);
+        let closure_id = *handlers.keys().next().unwrap();
+        let data = serialize(&(closure_id, Event::OnClick)).unwrap();
+        manager.on_event(&deserialize::<EventHandler>(&data).unwrap());
+    }
+
+    #[test]
+    fn coalesce_in_frame() {
+        let (mut manager, scheduler) = manager::<Counter>();
+        assert!(!scheduler.begin_frame());
+        assert_eq!(manager.render(), None);
+
+        manager.on_msg(&Msg::Increment);
+        manager.on_msg(&Msg::Increment);
+        assert!(manager.is_dirty());
+        assert!(scheduler.begin_frame());
+        assert!(manager.render().is_some());
+        assert!(!manager.is_dirty());
+
+        assert!(!scheduler.begin_frame());
+        assert_eq!(manager.render(), None);
+        assert_eq!(scheduler.frames(), 1);
+    }
+
+    #[test]
+    fn render_cmd_result() {
+        let (mut manager, scheduler) = manager::<Counter>();
+        manager.on_msg(&Msg::Load);
+        assert!(scheduler.begin_frame());
+        assert_eq!(manager.render(), None);
+
+        manager.resolve(&mut Context::from_waker(Waker::noop()));
+        assert!(scheduler.begin_frame());
+        assert!(manager.render().is_some());
+    }
+
+    #[test]
+    fn render_event() {
+        let (mut manager, scheduler) = manager::<Counter>();
+        click(&mut *manager);
+        assert!(scheduler.is_requested());
+        assert!(scheduler.begin_frame());
+        assert!(manager.render().is_some());
+    }
+
+    struct Toggle;
+
+    fn toggle(open: &bool, _: &()) -> Node<local::LocalMsg<Toggle, ()>> {
+        Div::new(Common::new(
+            None,
+            vec![on_click(|_| local::LocalMsg::Local(Toggle))].into(),
+            vec![open.to_string().into()].into(),
+        ))
+        .into()
+    }
+
+    struct Dropdown;
+
+    impl Program for Dropdown {
+        type Model = ();
+        type Msg = ();
+        fn init() -> Self::Model {}
+        fn view(_: &Self::Model) -> Node<Self::Msg> {
+            local::local((), |_| false, |open, Toggle| !open, toggle).into()
+        }
+    }
+
+    #[test]
+    fn render_local_update() {
+        let (mut manager, scheduler) = manager::<Dropdown>();
+        click(&mut *manager);
+        assert!(scheduler.begin_frame());
+        assert!(manager.render().is_some());
+        assert!(!scheduler.begin_frame());
+    }
+
+    #[test]
+    fn render_local_update_of_own_manager() {
+        let (mut first, first_scheduler) = manager::<Dropdown>();
+        let (mut second, second_scheduler) = manager::<Dropdown>();
+        click(&mut *first);
+        assert!(!second.is_dirty());
+        assert!(!second_scheduler.begin_frame());
+        assert_eq!(second.diff(), None);
+        assert!(first_scheduler.begin_frame());
+        assert!(first.render().is_some());
+    }
+}
@@ -1,6 +1,6 @@
 use std::{any::Any, collections::HashMap, rc::Weak};
 
-use crate::{ClosureId, Diff, Element, Node, PatchSingle};
+use crate::{ClosureId, Diff, Element, MsgMap, Node, PatchSingle, StyleRegistry};
 use sulafat_macros::{Clone, PartialEq, Serialize};
 
 use super::RenderedSingle;
@@ -34,6 +34,24 @@ impl<Msg> Single<Msg> {
         }
     }
 
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> Single<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        match self {
+            Single::Text(text) => Single::Text(text),
+            Single::Element(element) => Single::Element(element.map(map)),
+        }
+    }
+
+    pub(crate) fn is_outdated(&self) -> bool {
+        match self {
+            Single::Text(_) => false,
+            Single::Element(element) => element.children().iter().any(Node::is_outdated),
+        }
+    }
+
     pub(crate) fn pick_handler(&self, handlers: &mut HashMap<ClosureId, Weak<dyn Any>>)
     where
         Msg: 'static,
@@ -43,6 +61,13 @@ impl<Msg> Single<Msg> {
             Single::Element(element) => element.pick_handler(handlers),
         }
     }
+
+    pub(crate) fn pick_style(&self, registry: &mut StyleRegistry) {
+        match self {
+            Single::Text(_) => {}
+            Single::Element(element) => element.pick_style(registry),
+        }
+    }
 }
 
 impl<Msg> From<Single<Msg>> for Node<Msg> {
@@ -63,7 +88,10 @@ impl<Msg> Diff for Single<Msg> {
                 }
             }
             (Single::Element(s), Single::Element(o)) => Some(s.diff(o)?.into()),
-            (_, other) => Some(PatchSingle::Replace((&*other).into())),
+            (_, other) => {
+                other.full_render();
+                Some(PatchSingle::Replace((&*other).into()))
+            }
         }
     }
 }
@@ -0,0 +1,5 @@
+mod rendered_style_sheet;
+mod style_registry;
+
+pub use rendered_style_sheet::{PatchStyleSheet, PatchStyleSheetOp, RenderedStyleSheet};
+pub use style_registry::StyleRegistry;
@@ -0,0 +1,85 @@
+use crate::{Apply, ApplyResult, StyleRegistry};
+use serde_derive::{Deserialize, Serialize};
+
+/// The contents of the shared `<style>` sheet: class names and their CSS text, in
+/// document order.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "StyleSheet")]
+pub struct RenderedStyleSheet {
+    rules: Vec<(String, String)>,
+}
+
+impl RenderedStyleSheet {
+    fn position(&self, name: &str) -> Option<usize> {
+        self.rules.iter().position(|(n, _)| n == name)
+    }
+}
+
+impl From<&StyleRegistry> for RenderedStyleSheet {
+    fn from(registry: &StyleRegistry) -> Self {
+        Self {
+            rules: registry
+                .styles()
+                .map(|style| (style.name().to_string(), style.css().to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl Apply for RenderedStyleSheet {
+    type Patch = PatchStyleSheet;
+    fn apply(&mut self, patch: Self::Patch) -> ApplyResult {
+        for patch in patch {
+            match patch {
+                PatchStyleSheetOp::Remove(name) => {
+                    if let Some(index) = self.position(&name) {
+                        self.rules.remove(index);
+                    } else {
+                        return Err("削除するスタイルがありません".into());
+                    }
+                }
+                PatchStyleSheetOp::Insert(name, css) => {
+                    if let Some(index) = self.position(&name) {
+                        self.rules[index].1 = css;
+                    } else {
+                        self.rules.push((name, css));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchStyleSheet {
+    list: Vec<PatchStyleSheetOp>,
+}
+
+impl PatchStyleSheet {
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+impl From<Vec<PatchStyleSheetOp>> for PatchStyleSheet {
+    fn from(list: Vec<PatchStyleSheetOp>) -> Self {
+        Self { list }
+    }
+}
+
+impl IntoIterator for PatchStyleSheet {
+    type Item = PatchStyleSheetOp;
+    type IntoIter = <Vec<PatchStyleSheetOp> as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+/// `Insert` replaces the CSS of a class that is already in the sheet in place, and
+/// appends a new class at the end.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PatchStyleSheetOp {
+    Remove(String),
+    Insert(String, String),
+}
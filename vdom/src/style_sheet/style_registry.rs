@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use crate::{Diff, DynamicStyle, PatchStyleSheet, PatchStyleSheetOp};
+
+/// Dynamic styles used by a tree, with the number of elements using each class.
+///
+/// Styles keep the order they were first retained in, so a style set comes after the
+/// ones it extends and wins on conflicts of equal specificity.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyleRegistry {
+    entries: BTreeMap<String, (usize, usize, DynamicStyle)>,
+    next: usize,
+}
+
+impl StyleRegistry {
+    pub fn retain(&mut self, style: &DynamicStyle) {
+        let next = &mut self.next;
+        self.entries
+            .entry(style.name().to_string())
+            .or_insert_with(|| {
+                *next += 1;
+                (0, *next, style.clone())
+            })
+            .0 += 1;
+    }
+
+    /// Retains the styles of `other` as many times as it does, in its order.
+    pub fn merge(&mut self, other: &StyleRegistry) {
+        let mut styles = other.entries.values().collect::<Vec<_>>();
+        styles.sort_by_key(|(_, order, _)| *order);
+        for (count, _, style) in styles {
+            let next = &mut self.next;
+            self.entries
+                .entry(style.name().to_string())
+                .or_insert_with(|| {
+                    *next += 1;
+                    (0, *next, style.clone())
+                })
+                .0 += count;
+        }
+    }
+
+    pub fn release(&mut self, style: &DynamicStyle) {
+        if let Some((count, _, _)) = self.entries.get_mut(style.name()) {
+            *count -= 1;
+            if *count == 0 {
+                self.entries.remove(style.name());
+            }
+        }
+    }
+
+    pub fn count(&self, name: &str) -> usize {
+        self.entries
+            .get(name)
+            .map(|(count, _, _)| *count)
+            .unwrap_or(0)
+    }
+
+    /// Styles in the order they were first retained.
+    pub fn styles(&self) -> impl Iterator<Item = &DynamicStyle> {
+        let mut styles = self.entries.values().collect::<Vec<_>>();
+        styles.sort_by_key(|(_, order, _)| *order);
+        styles.into_iter().map(|(_, _, style)| style)
+    }
+}
+
+impl Diff for StyleRegistry {
+    type Patch = PatchStyleSheet;
+    fn diff(&self, other: &mut Self) -> Option<Self::Patch> {
+        let mut list = vec![];
+        for (name, (_, _, style)) in &self.entries {
+            match other.entries.get(name) {
+                None => list.push(PatchStyleSheetOp::Remove(name.clone())),
+                Some((_, _, other_style)) if other_style != style => list.push(
+                    PatchStyleSheetOp::Insert(name.clone(), other_style.css().to_string()),
+                ),
+                _ => {}
+            }
+        }
+        for style in other.styles() {
+            if !self.entries.contains_key(style.name()) {
+                list.push(PatchStyleSheetOp::Insert(
+                    style.name().to_string(),
+                    style.css().to_string(),
+                ))
+            }
+        }
+        if list.is_empty() {
+            None
+        } else {
+            Some(list.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Apply, Diff, DynamicStyle, PatchStyleSheetOp, RenderedStyleSheet, StyleRegistry};
+    use sulafat_style::{Length, LengthOrPercentage, StyleRule};
+
+    fn left(px: f64) -> DynamicStyle {
+        DynamicStyle::from_rules(&[StyleRule::Left(LengthOrPercentage::Length(Length::Px(px)))])
+    }
+
+    #[test]
+    fn count() {
+        let mut registry = StyleRegistry::default();
+        registry.retain(&left(1.));
+        registry.retain(&left(1.));
+        registry.retain(&left(2.));
+        assert_eq!(registry.count(left(1.).name()), 2);
+        registry.release(&left(1.));
+        assert_eq!(registry.count(left(1.).name()), 1);
+        registry.release(&left(1.));
+        assert_eq!(registry.count(left(1.).name()), 0);
+        assert_eq!(registry.styles().collect::<Vec<_>>(), vec![&left(2.)]);
+    }
+
+    #[test]
+    fn order() {
+        let mut registry = StyleRegistry::default();
+        registry.retain(&left(3.));
+        registry.retain(&left(1.));
+        registry.retain(&left(2.));
+        registry.retain(&left(1.));
+        assert_eq!(
+            registry.styles().collect::<Vec<_>>(),
+            vec![&left(3.), &left(1.), &left(2.)]
+        );
+        let mut rendered = RenderedStyleSheet::default();
+        rendered
+            .apply(StyleRegistry::default().diff(&mut registry).unwrap())
+            .unwrap();
+        assert_eq!(rendered, RenderedStyleSheet::from(&registry));
+    }
+
+    #[test]
+    fn merge() {
+        let mut registry = StyleRegistry::default();
+        registry.retain(&left(2.));
+        let mut other = StyleRegistry::default();
+        other.retain(&left(3.));
+        other.retain(&left(1.));
+        other.retain(&left(2.));
+        other.retain(&left(3.));
+        registry.merge(&other);
+        assert_eq!(registry.count(left(3.).name()), 2);
+        assert_eq!(registry.count(left(2.).name()), 2);
+        assert_eq!(
+            registry.styles().collect::<Vec<_>>(),
+            vec![&left(2.), &left(3.), &left(1.)]
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let mut registry1 = StyleRegistry::default();
+        registry1.retain(&left(1.));
+        registry1.retain(&left(2.));
+        let mut registry2 = StyleRegistry::default();
+        registry2.retain(&left(2.));
+        registry2.retain(&left(2.));
+        registry2.retain(&left(3.));
+        assert_eq!(registry1.diff(&mut registry1.clone()), None);
+        let patch = registry1.diff(&mut registry2).unwrap();
+        assert_eq!(
+            patch.clone().into_iter().collect::<Vec<_>>(),
+            vec![
+                PatchStyleSheetOp::Remove(left(1.).name().to_string()),
+                PatchStyleSheetOp::Insert(left(3.).name().to_string(), left(3.).css().to_string()),
+            ]
+        );
+        let mut rendered = RenderedStyleSheet::from(&registry1);
+        rendered.apply(patch).unwrap();
+        assert_eq!(rendered, RenderedStyleSheet::from(&registry2));
+    }
+}
@@ -1,10 +1,15 @@
-use crate::{list::PatchListOp, Diff, PatchNode};
+use crate::{
+    context::{self, Guard, Read},
+    list::PatchListOp,
+    local, Diff, MsgMap, PatchNode, StyleRegistry,
+};
 
 use super::{ClosureId, Node};
 use std::{
     any::Any,
-    cell::UnsafeCell,
+    cell::{OnceCell, UnsafeCell},
     fmt::{self, Debug, Formatter},
+    mem::size_of,
     rc::Rc,
 };
 
@@ -12,6 +17,8 @@ pub struct CachedView<Msg> {
     key: Option<String>,
     view: Rc<dyn View<Msg>>,
     rendered: Option<Rc<UnsafeCell<Node<Msg>>>>,
+    reads: Rc<[Read]>,
+    styles: Rc<OnceCell<StyleRegistry>>,
 }
 
 impl<Msg> CachedView<Msg> {
@@ -19,7 +26,9 @@ impl<Msg> CachedView<Msg> {
         Self {
             key,
             view: Rc::new(view),
-            rendered: None.into(),
+            rendered: None,
+            reads: Rc::new([]),
+            styles: Default::default(),
         }
     }
 
@@ -36,11 +45,21 @@ impl<Msg> CachedView<Msg> {
     }
 
     pub(crate) fn render(&mut self) -> &mut Node<Msg> {
-        let view = &self.view;
-        let rendered = self
-            .rendered
-            .get_or_insert_with(|| Rc::new(UnsafeCell::new(view.render())));
-        unsafe { &mut *rendered.get() }
+        if self.rendered.is_none() {
+            let (node, reads) = context::record(|| self.view.render());
+            self.rendered = Some(Rc::new(UnsafeCell::new(node)));
+            self.reads = reads.into();
+        }
+        unsafe { &mut *self.rendered.as_ref().unwrap().get() }
+    }
+
+    /// Provides the value of the view, if any, to the nodes below until the guard is
+    /// dropped.
+    fn enter(&self, old: Option<&Self>) -> Guard {
+        context::enter(
+            self.view.provided(),
+            old.and_then(|old| old.view.provided()),
+        )
     }
 
     pub(crate) fn is_full_rendered(&self) -> bool {
@@ -50,6 +69,7 @@ impl<Msg> CachedView<Msg> {
     }
 
     pub(crate) fn full_render(&mut self) -> &mut Node<Msg> {
+        let _context = self.enter(None);
         let node = self.render();
         node.full_render();
         node
@@ -59,42 +79,109 @@ impl<Msg> CachedView<Msg> {
         self.key != other.key || self.view.as_any().type_id() != other.view.as_any().type_id()
     }
 
+    /// Hands the local states of `self` over to `other` before comparing them.
+    ///
+    /// If only a view below has to be rendered again, for its local state or a context
+    /// value it read, `other` takes a copy of the rendered node to be diffed into instead
+    /// of the cache.
     pub(crate) fn share_cache_if_same(&self, other: &mut Self) -> bool {
-        if self == other {
-            other.rendered = self.rendered.clone();
-            true
-        } else {
-            false
+        if !self.is_different(other) {
+            other.view.adopt(&*self.view);
+        }
+        if self != other || self.view.is_updated() || context::is_changed_read(&self.reads) {
+            other.rendered = None;
+            other.reads = Rc::new([]);
+            return false;
         }
+        if (local::is_updated() || context::is_changed()) && self.is_outdated_below() {
+            let rendered = unsafe { self.rendered() }.unwrap().clone();
+            other.rendered = Some(Rc::new(UnsafeCell::new(rendered)));
+            other.reads = self.reads.clone();
+            return false;
+        }
+        other.rendered = self.rendered.clone();
+        other.reads = self.reads.clone();
+        other.styles = self.styles.clone();
+        true
+    }
+
+    /// Diffs the rendered nodes of the same view.
+    pub(crate) fn diff_rendered<R>(
+        &self,
+        other: &mut Self,
+        diff: impl FnOnce(&Node<Msg>, &mut Node<Msg>) -> R,
+    ) -> R {
+        let _context = other.enter(Some(self));
+        diff(unsafe { self.rendered() }.unwrap(), other.render())
     }
 
     pub(crate) fn add_patch(&mut self, patches: &mut Vec<PatchListOp>) {
+        let _context = self.enter(None);
         self.render().add_patch(patches);
     }
+
+    /// The styles of the rendered node are collected once, and shared with the views
+    /// sharing the cache, so that unchanged subtrees are not walked on every diff.
+    pub(crate) fn pick_style(&self, registry: &mut StyleRegistry) {
+        registry.merge(self.styles.get_or_init(|| {
+            let mut styles = StyleRegistry::default();
+            unsafe { self.rendered() }.unwrap().pick_style(&mut styles);
+            styles
+        }))
+    }
+
+    /// Whether the view has to be rendered again, or one below it.
+    pub(crate) fn is_outdated(&self) -> bool {
+        self.view.is_updated() || context::is_changed_read(&self.reads) || self.is_outdated_below()
+    }
+
+    fn is_outdated_below(&self) -> bool {
+        let _context = self.enter(Some(self));
+        unsafe { self.rendered() }.is_some_and(|rendered| rendered.is_outdated())
+    }
+
+    /// The mapped view is rendered when it is needed, as the original would be.
+    pub(crate) fn map<Msg2>(self, map: &MsgMap<Msg, Msg2>) -> CachedView<Msg2>
+    where
+        Msg: 'static,
+        Msg2: 'static,
+    {
+        CachedView::new(
+            self.key,
+            MappedView {
+                view: self.view,
+                map: map.clone(),
+            },
+        )
+    }
 }
 
 impl<Msg> Clone for CachedView<Msg> {
     fn clone(&self) -> Self {
-        let rendered = self.rendered.clone();
         Self {
             key: self.key.clone(),
             view: self.view.clone(),
-            rendered,
+            rendered: self.rendered.clone(),
+            reads: self.reads.clone(),
+            styles: self.styles.clone(),
         }
     }
 }
 
 impl<Msg> PartialEq for CachedView<Msg> {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.view, &other.view) || &self.view == &other.view
+        Rc::ptr_eq(&self.view, &other.view) || *self.view == *other.view
     }
 }
 
 impl<Msg> Eq for CachedView<Msg> {}
 
 impl<Msg> Debug for CachedView<Msg> {
-    fn fmt(&self, _f: &mut Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedView")
+            .field("key", &self.key)
+            .field("rendered", &self.rendered.is_some())
+            .finish()
     }
 }
 
@@ -106,7 +193,7 @@ impl<Msg> Diff for CachedView<Msg> {
         } else if self.is_different(other) {
             Some(PatchNode::Replace((&*other.full_render()).into()))
         } else {
-            unsafe { self.rendered() }.unwrap().diff(other.render())
+            self.diff_rendered(other, |this, other| this.diff(other))
         }
     }
 }
@@ -115,6 +202,16 @@ pub trait View<Msg> {
     fn render(&self) -> Node<Msg>;
     fn is_same(&self, other: &dyn View<Msg>) -> bool;
     fn as_any(&self) -> &dyn Any;
+    /// Takes over the state of `old`, the view at the same place in the previous tree.
+    fn adopt(&self, _old: &dyn View<Msg>) {}
+    /// Whether the state has changed since the last render.
+    fn is_updated(&self) -> bool {
+        false
+    }
+    /// The value provided to the nodes below.
+    fn provided(&self) -> Option<Rc<dyn context::ContextValue>> {
+        None
+    }
 }
 
 impl<Msg> PartialEq for dyn View<Msg> {
@@ -125,39 +222,149 @@ impl<Msg> PartialEq for dyn View<Msg> {
 
 impl<Msg> Eq for dyn View<Msg> {}
 
+struct MappedView<Msg, Msg2> {
+    view: Rc<dyn View<Msg>>,
+    map: MsgMap<Msg, Msg2>,
+}
+
+impl<Msg, Msg2> View<Msg2> for MappedView<Msg, Msg2>
+where
+    Msg: 'static,
+    Msg2: 'static,
+{
+    fn render(&self) -> Node<Msg2> {
+        self.view.render().map_with(&self.map)
+    }
+
+    fn is_same(&self, other: &dyn View<Msg2>) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() {
+            self.map.is_same(&other.map)
+                && (Rc::ptr_eq(&self.view, &other.view) || *self.view == *other.view)
+        } else {
+            false
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn adopt(&self, old: &dyn View<Msg2>) {
+        if let Some(old) = old.as_any().downcast_ref::<Self>() {
+            self.view.adopt(&*old.view)
+        }
+    }
+
+    fn is_updated(&self) -> bool {
+        self.view.is_updated()
+    }
+
+    fn provided(&self) -> Option<Rc<dyn context::ContextValue>> {
+        self.view.provided()
+    }
+}
+
+/// A view function of the arguments `Args`, a tuple of one to three values which it
+/// takes by reference.
+pub trait ViewFn<Args, Msg>: 'static {
+    fn call(&self, args: &Args) -> Node<Msg>;
+    fn closure_id(&self) -> ClosureId;
+}
+
+impl<F, A, Msg> ViewFn<(A,), Msg> for F
+where
+    F: 'static + Fn(&A) -> Node<Msg>,
+    A: 'static,
+    Msg: 'static,
+{
+    fn call(&self, (a,): &(A,)) -> Node<Msg> {
+        self(a)
+    }
+    fn closure_id(&self) -> ClosureId {
+        ClosureId::new::<F, &A, Node<Msg>>(self)
+    }
+}
+
+impl<F, A, B, Msg> ViewFn<(A, B), Msg> for F
+where
+    F: 'static + Fn(&A, &B) -> Node<Msg>,
+    A: 'static,
+    B: 'static,
+    Msg: 'static,
+{
+    fn call(&self, (a, b): &(A, B)) -> Node<Msg> {
+        self(a, b)
+    }
+    fn closure_id(&self) -> ClosureId {
+        ClosureId::with_data::<F, &A, &B, Node<Msg>>(self)
+    }
+}
+
+impl<F, A, B, C, Msg> ViewFn<(A, B, C), Msg> for F
+where
+    F: 'static + Fn(&A, &B, &C) -> Node<Msg>,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+    Msg: 'static,
+{
+    fn call(&self, (a, b, c): &(A, B, C)) -> Node<Msg> {
+        self(a, b, c)
+    }
+    fn closure_id(&self) -> ClosureId {
+        ClosureId::new3::<F, &A, &B, &C, Node<Msg>>(self)
+    }
+}
+
+/// Calls `f` again only when it or its arguments changed, as judged by `eq`.
 #[derive(Debug)]
-pub struct Memo<Model, Msg, F> {
+pub struct Memo<Args, Msg, F> {
     f: F,
-    id: ClosureId,
-    model: Rc<Model>,
+    /// `None` for a closure capturing values, which cannot be told apart from the same
+    /// closure capturing others and so is always called again.
+    id: Option<ClosureId>,
+    args: Args,
+    eq: fn(&Args, &Args) -> bool,
     _p: std::marker::PhantomData<Msg>,
 }
 
-impl<Model, Msg, F> Memo<Model, Msg, F>
+impl<Args, Msg, F> Memo<Args, Msg, F>
 where
-    F: 'static + Fn(&Model) -> Node<Msg>,
-    Model: 'static,
+    F: ViewFn<Args, Msg>,
+    Args: 'static,
     Msg: 'static,
 {
-    pub fn new(f: F, model: Rc<Model>) -> Self {
-        let id = ClosureId::new::<F, &Model, Node<Msg>>(&f);
+    pub fn new(f: F, args: Args) -> Self
+    where
+        Args: PartialEq,
+    {
+        Self::with_eq(f, args, PartialEq::eq)
+    }
+
+    pub fn with_eq(f: F, args: Args, eq: fn(&Args, &Args) -> bool) -> Self {
+        let id = f.closure_id();
         Self {
+            id: if matches!(id, ClosureId::FnPtr(_)) || size_of::<F>() == 0 {
+                Some(id)
+            } else {
+                None
+            },
             f,
-            id,
-            model,
+            args,
+            eq,
             _p: Default::default(),
         }
     }
 }
 
-impl<Model, Msg, F> View<Msg> for Memo<Model, Msg, F>
+impl<Args, Msg, F> View<Msg> for Memo<Args, Msg, F>
 where
-    F: 'static + Fn(&Model) -> Node<Msg>,
-    Model: 'static + PartialEq,
+    F: ViewFn<Args, Msg>,
+    Args: 'static,
     Msg: 'static,
 {
     fn render(&self) -> Node<Msg> {
-        (self.f)(&self.model)
+        self.f.call(&self.args)
     }
     fn is_same(&self, other: &dyn View<Msg>) -> bool {
         if let Some(other) = other.as_any().downcast_ref::<Self>() {
@@ -171,19 +378,247 @@ where
     }
 }
 
-impl<Model, Msg, F> PartialEq for Memo<Model, Msg, F>
+impl<Args, Msg, F> PartialEq for Memo<Args, Msg, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.is_some() && self.id == other.id && (self.eq)(&self.args, &other.args)
+    }
+}
+
+impl<Args, Msg, F> Eq for Memo<Args, Msg, F> {}
+
+/// A subtree that is rendered again only when its view function or arguments change,
+/// made by [`lazy`], [`lazy2`], [`lazy3`] or [`Lazy::with_eq`]. Unchanged subtrees are
+/// skipped by the diff as a whole.
+///
+/// The view function is to be a `fn` or a closure capturing nothing. A closure capturing
+/// values is rendered on every diff, since what it captured is not compared.
+#[must_use]
+pub struct Lazy<Args, Msg, F> {
+    key: Option<String>,
+    memo: Memo<Args, Msg, F>,
+}
+
+impl<Args, Msg, F> Lazy<Args, Msg, F>
 where
-    F: Fn(&Model) -> Node<Msg>,
-    Model: PartialEq,
+    F: ViewFn<Args, Msg>,
+    Args: 'static,
+    Msg: 'static,
 {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id && self.model == other.model
+    /// Compares the arguments with `eq` instead of `PartialEq`, e.g.
+    /// `Lazy::with_eq(view, (model,), |(a,), (b,)| Rc::ptr_eq(a, b))`.
+    pub fn with_eq(f: F, args: Args, eq: fn(&Args, &Args) -> bool) -> Self {
+        Self {
+            key: None,
+            memo: Memo::with_eq(f, args, eq),
+        }
+    }
+
+    pub fn key<K: Into<String>>(mut self, key: K) -> Self {
+        self.key = Some(key.into());
+        self
     }
 }
 
-impl<Model, Msg, F> Eq for Memo<Model, Msg, F>
+impl<Args, Msg, F> From<Lazy<Args, Msg, F>> for Node<Msg>
 where
-    F: Fn(&Model) -> Node<Msg>,
-    Model: PartialEq,
+    F: ViewFn<Args, Msg>,
+    Args: 'static,
+    Msg: 'static,
 {
+    fn from(lazy: Lazy<Args, Msg, F>) -> Self {
+        Node::CachedView(CachedView::new(lazy.key, lazy.memo))
+    }
+}
+
+pub fn lazy<A, Msg, F>(f: F, a: A) -> Lazy<(A,), Msg, F>
+where
+    F: 'static + Fn(&A) -> Node<Msg>,
+    A: 'static + PartialEq,
+    Msg: 'static,
+{
+    Lazy::with_eq(f, (a,), PartialEq::eq)
+}
+
+pub fn lazy2<A, B, Msg, F>(f: F, a: A, b: B) -> Lazy<(A, B), Msg, F>
+where
+    F: 'static + Fn(&A, &B) -> Node<Msg>,
+    A: 'static + PartialEq,
+    B: 'static + PartialEq,
+    Msg: 'static,
+{
+    Lazy::with_eq(f, (a, b), PartialEq::eq)
+}
+
+pub fn lazy3<A, B, C, Msg, F>(f: F, a: A, b: B, c: C) -> Lazy<(A, B, C), Msg, F>
+where
+    F: 'static + Fn(&A, &B, &C) -> Node<Msg>,
+    A: 'static + PartialEq,
+    B: 'static + PartialEq,
+    C: 'static + PartialEq,
+    Msg: 'static,
+{
+    Lazy::with_eq(f, (a, b, c), PartialEq::eq)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lazy, lazy2, lazy3, Lazy};
+    use crate::{
+        style, Apply, Common, Diff, Div, DynamicStyle, Node, RenderedNode, Style, StyleRegistry,
+    };
+    use std::{cell::Cell, rc::Rc};
+    use sulafat_style::{Length, LengthOrPercentage, StyleRule};
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn calls() -> usize {
+        CALLS.with(|calls| calls.replace(0))
+    }
+
+    fn item(n: &u32) -> Node<()> {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        n.to_string().into()
+    }
+
+    fn pair(a: &u32, b: &&'static str) -> Node<()> {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        format!("{}{}", a, b).into()
+    }
+
+    fn triple(a: &u32, b: &u32, c: &u32) -> Node<()> {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        vec![item(a), item(b), item(c)].into()
+    }
+
+    fn root(children: Vec<Node<()>>) -> Node<()> {
+        Div::new(Common::new(None, vec![].into(), children.into())).into()
+    }
+
+    fn check(old: &Node<()>, new: &mut Node<()>) -> bool {
+        let patch = old.diff(new);
+        let mut rendered = RenderedNode::from(old);
+        if let Some(patch) = patch.clone() {
+            rendered.apply(patch).unwrap();
+        }
+        assert_eq!(rendered, RenderedNode::from(&*new));
+        patch.is_some()
+    }
+
+    #[test]
+    fn skip_same_arguments() {
+        let mut old = root(vec![lazy(item, 1).into(), lazy2(pair, 2, "a").into()]);
+        old.full_render();
+        assert_eq!(calls(), 2);
+        let mut new = root(vec![lazy(item, 1).into(), lazy2(pair, 2, "a").into()]);
+        assert!(!check(&old, &mut new));
+        assert_eq!(calls(), 0);
+        let mut changed = root(vec![lazy(item, 1).into(), lazy2(pair, 2, "b").into()]);
+        assert!(check(&new, &mut changed));
+        assert_eq!(calls(), 1);
+    }
+
+    #[test]
+    fn keyed_and_nested() {
+        let mut old = root(vec![
+            lazy3(triple, 1, 2, 3).key("a").into(),
+            root(vec![lazy(item, 4).key("b").into()]),
+        ]);
+        old.full_render();
+        assert_eq!(calls(), 5);
+        let mut new = root(vec![
+            root(vec![lazy(item, 5).key("b").into()]),
+            lazy3(triple, 1, 2, 3).key("a").into(),
+        ]);
+        assert!(check(&old, &mut new));
+        assert_eq!(calls(), 1);
+        let mut replaced = root(vec![lazy(item, 1).into()]);
+        assert!(check(&new, &mut replaced));
+        assert_eq!(calls(), 1);
+    }
+
+    struct Model {
+        value: u32,
+    }
+
+    fn model(model: &Rc<Model>) -> Node<()> {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        model.value.to_string().into()
+    }
+
+    #[test]
+    fn custom_equality() {
+        let shared = Rc::new(Model { value: 1 });
+        let ptr_eq = |shared: &Rc<Model>| {
+            Lazy::with_eq(model, (shared.clone(),), |(a,), (b,)| Rc::ptr_eq(a, b))
+        };
+        let mut old = root(vec![ptr_eq(&shared).into()]);
+        old.full_render();
+        assert_eq!(calls(), 1);
+        let mut new = root(vec![ptr_eq(&shared).into()]);
+        assert!(!check(&old, &mut new));
+        assert_eq!(calls(), 0);
+        let mut copied = root(vec![ptr_eq(&Rc::new(Model { value: 1 })).into()]);
+        assert!(!check(&new, &mut copied));
+        assert_eq!(calls(), 1);
+    }
+
+    fn left(px: f64) -> DynamicStyle {
+        DynamicStyle::from_rules(&[StyleRule::Left(LengthOrPercentage::Length(Length::Px(px)))])
+    }
+
+    fn styled(px: &f64) -> Node<()> {
+        Div::new(Common::new(
+            None,
+            vec![style(Style::Dynamic(left(*px)))].into(),
+            vec![].into(),
+        ))
+        .into()
+    }
+
+    fn styles(node: &Node<()>) -> Vec<DynamicStyle> {
+        let mut registry = StyleRegistry::default();
+        node.pick_style(&mut registry);
+        registry.styles().cloned().collect()
+    }
+
+    #[test]
+    fn share_styles() {
+        let mut old: Node<()> = lazy(styled, 1.).into();
+        old.full_render();
+        assert_eq!(styles(&old), vec![left(1.)]);
+        let mut new = lazy(styled, 1.).into();
+        assert!(!check(&old, &mut new));
+        match (&old, &new) {
+            (Node::CachedView(old), Node::CachedView(new)) => {
+                assert!(Rc::ptr_eq(&old.styles, &new.styles))
+            }
+            _ => unreachable!(),
+        }
+        let mut changed = lazy(styled, 2.).into();
+        assert!(check(&new, &mut changed));
+        assert_eq!(styles(&changed), vec![left(2.)]);
+    }
+
+    #[test]
+    fn render_capturing_closures() {
+        let captured = |x: u32| move |n: &u32| item(&(n + x));
+        let mut old = root(vec![lazy(captured(0), 1).into()]);
+        old.full_render();
+        assert_eq!(calls(), 1);
+        let mut new = root(vec![lazy(captured(1), 1).into()]);
+        assert!(check(&old, &mut new));
+        assert_eq!(calls(), 1);
+        let mut same = root(vec![lazy(captured(1), 1).into()]);
+        assert!(!check(&new, &mut same));
+        assert_eq!(calls(), 1);
+        let plain = || |n: &u32| item(n);
+        let mut old = root(vec![lazy(plain(), 1).into()]);
+        old.full_render();
+        assert_eq!(calls(), 1);
+        let mut new = root(vec![lazy(plain(), 1).into()]);
+        assert!(!check(&old, &mut new));
+        assert_eq!(calls(), 0);
+    }
 }